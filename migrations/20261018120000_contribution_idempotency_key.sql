@@ -0,0 +1,6 @@
+-- Allow clients to retry contribution creation without recording duplicates
+ALTER TABLE user_contributions ADD COLUMN IF NOT EXISTS idempotency_key VARCHAR(255);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_user_contributions_idempotency_key
+    ON user_contributions (user_id, idempotency_key)
+    WHERE idempotency_key IS NOT NULL;
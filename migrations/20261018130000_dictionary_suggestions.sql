@@ -0,0 +1,19 @@
+-- Anonymous word suggestions awaiting moderator review
+CREATE TABLE IF NOT EXISTS dictionary_suggestions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    pnar_word VARCHAR(255) NOT NULL,
+    english_word VARCHAR(255) NOT NULL,
+    part_of_speech VARCHAR(50),
+    definition TEXT,
+    example_pnar TEXT,
+    example_english TEXT,
+    contact_name VARCHAR(255),
+    contact_email VARCHAR(255),
+    status VARCHAR(50) NOT NULL DEFAULT 'pending',
+    entry_id UUID REFERENCES pnar_dictionary(id) ON DELETE SET NULL,
+    reviewed_by UUID REFERENCES users(id),
+    reviewed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_dictionary_suggestions_status ON dictionary_suggestions(status);
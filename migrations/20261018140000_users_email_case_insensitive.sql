@@ -0,0 +1,6 @@
+-- Emails are stored lowercased; enforce case-insensitive uniqueness.
+-- Creating the index fails if accounts differing only in email case already
+-- exist, which must be merged by hand before this migration can run.
+CREATE UNIQUE INDEX IF NOT EXISTS idx_users_email_lower ON users (LOWER(email));
+
+UPDATE users SET email = LOWER(email) WHERE email <> LOWER(email);
@@ -0,0 +1,3 @@
+-- Row version for optimistic locking; every update increments it and
+-- full updates must name the version they were based on.
+ALTER TABLE pnar_dictionary ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 1;
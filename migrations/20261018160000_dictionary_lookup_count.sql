@@ -0,0 +1,15 @@
+-- Denormalized lookup counter so entries can be ranked by popularity without
+-- aggregating word_usage_analytics on every request.
+ALTER TABLE pnar_dictionary ADD COLUMN IF NOT EXISTS lookup_count BIGINT NOT NULL DEFAULT 0;
+
+UPDATE pnar_dictionary d
+SET lookup_count = a.lookups
+FROM (
+    SELECT word_id, COUNT(*) AS lookups
+    FROM word_usage_analytics
+    GROUP BY word_id
+) a
+WHERE a.word_id = d.id;
+
+CREATE INDEX IF NOT EXISTS idx_pnar_dictionary_lookup_count
+    ON pnar_dictionary (lookup_count DESC, id);
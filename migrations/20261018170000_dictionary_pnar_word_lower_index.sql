@@ -0,0 +1,3 @@
+-- Supports case-insensitive lookups of an entry by its Pnar word.
+CREATE INDEX IF NOT EXISTS idx_pnar_dictionary_pnar_word_lower
+    ON pnar_dictionary (LOWER(pnar_word));
@@ -0,0 +1,3 @@
+-- Blocklisted terms found in a suggestion, kept for moderator review.
+ALTER TABLE dictionary_suggestions
+    ADD COLUMN IF NOT EXISTS flagged_terms TEXT[] NOT NULL DEFAULT '{}';
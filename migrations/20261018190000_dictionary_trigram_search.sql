@@ -0,0 +1,20 @@
+-- Enables fuzzy dictionary search. Hosts without pg_trgm skip this and the
+-- service falls back to ILIKE matching.
+DO $$
+BEGIN
+    CREATE EXTENSION IF NOT EXISTS pg_trgm;
+EXCEPTION WHEN OTHERS THEN
+    RAISE NOTICE 'pg_trgm unavailable, fuzzy search will fall back to ILIKE: %', SQLERRM;
+END
+$$;
+
+DO $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM pg_extension WHERE extname = 'pg_trgm') THEN
+        CREATE INDEX IF NOT EXISTS idx_pnar_dictionary_pnar_word_trgm
+            ON pnar_dictionary USING GIN (pnar_word gin_trgm_ops);
+        CREATE INDEX IF NOT EXISTS idx_pnar_dictionary_english_word_trgm
+            ON pnar_dictionary USING GIN (english_word gin_trgm_ops);
+    END IF;
+END
+$$;
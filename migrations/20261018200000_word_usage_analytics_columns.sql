@@ -0,0 +1,17 @@
+-- The analytics service records events as event_type/timestamp/metadata and
+-- tracks updated_at, but the table was created with usage_type/context_data
+-- and no event time, so every analytics query failed. Align the table with
+-- the service; existing rows keep their creation time as the event time.
+ALTER TABLE word_usage_analytics RENAME COLUMN usage_type TO event_type;
+ALTER TABLE word_usage_analytics RENAME COLUMN context_data TO metadata;
+
+ALTER TABLE word_usage_analytics ADD COLUMN timestamp TIMESTAMPTZ;
+UPDATE word_usage_analytics SET timestamp = created_at;
+ALTER TABLE word_usage_analytics
+    ALTER COLUMN timestamp SET DEFAULT NOW(),
+    ALTER COLUMN timestamp SET NOT NULL;
+
+ALTER TABLE word_usage_analytics
+    ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+
+CREATE INDEX IF NOT EXISTS idx_word_usage_timestamp ON word_usage_analytics(timestamp);
@@ -0,0 +1,23 @@
+-- Table-level "last changed" marker for dictionary listings. Edits show up
+-- in MAX(updated_at) of the listed rows, but a deleted or merged entry, or
+-- one that moved out of a filter, leaves nothing behind to compare against.
+CREATE TABLE IF NOT EXISTS dictionary_changes (
+    id BOOLEAN PRIMARY KEY DEFAULT true CHECK (id),
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO dictionary_changes (id) VALUES (true) ON CONFLICT DO NOTHING;
+
+CREATE OR REPLACE FUNCTION touch_dictionary_changes()
+RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE dictionary_changes SET changed_at = NOW();
+    RETURN NULL;
+END;
+$$ language 'plpgsql';
+
+DROP TRIGGER IF EXISTS touch_dictionary_changes ON pnar_dictionary;
+CREATE TRIGGER touch_dictionary_changes
+    AFTER DELETE OR UPDATE OF verified, part_of_speech, created_at ON pnar_dictionary
+    FOR EACH STATEMENT
+    EXECUTE FUNCTION touch_dictionary_changes();
@@ -8,6 +8,15 @@ pub struct Settings {
     pub database: DatabaseSettings,
     pub jwt: JwtSettings,
     pub logging: LoggingSettings,
+    pub monitoring: MonitoringSettings,
+    pub dictionary: DictionarySettings,
+    pub maintenance: MaintenanceSettings,
+    pub rate_limit: RateLimitSettings,
+    pub moderation: ModerationSettings,
+    pub notifications: NotificationSettings,
+    pub analytics: AnalyticsSettings,
+    pub pagination: PaginationSettings,
+    pub security: SecuritySettings,
 }
 
 /// Load configuration from files and environment variables
@@ -21,6 +30,22 @@ pub struct ApplicationSettings {
     pub port: u16,
     pub base_url: String,
     pub cors: CorsSettings,
+    /// Indent JSON response bodies; meant for development only
+    pub pretty_json: bool,
+    /// Seconds `/ready` keeps reporting not ready after pool warmup completes
+    pub readiness_grace_seconds: u64,
+    pub json_limits: JsonLimitSettings,
+}
+
+/// Shape limits applied to JSON request bodies before deserialization
+#[derive(Debug, Deserialize, Clone)]
+pub struct JsonLimitSettings {
+    /// Largest JSON body buffered for inspection
+    pub max_body_bytes: usize,
+    /// Deepest allowed nesting of objects and arrays
+    pub max_depth: usize,
+    /// Most object fields allowed across the whole body
+    pub max_fields: usize,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -28,6 +53,8 @@ pub struct CorsSettings {
     pub allowed_origins: Vec<String>,
     pub allowed_methods: Vec<String>,
     pub allowed_headers: Vec<String>,
+    /// Response headers browser clients may read, e.g. for trace correlation
+    pub exposed_headers: Vec<String>,
     pub allow_credentials: bool,
 }
 
@@ -40,6 +67,9 @@ pub struct DatabaseSettings {
     pub database_name: String,
     pub require_ssl: bool,
     pub max_connections: u32,
+    pub min_connections: u32,
+    /// How long an instance waits for another one to finish migrating
+    pub migration_lock_timeout_seconds: u64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -50,6 +80,15 @@ pub struct JwtSettings {
     pub cookie_name: String,
     pub cookie_domain: Option<String>,
     pub cookie_secure: bool,
+    /// Also set the access token as an HttpOnly `cookie_name` cookie on
+    /// login/register, and accept it when no Authorization header is sent
+    pub cookie_auth: bool,
+    /// `iss` claim set on issued tokens and required on verification
+    pub issuer: String,
+    /// `aud` claim set on issued tokens and required on verification
+    pub audience: String,
+    /// Clock skew tolerated when checking `exp`/`nbf` on verification
+    pub leeway_seconds: u64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -58,6 +97,113 @@ pub struct LoggingSettings {
     pub format: String, // "json" or "pretty"
 }
 
+#[derive(Debug, Deserialize, Clone)]
+pub struct MonitoringSettings {
+    pub performance_monitoring: bool,
+    pub slow_query_threshold_ms: u64,
+    /// Default minimum runtime for queries listed by the admin activity endpoint
+    pub long_running_query_seconds: u64,
+    /// Hide query text from the admin activity endpoint
+    pub redact_query_text: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct DictionarySettings {
+    pub stats_cache_ttl_seconds: u64,
+    /// Upper bound on `limit` for dictionary searches; larger requests are capped
+    pub max_search_limit: i64,
+    /// How often lookup counters are reconciled with the analytics table (0 disables)
+    pub lookup_reconcile_interval_seconds: u64,
+    /// Minimum trigram similarity (0-1) for a word to match a fuzzy search
+    pub fuzzy_min_similarity: f32,
+    /// Most dictionary exports allowed to stream at the same time
+    pub max_concurrent_exports: usize,
+    /// Longest an export query may run before it is cancelled
+    pub export_statement_timeout_seconds: u64,
+}
+
+/// Argon2id cost parameters for password hashing (defaults match `Argon2::default()`)
+#[derive(Debug, Deserialize, Clone)]
+pub struct SecuritySettings {
+    pub argon2_memory_kib: u32,
+    pub argon2_iterations: u32,
+    pub argon2_parallelism: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PaginationSettings {
+    /// Page size used when a list request omits `per_page`
+    pub default_per_page: i64,
+    /// Largest page size a client may request; larger values are clamped
+    pub max_per_page: i64,
+}
+
+/// Highest page number a list or search will serve; keeps `(page - 1) * per_page`
+/// from overflowing when computing the row offset
+pub const MAX_PAGE: i64 = 1_000_000;
+
+impl PaginationSettings {
+    /// Resolve optional `page` / `per_page` query values to a page number and size
+    pub fn resolve(&self, page: Option<i64>, per_page: Option<i64>) -> (i64, i64) {
+        let max_per_page = self.max_per_page.max(1);
+        (
+            page.unwrap_or(1).clamp(1, MAX_PAGE),
+            per_page
+                .unwrap_or(self.default_per_page)
+                .clamp(1, max_per_page),
+        )
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AnalyticsSettings {
+    /// Largest accepted serialized `metadata` (stored as context data) per event
+    pub max_metadata_bytes: usize,
+    pub impact: ImpactPointsSettings,
+}
+
+/// Points awarded to entry creators for lookups of their words
+#[derive(Debug, Deserialize, Clone)]
+pub struct ImpactPointsSettings {
+    pub enabled: bool,
+    /// How often the award job runs; each run settles the last completed day
+    pub interval_seconds: u64,
+    /// Lookups needed in a window to earn one point
+    pub lookups_per_point: i64,
+    pub max_points_per_window: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MaintenanceSettings {
+    pub enabled: bool,
+    pub retry_after_seconds: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct NotificationSettings {
+    /// How often unread-notification digests are sent, and the default
+    /// look-back window for an on-demand digest (0 disables the job)
+    pub digest_interval_seconds: u64,
+}
+
+/// Screening of user-submitted text
+#[derive(Debug, Deserialize, Clone)]
+pub struct ModerationSettings {
+    /// Words and phrases that flag a submission for moderator review
+    pub blocked_terms: Vec<String>,
+}
+
+/// Advisory per-caller request budget reported in `X-RateLimit-*` headers
+#[derive(Debug, Deserialize, Clone)]
+pub struct RateLimitSettings {
+    pub enabled: bool,
+    pub requests_per_window: u32,
+    pub window_seconds: u64,
+    /// Expensive requests (searches, exports) one user may have in flight at
+    /// once; further ones get 429 (0 disables the limit)
+    pub max_concurrent_per_user: usize,
+}
+
 impl Settings {
     pub fn load() -> Result<Self, config::ConfigError> {
         let base_path = std::env::current_dir().expect("Failed to determine the current directory");
@@ -109,6 +255,9 @@ impl DatabaseSettings {
             .port(self.port)
             .database(&self.database_name)
             .ssl_mode(ssl_mode)
+            // Day boundaries in SQL (e.g. DATE_TRUNC on TIMESTAMPTZ) follow the
+            // session zone, so pin it to match the UTC timestamps the API returns
+            .options([("timezone", "UTC")])
     }
 
     pub fn connection_string(&self) -> Secret<String> {
@@ -156,3 +305,31 @@ impl TryFrom<String> for Environment {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pagination(default_per_page: i64, max_per_page: i64) -> PaginationSettings {
+        PaginationSettings {
+            default_per_page,
+            max_per_page,
+        }
+    }
+
+    #[test]
+    fn default_per_page_applies_when_omitted() {
+        assert_eq!(pagination(20, 100).resolve(None, None), (1, 20));
+        assert_eq!(pagination(35, 100).resolve(None, None), (1, 35));
+    }
+
+    #[test]
+    fn requested_values_are_clamped() {
+        let settings = pagination(20, 100);
+
+        assert_eq!(settings.resolve(Some(3), Some(50)), (3, 50));
+        assert_eq!(settings.resolve(Some(0), Some(500)), (1, 100));
+        assert_eq!(settings.resolve(Some(-2), Some(0)), (1, 1));
+        assert_eq!(settings.resolve(Some(i64::MAX), Some(100)), (MAX_PAGE, 100));
+    }
+}
@@ -1,28 +1,290 @@
-use crate::{config::DatabaseSettings, error::AppResult};
-use sqlx::{postgres::PgPoolOptions, PgPool};
-use std::time::Duration;
-use tracing::info;
+use crate::{
+    config::{DatabaseSettings, MonitoringSettings},
+    dto::responses::PoolStats,
+    error::{AppError, AppResult},
+};
+use sqlx::{postgres::PgPoolOptions, ConnectOptions, PgConnection, PgPool, Row};
+use std::{
+    collections::HashSet,
+    sync::OnceLock,
+    time::{Duration, Instant},
+};
+use tracing::{info, warn};
 
-pub async fn create_connection_pool(settings: &DatabaseSettings) -> AppResult<PgPool> {
+pub async fn create_connection_pool(
+    settings: &DatabaseSettings,
+    monitoring: &MonitoringSettings,
+) -> AppResult<PgPool> {
     info!("Connecting to database...");
 
+    let mut connect_options = settings.connection_options();
+    if monitoring.performance_monitoring {
+        // Every statement slower than the threshold is logged at warn level
+        // together with its SQL and elapsed time.
+        connect_options = connect_options.log_slow_statements(
+            log::LevelFilter::Warn,
+            Duration::from_millis(monitoring.slow_query_threshold_ms),
+        );
+    }
+
     let pool = PgPoolOptions::new()
         .max_connections(settings.max_connections)
+        .min_connections(settings.min_connections)
         .acquire_timeout(Duration::from_secs(10))
-        .connect_with(settings.connection_options())
+        .connect_with(connect_options)
         .await?;
 
+    warm_up_pool(&pool, settings.min_connections).await;
+
     info!("Database connection pool created successfully");
     Ok(pool)
 }
 
-pub async fn run_migrations(pool: &PgPool) -> AppResult<()> {
+/// Open up to `target` connections up front so the first requests don't pay
+/// the connection latency. Returns the number of connections established.
+pub async fn warm_up_pool(pool: &PgPool, target: u32) -> u32 {
+    let mut connections = Vec::with_capacity(target as usize);
+
+    for _ in 0..target {
+        match pool.acquire().await {
+            Ok(connection) => connections.push(connection),
+            Err(e) => {
+                warn!("Failed to open warmup connection: {}", e);
+                break;
+            }
+        }
+    }
+
+    let established = connections.len() as u32;
+    // Returning the connections to the pool leaves them idle and ready.
+    drop(connections);
+
+    if established < target {
+        warn!(
+            "Connection pool warmup established {} of {} min_connections",
+            established, target
+        );
+    } else {
+        info!("Connection pool warmed up with {} connections", established);
+    }
+
+    established
+}
+
+/// Gate for the readiness probe: closed until pool warmup has completed and
+/// the configured grace period has elapsed since
+#[derive(Debug)]
+pub struct Readiness {
+    warmed_up_at: OnceLock<Instant>,
+    grace: Duration,
+}
+
+impl Readiness {
+    pub fn new(grace: Duration) -> Self {
+        Self {
+            warmed_up_at: OnceLock::new(),
+            grace,
+        }
+    }
+
+    pub fn mark_warmed_up(&self) {
+        self.warmed_up_at.get_or_init(Instant::now);
+    }
+
+    /// Grace time left before traffic should be routed here; `None` while
+    /// warmup is still running
+    pub fn grace_remaining(&self) -> Option<Duration> {
+        self.warmed_up_at
+            .get()
+            .map(|warmed_up_at| self.grace.saturating_sub(warmed_up_at.elapsed()))
+    }
+}
+
+/// Advisory lock key serializing migrations across instances ("pnarmig")
+const MIGRATION_LOCK_KEY: i64 = 0x0070_6e61_726d_6967;
+
+/// Apply pending migrations while holding a Postgres advisory lock, so during
+/// a rolling deploy only one instance migrates and the others wait for it,
+/// then find nothing left to apply. Waiting longer than `lock_timeout` fails.
+pub async fn run_migrations(pool: &PgPool, lock_timeout: Duration) -> AppResult<()> {
+    let mut conn = pool.acquire().await?;
+
+    let acquired: bool = sqlx::query_scalar("SELECT pg_try_advisory_lock($1)")
+        .bind(MIGRATION_LOCK_KEY)
+        .fetch_one(&mut *conn)
+        .await?;
+
+    if !acquired {
+        info!(
+            "Another instance is running migrations; waiting up to {}s for the migration lock",
+            lock_timeout.as_secs()
+        );
+
+        let wait = sqlx::query("SELECT pg_advisory_lock($1)")
+            .bind(MIGRATION_LOCK_KEY)
+            .execute(&mut *conn);
+
+        match tokio::time::timeout(lock_timeout, wait).await {
+            Ok(result) => {
+                result?;
+            }
+            Err(_) => {
+                // The lock query was abandoned mid-flight; close the connection
+                // instead of returning it to the pool in an unknown state
+                drop(conn.detach());
+                return Err(AppError::Internal(format!(
+                    "Timed out after {}s waiting for the migration lock",
+                    lock_timeout.as_secs()
+                )));
+            }
+        }
+
+        info!("Migration lock acquired");
+    }
+
     info!("Running database migrations...");
-    sqlx::migrate!("./migrations").run(pool).await?;
+    let result = sqlx::migrate!("./migrations").run(&mut *conn).await;
+
+    sqlx::query("SELECT pg_advisory_unlock($1)")
+        .bind(MIGRATION_LOCK_KEY)
+        .execute(&mut *conn)
+        .await?;
+
+    result?;
     info!("Database migrations completed successfully");
     Ok(())
 }
 
+/// Tables the API cannot serve requests without
+pub const REQUIRED_TABLES: &[&str] = &[
+    "user_role",
+    "users",
+    "pnar_dictionary",
+    "translation_requests",
+    "user_contributions",
+    "word_usage_analytics",
+    "notifications",
+];
+
+/// Returns the required tables that are missing from the current schema
+pub async fn check_database_readiness(pool: &PgPool) -> AppResult<Vec<String>> {
+    let rows = sqlx::query(
+        r#"
+        SELECT table_name::text AS table_name
+        FROM information_schema.tables
+        WHERE table_schema = current_schema() AND table_name = ANY($1)
+        "#,
+    )
+    .bind(REQUIRED_TABLES)
+    .fetch_all(pool)
+    .await?;
+
+    let present: HashSet<String> = rows.iter().map(|row| row.get("table_name")).collect();
+
+    Ok(REQUIRED_TABLES
+        .iter()
+        .filter(|table| !present.contains(**table))
+        .map(|table| table.to_string())
+        .collect())
+}
+
+/// Version of the newest embedded migration, i.e. the schema this build expects
+pub fn schema_version() -> i64 {
+    sqlx::migrate!("./migrations")
+        .iter()
+        .map(|migration| migration.version)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the versions of embedded migrations that have not been applied yet
+pub async fn pending_migrations(pool: &PgPool) -> AppResult<Vec<i64>> {
+    let migrator = sqlx::migrate!("./migrations");
+
+    let applied: HashSet<i64> =
+        match sqlx::query("SELECT version FROM _sqlx_migrations WHERE success")
+            .fetch_all(pool)
+            .await
+        {
+            Ok(rows) => rows.iter().map(|row| row.get("version")).collect(),
+            // No migrations table yet means nothing has been applied
+            Err(sqlx::Error::Database(e)) if e.code().as_deref() == Some("42P01") => HashSet::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+    Ok(migrator
+        .iter()
+        .filter(|migration| migration.migration_type.is_up_migration())
+        .filter(|migration| !applied.contains(&migration.version))
+        .map(|migration| migration.version)
+        .collect())
+}
+
+/// Tables whose indexes and planner statistics are rebuilt by [`reindex_search_tables`]
+pub const SEARCH_TABLES: &[&str] = &["pnar_dictionary", "dictionary_suggestions"];
+
+/// Advisory lock key held for the duration of a reindex, across all instances
+const REINDEX_LOCK_KEY: i64 = 0x706e_6172_5249_4458;
+
+/// Rebuild indexes and refresh statistics on the search tables.
+///
+/// Only one reindex may run at a time; a concurrent call gets a conflict error.
+pub async fn reindex_search_tables(pool: &PgPool) -> AppResult<Vec<String>> {
+    // Advisory locks belong to a session, so lock and unlock on the same connection
+    let mut conn = pool.acquire().await?;
+
+    let locked: bool = sqlx::query_scalar("SELECT pg_try_advisory_lock($1)")
+        .bind(REINDEX_LOCK_KEY)
+        .fetch_one(&mut *conn)
+        .await?;
+
+    if !locked {
+        return Err(AppError::Conflict(
+            "A reindex is already in progress".to_string(),
+        ));
+    }
+
+    let result = reindex_tables(&mut conn).await;
+
+    if let Err(e) = sqlx::query("SELECT pg_advisory_unlock($1)")
+        .bind(REINDEX_LOCK_KEY)
+        .execute(&mut *conn)
+        .await
+    {
+        warn!("Failed to release reindex lock: {}", e);
+    }
+
+    result?;
+
+    Ok(SEARCH_TABLES
+        .iter()
+        .map(|table| table.to_string())
+        .collect())
+}
+
+async fn reindex_tables(conn: &mut PgConnection) -> AppResult<()> {
+    for table in SEARCH_TABLES {
+        info!("Reindexing and analyzing {}", table);
+        sqlx::query(&format!("REINDEX TABLE {}", table))
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query(&format!("ANALYZE {}", table))
+            .execute(&mut *conn)
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Current connection usage of the pool
+pub fn pool_stats(pool: &PgPool) -> PoolStats {
+    PoolStats {
+        size: pool.size(),
+        idle: pool.num_idle() as u32,
+        max_connections: pool.options().get_max_connections(),
+    }
+}
+
 pub async fn health_check(pool: &PgPool) -> AppResult<()> {
     sqlx::query("SELECT 1").fetch_one(pool).await?;
     Ok(())
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// Toggle maintenance mode
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MaintenanceModeRequest {
+    #[schema(example = true)]
+    pub enabled: bool,
+}
@@ -5,6 +5,8 @@ use validator::Validate;
 /// User registration request
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct RegisterRequest {
+    /// Stored lowercased; emails differing only in case (local part included)
+    /// belong to the same account
     #[validate(email(message = "Invalid email format"))]
     #[schema(example = "user@example.com")]
     pub email: String,
@@ -25,6 +27,7 @@ pub struct RegisterRequest {
 /// User login request
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct LoginRequest {
+    /// Matched case-insensitively
     #[validate(email(message = "Invalid email format"))]
     #[schema(example = "user@example.com")]
     pub email: String,
@@ -1,5 +1,7 @@
+use crate::config::MAX_PAGE;
 use serde::Deserialize;
 use utoipa::ToSchema;
+use uuid::Uuid;
 use validator::Validate;
 
 /// Request to create a new dictionary entry
@@ -33,11 +35,7 @@ pub struct CreateDictionaryEntryRequest {
     #[schema(example = "I go home")]
     pub example_english: Option<String>,
 
-    #[validate(range(
-        min = 1,
-        max = 10,
-        message = "Difficulty level must be between 1 and 10"
-    ))]
+    #[validate(range(min = 1, max = 5, message = "Difficulty level must be between 1 and 5"))]
     #[schema(example = 1)]
     pub difficulty_level: Option<i32>,
 
@@ -84,11 +82,7 @@ pub struct UpdateDictionaryEntryRequest {
     #[schema(example = "Updated English example")]
     pub example_english: Option<String>,
 
-    #[validate(range(
-        min = 1,
-        max = 10,
-        message = "Difficulty level must be between 1 and 10"
-    ))]
+    #[validate(range(min = 1, max = 5, message = "Difficulty level must be between 1 and 5"))]
     pub difficulty_level: Option<i32>,
 
     #[validate(range(min = 0, message = "Usage frequency must be non-negative"))]
@@ -98,6 +92,11 @@ pub struct UpdateDictionaryEntryRequest {
     pub related_words: Option<String>,
     pub pronunciation: Option<String>,
     pub etymology: Option<String>,
+
+    /// Version the update is based on; rejected with 409 if the entry has
+    /// changed since
+    #[schema(example = 1)]
+    pub version: i32,
 }
 
 /// Dictionary search request
@@ -109,13 +108,24 @@ pub struct SearchDictionaryRequest {
 
     pub search_type: Option<SearchType>,
 
-    #[validate(range(min = 1, max = 100, message = "Limit must be between 1 and 100"))]
+    /// Page number (default: 1)
+    #[validate(range(min = 1, max = MAX_PAGE, message = "Page must be between 1 and 1000000"))]
+    #[schema(example = 1)]
+    pub page: Option<i64>,
+
+    /// Results per page (default: 50); capped at the configured maximum
+    #[validate(range(min = 1, message = "Per page must be at least 1"))]
+    #[schema(example = 10)]
+    pub per_page: Option<i64>,
+
+    /// Deprecated alias for `per_page`, used when `per_page` is absent
+    #[validate(range(min = 1, message = "Limit must be at least 1"))]
     #[schema(example = 10)]
     pub limit: Option<i64>,
 
-    #[validate(range(min = 0, message = "Offset must be non-negative"))]
-    #[schema(example = 0)]
-    pub offset: Option<i64>,
+    /// Only return verified entries (default: false)
+    #[schema(example = false)]
+    pub verified_only: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -125,4 +135,134 @@ pub enum SearchType {
     English,
     Definition,
     All,
+    /// Trigram similarity on Pnar and English words, tolerant of misspellings
+    Fuzzy,
+}
+
+/// Anonymous suggestion for a new dictionary entry
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SuggestDictionaryEntryRequest {
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Pnar word must be between 1 and 255 characters"
+    ))]
+    #[schema(example = "ka")]
+    pub pnar_word: String,
+
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "English word must be between 1 and 255 characters"
+    ))]
+    #[schema(example = "go")]
+    pub english_word: String,
+
+    #[validate(length(max = 50, message = "Part of speech must be less than 50 characters"))]
+    #[schema(example = "verb")]
+    pub part_of_speech: Option<String>,
+
+    #[schema(example = "To move from one place to another")]
+    pub definition: Option<String>,
+    #[schema(example = "Nga ka noh")]
+    pub example_pnar: Option<String>,
+    #[schema(example = "I go home")]
+    pub example_english: Option<String>,
+
+    // Optional contact details so moderators can follow up
+    #[validate(length(max = 255, message = "Contact name must be less than 255 characters"))]
+    #[schema(example = "Jane Doe")]
+    pub contact_name: Option<String>,
+
+    #[validate(email(message = "Contact email must be a valid email address"))]
+    #[schema(example = "jane@example.com")]
+    pub contact_email: Option<String>,
+}
+
+/// Merge a duplicate dictionary entry into the one being kept
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MergeDictionaryEntriesRequest {
+    /// Entry that survives the merge
+    #[schema(example = "f47ac10b-58cc-4372-a567-0e02b2c3d479")]
+    pub keep_id: Uuid,
+    /// Duplicate entry that is folded into `keep_id` and deleted
+    #[schema(example = "9b2d7c1e-3f4a-4b5c-8d6e-7f8091a2b3c4")]
+    pub merge_id: Uuid,
+}
+
+/// Hand a dictionary entry over to another user
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReassignDictionaryEntryRequest {
+    #[schema(example = "f47ac10b-58cc-4372-a567-0e02b2c3d479")]
+    pub new_owner_id: Uuid,
+}
+
+/// Maximum number of ids accepted by a single batch lookup
+pub const MAX_ENTRIES_PER_BATCH_GET: usize = 100;
+
+/// Look up several dictionary entries at once
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BatchGetDictionaryEntriesRequest {
+    #[schema(example = json!(["f47ac10b-58cc-4372-a567-0e02b2c3d479"]))]
+    pub ids: Vec<Uuid>,
+}
+
+/// Maximum number of data rows accepted by a single CSV import
+pub const MAX_ENTRIES_PER_IMPORT: usize = 1000;
+
+/// Maximum size of an uploaded CSV file
+pub const MAX_IMPORT_FILE_BYTES: usize = 5 * 1024 * 1024;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn create_request(extra: serde_json::Value) -> CreateDictionaryEntryRequest {
+        let mut body = json!({ "pnar_word": "ka", "english_word": "go" });
+        body.as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        serde_json::from_value(body).unwrap()
+    }
+
+    #[test]
+    fn create_accepts_difficulty_in_range() {
+        let request = create_request(json!({ "difficulty_level": 5, "usage_frequency": 0 }));
+
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn create_rejects_difficulty_above_five() {
+        let errors = create_request(json!({ "difficulty_level": 7 }))
+            .validate()
+            .unwrap_err();
+
+        assert!(errors.field_errors().contains_key("difficulty_level"));
+    }
+
+    #[test]
+    fn create_rejects_negative_usage_frequency() {
+        let errors = create_request(json!({ "usage_frequency": -1 }))
+            .validate()
+            .unwrap_err();
+
+        assert!(errors.field_errors().contains_key("usage_frequency"));
+    }
+
+    #[test]
+    fn update_rejects_out_of_range_values() {
+        let request: UpdateDictionaryEntryRequest = serde_json::from_value(json!({
+            "difficulty_level": 7,
+            "usage_frequency": -1,
+            "version": 1
+        }))
+        .unwrap();
+
+        let errors = request.validate().unwrap_err();
+        let fields = errors.field_errors();
+        assert!(fields.contains_key("difficulty_level"));
+        assert!(fields.contains_key("usage_frequency"));
+    }
 }
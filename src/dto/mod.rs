@@ -1,3 +1,4 @@
+pub mod admin;
 pub mod analytics;
 pub mod auth;
 pub mod contribution;
@@ -7,6 +8,7 @@ pub mod responses;
 pub mod translation;
 pub mod user;
 
+pub use admin::*;
 pub use analytics::*;
 pub use auth::*;
 pub use contribution::*;
@@ -1,13 +1,21 @@
 use chrono::{DateTime, Utc};
 use serde::Serialize;
+use std::collections::BTreeMap;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+/// Zone of every timestamp the API returns; all of them serialize as RFC 3339
+/// with a `Z` suffix
+pub const RESPONSE_TIMEZONE: &str = "UTC";
+
 /// Standard API response wrapper
 #[derive(Debug, Serialize, ToSchema)]
 pub struct ApiResponse<T> {
     pub data: T,
     pub timestamp: DateTime<Utc>,
+    /// Always "UTC"
+    #[schema(example = "UTC")]
+    pub timezone: &'static str,
 }
 
 impl<T> ApiResponse<T> {
@@ -15,6 +23,7 @@ impl<T> ApiResponse<T> {
         Self {
             data,
             timestamp: Utc::now(),
+            timezone: RESPONSE_TIMEZONE,
         }
     }
 }
@@ -24,6 +33,9 @@ impl<T> ApiResponse<T> {
 pub struct SuccessResponse {
     pub data: String,
     pub timestamp: DateTime<Utc>,
+    /// Always "UTC"
+    #[schema(example = "UTC")]
+    pub timezone: &'static str,
 }
 
 impl SuccessResponse {
@@ -31,6 +43,7 @@ impl SuccessResponse {
         Self {
             data: message,
             timestamp: Utc::now(),
+            timezone: RESPONSE_TIMEZONE,
         }
     }
 }
@@ -106,7 +119,7 @@ impl UserApiResponse {
 }
 
 /// Dictionary entry response
-#[derive(Debug, Serialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct DictionaryEntryResponse {
     #[schema(example = "f47ac10b-58cc-4372-a567-0e02b2c3d479")]
     pub id: Uuid,
@@ -137,10 +150,117 @@ pub struct DictionaryEntryResponse {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub created_by: Option<Uuid>,
+    /// Incremented on every update; send it back to update the entry
+    #[schema(example = 1)]
+    pub version: i32,
+}
+
+/// Dictionary entry with its related words resolved (`?expand=related`)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DictionaryEntryWithRelatedResponse {
+    #[serde(flatten)]
+    pub entry: DictionaryEntryResponse,
+    pub related_entries: Vec<DictionaryEntryResponse>,
+}
+
+/// Anonymous dictionary suggestion awaiting (or after) moderator review
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DictionarySuggestionResponse {
+    pub id: Uuid,
+    #[schema(example = "ka")]
+    pub pnar_word: String,
+    #[schema(example = "go")]
+    pub english_word: String,
+    #[schema(example = "verb")]
+    pub part_of_speech: Option<String>,
+    pub definition: Option<String>,
+    pub example_pnar: Option<String>,
+    pub example_english: Option<String>,
+    pub contact_name: Option<String>,
+    pub contact_email: Option<String>,
+    /// Blocklisted terms found in the suggestion; non-empty means flagged
+    pub flagged_terms: Vec<String>,
+    #[schema(example = "pending")]
+    pub status: String,
+    /// Dictionary entry created when the suggestion was approved
+    pub entry_id: Option<Uuid>,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Aggregate dictionary statistics
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DictionaryStatsResponse {
+    #[schema(example = 1200)]
+    pub total_entries: i64,
+    #[schema(example = 950)]
+    pub verified_entries: i64,
+    #[schema(example = 250)]
+    pub unverified_entries: i64,
+    /// Entry counts keyed by part of speech ("unspecified" when missing)
+    pub by_part_of_speech: BTreeMap<String, i64>,
+    /// Number of entries with each optional field filled in, to spot curation gaps
+    pub completeness: BTreeMap<String, i64>,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// Number of dictionary entries matching a search
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SearchCountResponse {
+    #[schema(example = "go")]
+    pub query: String,
+    #[schema(example = 142)]
+    pub count: i64,
+}
+
+/// An existing entry that a proposed entry would collide with
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EntryConflict {
+    #[schema(example = "pnar_word")]
+    pub field: String,
+    #[schema(example = "ka")]
+    pub value: String,
+    pub existing_id: Uuid,
+}
+
+/// Outcome of validating a dictionary entry without saving it
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DictionaryValidationResponse {
+    /// Whether creating the entry would currently succeed
+    pub valid: bool,
+    pub conflicts: Vec<EntryConflict>,
+    /// Field validation messages, formatted as `field: message`
+    pub errors: Vec<String>,
+}
+
+/// Number of entries whose last update falls in an age range
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FreshnessBucket {
+    #[schema(example = "30_to_90_days")]
+    pub bucket: String,
+    #[schema(example = 120)]
+    pub count: i64,
+}
+
+/// Report of stale dictionary content for curators
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DictionaryFreshnessResponse {
+    #[schema(example = 1200)]
+    pub total_entries: i64,
+    /// Entry counts by time since last update, newest bucket first
+    pub by_last_update_age: Vec<FreshnessBucket>,
+    /// Unverified entries that have gone longest without an update
+    pub oldest_unverified: Vec<DictionaryEntryResponse>,
+    pub generated_at: DateTime<Utc>,
 }
 
 /// Paginated response
 #[derive(Debug, Serialize, ToSchema)]
+#[aliases(
+    UserSubmissionPaginatedResponse = PaginatedResponse<UserSubmission>,
+    PointsLedgerPaginatedResponse = PaginatedResponse<PointsLedgerEntry>
+)]
 pub struct PaginatedResponse<T> {
     pub data: Vec<T>,
     pub pagination: PaginationInfo,
@@ -153,29 +273,85 @@ pub struct PaginationInfo {
     pub page: i64,
     #[schema(example = 10)]
     pub per_page: i64,
+    /// Total matching rows; omitted when requested with `count=false`
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[schema(example = 100)]
-    pub total: i64,
+    pub total: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[schema(example = 10)]
-    pub pages: i64,
+    pub pages: Option<i64>,
+    #[schema(example = true)]
+    pub has_more: bool,
+}
+
+impl PaginationInfo {
+    pub fn counted(page: i64, per_page: i64, total: i64) -> Self {
+        let pages = (total + per_page - 1) / per_page; // Ceiling division
+
+        Self {
+            page,
+            per_page,
+            total: Some(total),
+            pages: Some(pages),
+            has_more: page < pages,
+        }
+    }
+
+    /// Pagination info for lightweight mode, where no total is computed
+    pub fn uncounted(page: i64, per_page: i64, has_more: bool) -> Self {
+        Self {
+            page,
+            per_page,
+            total: None,
+            pages: None,
+            has_more,
+        }
+    }
 }
 
 impl<T> PaginatedResponse<T> {
     pub fn new(data: Vec<T>, page: i64, per_page: i64, total: i64) -> Self {
-        let pages = (total + per_page - 1) / per_page; // Ceiling division
+        Self {
+            data,
+            pagination: PaginationInfo::counted(page, per_page, total),
+            timestamp: Utc::now(),
+        }
+    }
 
+    pub fn without_total(data: Vec<T>, page: i64, per_page: i64, has_more: bool) -> Self {
         Self {
             data,
-            pagination: PaginationInfo {
-                page,
-                per_page,
-                total,
-                pages,
-            },
+            pagination: PaginationInfo::uncounted(page, per_page, has_more),
             timestamp: Utc::now(),
         }
     }
 }
 
+/// One page of dictionary search results with the query echoed back
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DictionarySearchResponse {
+    #[schema(example = "go")]
+    pub query: String,
+    /// Number of entries matching the query across all pages
+    #[schema(example = 3)]
+    pub count: i64,
+    #[serde(flatten)]
+    pub results: DictionaryPaginatedResponse,
+}
+
+impl DictionarySearchResponse {
+    pub fn new(query: String, results: DictionaryPaginatedResponse) -> Self {
+        Self {
+            query,
+            count: results
+                .pagination
+                .total
+                .unwrap_or(results.data.len() as i64),
+            results,
+        }
+    }
+}
+
 /// Dictionary entries paginated response
 #[derive(Debug, Serialize, ToSchema)]
 pub struct DictionaryPaginatedResponse {
@@ -186,16 +362,22 @@ pub struct DictionaryPaginatedResponse {
 
 impl DictionaryPaginatedResponse {
     pub fn new(data: Vec<DictionaryEntryResponse>, page: i64, per_page: i64, total: i64) -> Self {
-        let pages = (total + per_page - 1) / per_page; // Ceiling division
+        Self {
+            data,
+            pagination: PaginationInfo::counted(page, per_page, total),
+            timestamp: Utc::now(),
+        }
+    }
 
+    pub fn without_total(
+        data: Vec<DictionaryEntryResponse>,
+        page: i64,
+        per_page: i64,
+        has_more: bool,
+    ) -> Self {
         Self {
             data,
-            pagination: PaginationInfo {
-                page,
-                per_page,
-                total,
-                pages,
-            },
+            pagination: PaginationInfo::uncounted(page, per_page, has_more),
             timestamp: Utc::now(),
         }
     }
@@ -211,16 +393,9 @@ pub struct UserPaginatedResponse {
 
 impl UserPaginatedResponse {
     pub fn new(data: Vec<UserResponse>, page: i64, per_page: i64, total: i64) -> Self {
-        let pages = (total + per_page - 1) / per_page; // Ceiling division
-
         Self {
             data,
-            pagination: PaginationInfo {
-                page,
-                per_page,
-                total,
-                pages,
-            },
+            pagination: PaginationInfo::counted(page, per_page, total),
             timestamp: Utc::now(),
         }
     }
@@ -236,16 +411,9 @@ pub struct TranslationPaginatedResponse {
 
 impl TranslationPaginatedResponse {
     pub fn new(data: Vec<TranslationResponse>, page: i64, per_page: i64, total: i64) -> Self {
-        let pages = (total + per_page - 1) / per_page; // Ceiling division
-
         Self {
             data,
-            pagination: PaginationInfo {
-                page,
-                per_page,
-                total,
-                pages,
-            },
+            pagination: PaginationInfo::counted(page, per_page, total),
             timestamp: Utc::now(),
         }
     }
@@ -261,16 +429,22 @@ pub struct ContributionPaginatedResponse {
 
 impl ContributionPaginatedResponse {
     pub fn new(data: Vec<ContributionResponse>, page: i64, per_page: i64, total: i64) -> Self {
-        let pages = (total + per_page - 1) / per_page; // Ceiling division
+        Self {
+            data,
+            pagination: PaginationInfo::counted(page, per_page, total),
+            timestamp: Utc::now(),
+        }
+    }
 
+    pub fn without_total(
+        data: Vec<ContributionResponse>,
+        page: i64,
+        per_page: i64,
+        has_more: bool,
+    ) -> Self {
         Self {
             data,
-            pagination: PaginationInfo {
-                page,
-                per_page,
-                total,
-                pages,
-            },
+            pagination: PaginationInfo::uncounted(page, per_page, has_more),
             timestamp: Utc::now(),
         }
     }
@@ -286,21 +460,152 @@ pub struct AnalyticsPaginatedResponse {
 
 impl AnalyticsPaginatedResponse {
     pub fn new(data: Vec<AnalyticsResponse>, page: i64, per_page: i64, total: i64) -> Self {
-        let pages = (total + per_page - 1) / per_page; // Ceiling division
-
         Self {
             data,
-            pagination: PaginationInfo {
-                page,
-                per_page,
-                total,
-                pages,
-            },
+            pagination: PaginationInfo::counted(page, per_page, total),
             timestamp: Utc::now(),
         }
     }
 }
 
+/// Maintenance mode status
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MaintenanceModeResponse {
+    #[schema(example = false)]
+    pub enabled: bool,
+    #[schema(example = 300)]
+    pub retry_after_seconds: u64,
+}
+
+/// Result of an index rebuild
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReindexResponse {
+    #[schema(example = json!(["pnar_dictionary", "dictionary_suggestions"]))]
+    pub tables: Vec<String>,
+    #[schema(example = 1250)]
+    pub duration_ms: u64,
+}
+
+/// Connection pool usage
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PoolStats {
+    /// Open connections, idle or in use
+    #[schema(example = 5)]
+    pub size: u32,
+    #[schema(example = 3)]
+    pub idle: u32,
+    #[schema(example = 20)]
+    pub max_connections: u32,
+}
+
+/// Row counts for the main content tables
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ContentCounts {
+    #[schema(example = 1200)]
+    pub dictionary_entries: i64,
+    #[schema(example = 950)]
+    pub verified_entries: i64,
+    #[schema(example = 340)]
+    pub users: i64,
+    #[schema(example = 2100)]
+    pub translations: i64,
+}
+
+/// Items waiting on a moderator or admin
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReviewQueueCounts {
+    #[schema(example = 12)]
+    pub pending_suggestions: i64,
+    #[schema(example = 250)]
+    pub unverified_entries: i64,
+    #[schema(example = 8)]
+    pub pending_translations: i64,
+    #[schema(example = 5)]
+    pub pending_contributions: i64,
+}
+
+/// System state at a glance for admins
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdminDashboardResponse {
+    #[schema(example = "0.1.0")]
+    pub version: String,
+    #[schema(example = 86400)]
+    pub uptime_seconds: u64,
+    #[schema(example = "connected")]
+    pub database: String,
+    pub pool: PoolStats,
+    /// Omitted when the database is unreachable
+    pub content: Option<ContentCounts>,
+    /// Omitted when the database is unreachable
+    pub review_queues: Option<ReviewQueueCounts>,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// A query that has been running on the database for a while
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DbActivity {
+    #[schema(example = 4242)]
+    pub pid: i32,
+    #[schema(example = "postgres")]
+    pub username: Option<String>,
+    #[schema(example = "")]
+    pub application_name: Option<String>,
+    #[schema(example = "10.0.0.12")]
+    pub client_addr: Option<String>,
+    #[schema(example = "active")]
+    pub state: Option<String>,
+    #[schema(example = "Lock")]
+    pub wait_event_type: Option<String>,
+    #[schema(example = "relation")]
+    pub wait_event: Option<String>,
+    pub query_start: DateTime<Utc>,
+    #[schema(example = 42.5)]
+    pub duration_seconds: f64,
+    /// Omitted when query text redaction is enabled
+    #[schema(example = "SELECT pg_sleep(60)")]
+    pub query: Option<String>,
+}
+
+/// Long-running queries on the application database
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DbActivityResponse {
+    #[schema(example = 5)]
+    pub min_duration_seconds: u64,
+    pub queries: Vec<DbActivity>,
+}
+
+/// Outcome of asking Postgres to cancel a backend's current query
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CancelQueryResponse {
+    #[schema(example = 4242)]
+    pub pid: i32,
+    /// Whether the cancel signal was delivered
+    #[schema(example = true)]
+    pub cancelled: bool,
+}
+
+/// Rows restored into one table by a backup import
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TableImportCounts {
+    #[schema(example = "pnar_dictionary")]
+    pub table: String,
+    #[schema(example = 40)]
+    pub inserted: i64,
+    #[schema(example = 2)]
+    pub updated: i64,
+    /// Rows already identical to the archive
+    #[schema(example = 1158)]
+    pub skipped: i64,
+}
+
+/// Result of restoring a backup archive
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BackupImportResponse {
+    #[schema(example = 20261018150000_i64)]
+    pub schema_version: i64,
+    pub tables: Vec<TableImportCounts>,
+}
+
 /// Health check response
 #[derive(Debug, Serialize, ToSchema)]
 pub struct HealthResponse {
@@ -333,6 +638,36 @@ impl HealthResponse {
     }
 }
 
+/// Readiness probe response
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReadinessResponse {
+    #[schema(example = "not_ready")]
+    pub status: String,
+    /// Why traffic should not be routed here yet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = "startup grace period has 12s left")]
+    pub reason: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl ReadinessResponse {
+    pub fn ready() -> Self {
+        Self {
+            status: "ready".to_string(),
+            reason: None,
+            timestamp: Utc::now(),
+        }
+    }
+
+    pub fn not_ready(reason: impl Into<String>) -> Self {
+        Self {
+            status: "not_ready".to_string(),
+            reason: Some(reason.into()),
+            timestamp: Utc::now(),
+        }
+    }
+}
+
 /// Translation request response
 #[derive(Debug, Serialize, ToSchema)]
 pub struct TranslationResponse {
@@ -364,6 +699,45 @@ pub struct TranslationResponse {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Notification as included in a user's data export
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationResponse {
+    pub id: Uuid,
+    #[schema(example = "dictionary_suggestion")]
+    pub notification_type: String,
+    pub title: String,
+    pub message: String,
+    pub data: Option<serde_json::Value>,
+    pub read: bool,
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// Summary of a user's unread notifications since a cutoff
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationDigestResponse {
+    pub since: DateTime<Utc>,
+    #[schema(example = 7)]
+    pub unread_count: i64,
+    /// Unread counts keyed by notification type
+    pub by_type: BTreeMap<String, i64>,
+    /// Most recent unread notifications, newest first
+    pub latest: Vec<NotificationResponse>,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// Everything stored about a single user, for data portability requests
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserDataExport {
+    pub user: UserResponse,
+    pub contributions: Vec<ContributionResponse>,
+    pub translations: Vec<TranslationResponse>,
+    pub dictionary_entries: Vec<DictionaryEntryResponse>,
+    pub notifications: Vec<NotificationResponse>,
+    pub exported_at: DateTime<Utc>,
+}
+
 /// User contribution response
 #[derive(Debug, Serialize, ToSchema)]
 pub struct ContributionResponse {
@@ -386,6 +760,8 @@ pub struct ContributionResponse {
     #[schema(example = "approved")]
     pub status: String,
     pub reviewed_by: Option<Uuid>,
+    #[schema(example = "moderator@example.com")]
+    pub reviewed_by_email: Option<String>,
     pub reviewed_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
 }
@@ -408,3 +784,82 @@ pub struct AnalyticsResponse {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+/// Single item of a user's activity feed
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ActivityItem {
+    #[schema(example = "contribution")]
+    pub kind: String,
+    #[schema(example = "f47ac10b-58cc-4372-a567-0e02b2c3d479")]
+    pub ref_id: Uuid,
+    #[schema(example = "dictionary_entry create on pnar_dictionary")]
+    pub summary: String,
+    pub at: DateTime<Utc>,
+}
+
+/// Content a user submitted, for vetting by moderators
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UserSubmission {
+    /// "dictionary_entry", "translation" or "contribution"
+    #[schema(example = "dictionary_entry")]
+    pub kind: String,
+    #[schema(example = "f47ac10b-58cc-4372-a567-0e02b2c3d479")]
+    pub ref_id: Uuid,
+    #[schema(example = "ka: go")]
+    pub summary: String,
+    /// Review state; dictionary entries report "verified" or "unverified"
+    #[schema(example = "unverified")]
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// One change to a user's `translation_points`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PointsLedgerEntry {
+    #[schema(example = "f47ac10b-58cc-4372-a567-0e02b2c3d479")]
+    pub contribution_id: Uuid,
+    /// "impact" (lookups of the user's entries) or "points_adjustment" (by an admin)
+    #[schema(example = "impact")]
+    pub source: String,
+    /// "award" or "deduct"
+    #[schema(example = "award")]
+    pub action: String,
+    /// Signed change to the running total
+    #[schema(example = 5)]
+    pub points: i32,
+    /// Context recorded with the change, e.g. the admin's reason
+    pub details: Option<serde_json::Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Outcome of a batch operation: created items plus per-row failures
+#[derive(Debug, Serialize, ToSchema)]
+#[aliases(
+    UserBatchResult = BatchResult<UserResponse>,
+    DictionaryBatchResult = BatchResult<DictionaryEntryResponse>
+)]
+pub struct BatchResult<T> {
+    pub created: Vec<T>,
+    pub failed: Vec<BatchError>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl<T> BatchResult<T> {
+    pub fn new(created: Vec<T>, failed: Vec<BatchError>) -> Self {
+        Self {
+            created,
+            failed,
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+/// A single failed row of a batch operation
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatchError {
+    /// Zero-based position of the row in the submitted batch
+    #[schema(example = 2)]
+    pub index: usize,
+    #[schema(example = "User with this email already exists")]
+    pub message: String,
+}
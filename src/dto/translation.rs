@@ -62,4 +62,20 @@ pub struct UpdateTranslationRequest {
     pub reviewed: Option<bool>,
 
     pub metadata: Option<serde_json::Value>,
+
+    /// Merge `metadata` into the stored object key by key (top level) instead
+    /// of replacing it (default: false)
+    #[schema(example = false)]
+    pub merge_metadata: Option<bool>,
+}
+
+/// Autosaved, possibly partial, translated text
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SaveTranslationDraftRequest {
+    #[validate(length(
+        max = 5000,
+        message = "Translated text must be at most 5000 characters"
+    ))]
+    #[schema(example = "Kumno")]
+    pub translated_text: String,
 }
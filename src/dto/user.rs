@@ -49,6 +49,37 @@ pub struct CreateUserRequest {
     pub is_active: Option<bool>,
 }
 
+/// Maximum number of users accepted by a single batch request
+pub const MAX_USERS_PER_BATCH: usize = 100;
+
+/// Batch user creation request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateUsersBatchRequest {
+    pub users: Vec<CreateUserRequest>,
+}
+
+/// One row of a bulk role assignment
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RoleAssignment {
+    #[validate(email(message = "Invalid email format"))]
+    #[schema(example = "translator@example.com")]
+    pub email: String,
+
+    #[validate(length(
+        min = 2,
+        max = 20,
+        message = "Role must be between 2 and 20 characters"
+    ))]
+    #[schema(example = "translator")]
+    pub role: String,
+}
+
+/// Bulk role assignment request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BulkUpdateRolesRequest {
+    pub assignments: Vec<RoleAssignment>,
+}
+
 /// Update user request
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct UpdateUserRequest {
@@ -112,7 +143,8 @@ pub struct UserQueryParams {
     #[schema(example = 1)]
     pub page: Option<i64>,
 
-    #[validate(range(min = 1, max = 100, message = "Per page must be between 1 and 100"))]
+    /// Clamped to the configured maximum page size
+    #[validate(range(min = 1, message = "Per page must be at least 1"))]
     #[schema(example = 10)]
     pub per_page: Option<i64>,
 
@@ -122,6 +154,8 @@ pub struct UserQueryParams {
     pub is_email_verified: Option<bool>,
     #[schema(example = "john")]
     pub search: Option<String>, // Search in email or full_name
+    /// Set to false to skip the total count and only report `has_more`
+    pub count: Option<bool>,
 }
 
 /// Award points request
@@ -139,3 +173,11 @@ pub struct AwardPointsRequest {
     #[schema(example = "Good translation work")]
     pub reason: String,
 }
+
+/// Activity feed query parameters
+#[derive(Debug, Deserialize, Validate, ToSchema, IntoParams)]
+pub struct ActivityQueryParams {
+    #[validate(range(min = 1, max = 100, message = "Limit must be between 1 and 100"))]
+    #[schema(example = 20)]
+    pub limit: Option<i64>,
+}
@@ -1,5 +1,11 @@
-use actix_web::{HttpResponse, ResponseError};
+use crate::dto::responses::RESPONSE_TIMEZONE;
+use actix_web::{http::header::RETRY_AFTER, HttpResponse, ResponseError};
+use chrono::SecondsFormat;
 use serde_json::json;
+use uuid::Uuid;
+
+/// Seconds clients are asked to wait after the connection pool was exhausted
+const POOL_TIMEOUT_RETRY_AFTER_SECONDS: u64 = 5;
 
 /// Application-wide error types
 #[derive(thiserror::Error, Debug)]
@@ -28,6 +34,21 @@ pub enum AppError {
     #[error("Conflict: {0}")]
     Conflict(String),
 
+    #[error("Conflict: {message}")]
+    DuplicateResource {
+        message: String,
+        conflict_field: String,
+        conflicting_value: String,
+        existing_id: Option<Uuid>,
+    },
+
+    #[error("Too many requests: {0}")]
+    TooManyRequests(String),
+
+    /// Writes are paused; clients should retry after `retry_after_seconds`
+    #[error("The API is in maintenance mode; only read requests are accepted")]
+    Maintenance { retry_after_seconds: u64 },
+
     #[error("Internal server error: {0}")]
     Internal(String),
 
@@ -41,9 +62,10 @@ pub enum AppError {
     PasswordHash(String),
 }
 
-impl ResponseError for AppError {
-    fn error_response(&self) -> HttpResponse {
-        let (status, error_code, message) = match self {
+impl AppError {
+    /// HTTP status, machine-readable code and client-facing message
+    fn parts(&self) -> (actix_web::http::StatusCode, &'static str, String) {
+        match self {
             AppError::Authentication(_) => (
                 actix_web::http::StatusCode::UNAUTHORIZED,
                 "AUTH_ERROR",
@@ -74,11 +96,28 @@ impl ResponseError for AppError {
                 "NOT_FOUND",
                 self.to_string(),
             ),
-            AppError::Conflict(_) => (
+            AppError::Conflict(_) | AppError::DuplicateResource { .. } => (
                 actix_web::http::StatusCode::CONFLICT,
                 "CONFLICT",
                 self.to_string(),
             ),
+            AppError::TooManyRequests(_) => (
+                actix_web::http::StatusCode::TOO_MANY_REQUESTS,
+                "TOO_MANY_REQUESTS",
+                self.to_string(),
+            ),
+            AppError::Maintenance { .. } => (
+                actix_web::http::StatusCode::SERVICE_UNAVAILABLE,
+                "MAINTENANCE_MODE",
+                self.to_string(),
+            ),
+            // Every connection stayed busy for the whole acquire timeout; this
+            // is load, not a fault, so ask clients to back off and retry
+            AppError::Database(sqlx::Error::PoolTimedOut) => (
+                actix_web::http::StatusCode::SERVICE_UNAVAILABLE,
+                "SERVICE_UNAVAILABLE",
+                "The service is temporarily overloaded, please retry shortly".to_string(),
+            ),
             AppError::Database(_) | AppError::Internal(_) | AppError::Config(_) => (
                 actix_web::http::StatusCode::INTERNAL_SERVER_ERROR,
                 "INTERNAL_ERROR",
@@ -94,15 +133,79 @@ impl ResponseError for AppError {
                 "PASSWORD_ERROR",
                 "Password processing error".to_string(),
             ),
-        };
+        }
+    }
+
+    /// Seconds to send in `Retry-After`, for errors that are worth retrying
+    fn retry_after(&self) -> Option<u64> {
+        match self {
+            AppError::Database(sqlx::Error::PoolTimedOut) => Some(POOL_TIMEOUT_RETRY_AFTER_SECONDS),
+            AppError::Maintenance {
+                retry_after_seconds,
+            } => Some(*retry_after_seconds),
+            _ => None,
+        }
+    }
 
-        HttpResponse::build(status).json(json!({
+    /// Render the error as `text/plain` for clients that don't accept JSON
+    pub fn plain_text_response(&self) -> HttpResponse {
+        let (status, error_code, message) = self.parts();
+
+        let mut response = HttpResponse::build(status);
+        if let Some(seconds) = self.retry_after() {
+            response.insert_header((RETRY_AFTER, seconds));
+        }
+
+        response
+            .content_type("text/plain; charset=utf-8")
+            .body(format!("{}: {}\n", error_code, message))
+    }
+}
+
+impl ResponseError for AppError {
+    fn error_response(&self) -> HttpResponse {
+        self.json_response(None)
+    }
+}
+
+impl AppError {
+    /// Render the JSON error body, tagged with the request's trace id when known
+    pub fn json_response(&self, trace_id: Option<&str>) -> HttpResponse {
+        let (status, error_code, message) = self.parts();
+
+        let mut body = json!({
             "error": {
                 "code": error_code,
                 "message": message,
-                "timestamp": chrono::Utc::now().to_rfc3339()
+                "timestamp": chrono::Utc::now().to_rfc3339_opts(SecondsFormat::AutoSi, true),
+                "timezone": RESPONSE_TIMEZONE
             }
-        }))
+        });
+
+        if let AppError::DuplicateResource {
+            conflict_field,
+            conflicting_value,
+            existing_id,
+            ..
+        } = self
+        {
+            body["error"]["details"] = json!({
+                "conflict_field": conflict_field,
+                "conflicting_value": conflicting_value,
+                "existing_id": existing_id
+            });
+        }
+
+        if let Some(trace_id) = trace_id {
+            body["error"]["trace_id"] = json!(trace_id);
+        }
+
+        let mut response = HttpResponse::build(status);
+        if let Some(seconds) = self.retry_after() {
+            response.insert_header((RETRY_AFTER, seconds));
+        }
+
+        response.json(body)
     }
 }
 
@@ -116,24 +219,26 @@ impl From<argon2::password_hash::Error> for AppError {
     }
 }
 
+/// Flatten validation errors into `field: message` strings
+pub fn validation_messages(err: &validator::ValidationErrors) -> Vec<String> {
+    err.field_errors()
+        .into_iter()
+        .flat_map(|(field, errors)| {
+            errors.iter().map(move |error| {
+                format!(
+                    "{}: {}",
+                    field,
+                    error.message.as_ref().unwrap_or(&"Invalid value".into())
+                )
+            })
+        })
+        .collect()
+}
+
 /// Convert validation errors to AppError
 impl From<validator::ValidationErrors> for AppError {
     fn from(err: validator::ValidationErrors) -> Self {
-        let error_messages: Vec<String> = err
-            .field_errors()
-            .into_iter()
-            .flat_map(|(field, errors)| {
-                errors.iter().map(move |error| {
-                    format!(
-                        "{}: {}",
-                        field,
-                        error.message.as_ref().unwrap_or(&"Invalid value".into())
-                    )
-                })
-            })
-            .collect();
-
-        AppError::Validation(error_messages.join("; "))
+        AppError::Validation(validation_messages(&err).join("; "))
     }
 }
 
@@ -150,3 +255,41 @@ impl From<sqlx::migrate::MigrateError> for AppError {
         AppError::Internal(format!("Migration error: {}", err))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::body::to_bytes;
+
+    #[actix_web::test]
+    async fn error_timestamp_is_marked_as_utc() {
+        let response = AppError::NotFound("Nothing here".to_string()).error_response();
+
+        let body: serde_json::Value =
+            serde_json::from_slice(&to_bytes(response.into_body()).await.unwrap()).unwrap();
+        let timestamp = body["error"]["timestamp"].as_str().unwrap();
+        assert!(timestamp.ends_with('Z'), "{}", timestamp);
+        assert!(chrono::DateTime::parse_from_rfc3339(timestamp).is_ok());
+        assert_eq!(body["error"]["timezone"], "UTC");
+    }
+
+    #[actix_web::test]
+    async fn message_variants_carry_a_dynamic_message() {
+        let id = Uuid::new_v4();
+        let cases = [
+            (AppError::NotFound(format!("Entry {} not found", id)), 404),
+            (AppError::Conflict(format!("Entry {} changed", id)), 409),
+            (AppError::Forbidden(format!("Entry {} is locked", id)), 403),
+        ];
+
+        for (error, status) in cases {
+            let response = error.error_response();
+
+            assert_eq!(response.status().as_u16(), status);
+            let body: serde_json::Value =
+                serde_json::from_slice(&to_bytes(response.into_body()).await.unwrap()).unwrap();
+            let message = body["error"]["message"].as_str().unwrap();
+            assert!(message.contains(&id.to_string()), "{}", message);
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use crate::{
+    config::Settings,
+    database,
+    dto::{
+        responses::{
+            AdminDashboardResponse, CancelQueryResponse, DbActivityResponse,
+            MaintenanceModeResponse, ReindexResponse,
+        },
+        MaintenanceModeRequest,
+    },
+    error::AppError,
+    metrics,
+    middleware::{
+        auth::AdminUser, concurrency::ExpensiveRequestPermit, maintenance::MaintenanceMode,
+    },
+    services::{
+        admin_service,
+        backup_service::{self, ArchiveImporter},
+    },
+    utils::password,
+};
+use actix_web::{
+    get,
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    post, put,
+    web::{self, Bytes},
+    HttpResponse,
+};
+use futures_util::{StreamExt, TryStreamExt};
+use serde::Deserialize;
+use sqlx::PgPool;
+use std::time::Instant;
+use tracing::warn;
+use utoipa::IntoParams;
+
+/// Health, pool usage, content counts and review queue sizes in one payload
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/dashboard",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "System dashboard", body = AdminDashboardResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required")
+    )
+)]
+#[get("/dashboard")]
+pub async fn dashboard(
+    pool: web::Data<PgPool>,
+    _admin_user: AdminUser,
+) -> Result<HttpResponse, AppError> {
+    let connected = database::health_check(&pool).await.is_ok();
+
+    let (content, review_queues) = if connected {
+        (
+            Some(admin_service::content_counts(&pool).await?),
+            Some(admin_service::review_queue_counts(&pool).await?),
+        )
+    } else {
+        (None, None)
+    };
+
+    Ok(HttpResponse::Ok().json(AdminDashboardResponse {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        uptime_seconds: metrics::uptime().as_secs(),
+        database: if connected {
+            "connected"
+        } else {
+            "disconnected"
+        }
+        .to_string(),
+        pool: database::pool_stats(&pool),
+        content,
+        review_queues,
+        generated_at: chrono::Utc::now(),
+    }))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct DbActivityQuery {
+    /// Only list queries running at least this many seconds (default from configuration)
+    pub min_duration_seconds: Option<u64>,
+}
+
+/// Queries that have been running on the database longer than a threshold
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/db/activity",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    params(DbActivityQuery),
+    responses(
+        (status = 200, description = "Long-running queries, longest first", body = DbActivityResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required")
+    )
+)]
+#[get("/db/activity")]
+pub async fn db_activity(
+    pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
+    query: web::Query<DbActivityQuery>,
+    _admin_user: AdminUser,
+) -> Result<HttpResponse, AppError> {
+    let min_duration_seconds = query
+        .min_duration_seconds
+        .unwrap_or(settings.monitoring.long_running_query_seconds);
+
+    let queries = admin_service::long_running_queries(
+        &pool,
+        min_duration_seconds,
+        settings.monitoring.redact_query_text,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(DbActivityResponse {
+        min_duration_seconds,
+        queries,
+    }))
+}
+
+/// Cancel the current query of a database backend
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/db/cancel/{pid}",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    params(("pid" = i32, Path, description = "Backend process ID from the activity listing")),
+    responses(
+        (status = 200, description = "Cancel signal sent", body = CancelQueryResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 404, description = "No client backend with that pid on this database")
+    )
+)]
+#[post("/db/cancel/{pid}")]
+pub async fn cancel_db_query(
+    pool: web::Data<PgPool>,
+    path: web::Path<i32>,
+    admin_user: AdminUser,
+) -> Result<HttpResponse, AppError> {
+    let pid = path.into_inner();
+    let cancelled = admin_service::cancel_query(&pool, pid).await?;
+    warn!(
+        "Query cancel for backend {} requested by user {} (delivered: {})",
+        pid, admin_user.0.user_id, cancelled
+    );
+
+    Ok(HttpResponse::Ok().json(CancelQueryResponse { pid, cancelled }))
+}
+
+/// Get the current maintenance mode status
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/maintenance",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Maintenance mode status", body = MaintenanceModeResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required")
+    )
+)]
+#[get("/maintenance")]
+pub async fn get_maintenance_mode(
+    maintenance: web::Data<MaintenanceMode>,
+    _admin_user: AdminUser,
+) -> Result<HttpResponse, AppError> {
+    Ok(HttpResponse::Ok().json(status(&maintenance)))
+}
+
+/// Turn maintenance mode on or off
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/maintenance",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    request_body = MaintenanceModeRequest,
+    responses(
+        (status = 200, description = "Maintenance mode updated", body = MaintenanceModeResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required")
+    )
+)]
+#[put("/maintenance")]
+pub async fn set_maintenance_mode(
+    maintenance: web::Data<MaintenanceMode>,
+    request: web::Json<MaintenanceModeRequest>,
+    admin_user: AdminUser,
+) -> Result<HttpResponse, AppError> {
+    maintenance.set_enabled(request.enabled);
+    warn!(
+        "Maintenance mode {} by user {}",
+        if request.enabled {
+            "enabled"
+        } else {
+            "disabled"
+        },
+        admin_user.0.user_id
+    );
+
+    Ok(HttpResponse::Ok().json(status(&maintenance)))
+}
+
+/// Rebuild search indexes and refresh planner statistics
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/reindex",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Indexes rebuilt", body = ReindexResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 409, description = "A reindex is already in progress")
+    )
+)]
+#[post("/reindex")]
+pub async fn reindex(
+    pool: web::Data<PgPool>,
+    admin_user: AdminUser,
+) -> Result<HttpResponse, AppError> {
+    warn!("Reindex started by user {}", admin_user.0.user_id);
+
+    let started = Instant::now();
+    let tables = database::reindex_search_tables(&pool).await?;
+
+    Ok(HttpResponse::Ok().json(ReindexResponse {
+        tables,
+        duration_ms: started.elapsed().as_millis() as u64,
+    }))
+}
+
+/// Download a point-in-time NDJSON backup of roles, users and the dictionary
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/export",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "NDJSON archive: a manifest line, then one line per row", content_type = "application/x-ndjson"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 429, description = "Too many concurrent expensive requests for this user")
+    )
+)]
+#[get("/export")]
+pub async fn export(
+    pool: web::Data<PgPool>,
+    admin_user: AdminUser,
+    permit: ExpensiveRequestPermit,
+) -> Result<HttpResponse, AppError> {
+    warn!("Backup export started by user {}", admin_user.0.user_id);
+
+    let archive = backup_service::export_archive(&pool).await?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("application/x-ndjson")
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(
+                "pnar-world-backup.ndjson".to_string(),
+            )],
+        })
+        // The permit rides along with the stream until the download ends
+        .streaming(archive.map_ok(move |line| {
+            let _ = &permit;
+            Bytes::from(line)
+        })))
+}
+
+/// Longest single archive line accepted by [`import`]
+const MAX_IMPORT_LINE_BYTES: usize = 1024 * 1024;
+
+/// Restore an NDJSON backup produced by the export endpoint
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/import",
+    tag = "admin",
+    security(("bearer_auth" = [])),
+    request_body(content = String, description = "NDJSON archive from GET /api/v1/admin/export", content_type = "application/x-ndjson"),
+    responses(
+        (status = 200, description = "Archive restored", body = BackupImportResponse),
+        (status = 400, description = "Malformed or incomplete archive"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 409, description = "Archive was exported from a different schema version")
+    )
+)]
+#[post("/import")]
+pub async fn import(
+    pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
+    admin_user: AdminUser,
+    mut payload: web::Payload,
+) -> Result<HttpResponse, AppError> {
+    warn!("Backup import started by user {}", admin_user.0.user_id);
+
+    // Restored accounts get a random password nobody knows until they reset it
+    let placeholder_password_hash =
+        password::hash_password(&settings.security, &uuid::Uuid::new_v4().to_string())?;
+    let mut importer = ArchiveImporter::begin(&pool, placeholder_password_hash).await?;
+
+    let mut buffer = Vec::new();
+    while let Some(chunk) = payload.next().await {
+        let chunk =
+            chunk.map_err(|e| AppError::Validation(format!("Failed to read archive: {}", e)))?;
+        buffer.extend_from_slice(&chunk);
+
+        while let Some(end) = buffer.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = buffer.drain(..=end).collect();
+            importer.import_line(&line).await?;
+        }
+
+        if buffer.len() > MAX_IMPORT_LINE_BYTES {
+            return Err(AppError::Validation(format!(
+                "Archive lines must be at most {} bytes",
+                MAX_IMPORT_LINE_BYTES
+            )));
+        }
+    }
+    importer.import_line(&buffer).await?;
+
+    let report = importer.finish().await?;
+
+    Ok(HttpResponse::Ok().json(report))
+}
+
+fn status(maintenance: &MaintenanceMode) -> MaintenanceModeResponse {
+    MaintenanceModeResponse {
+        enabled: maintenance.is_enabled(),
+        retry_after_seconds: maintenance.retry_after_seconds(),
+    }
+}
@@ -1,13 +1,16 @@
 use actix_web::{web, HttpResponse, Result};
+use chrono::{DateTime, Duration, Utc};
 use serde::Deserialize;
 use utoipa::IntoParams;
 use uuid::Uuid;
+use validator::Validate;
 
 use crate::{
+    config::Settings,
     dto::{CreateAnalyticsRequest, UpdateAnalyticsRequest},
     error::AppError,
     middleware::auth::AuthenticatedUser,
-    services::analytics_service,
+    services::analytics_service::{self, AnalyticsFilter},
 };
 
 #[derive(Deserialize, IntoParams)]
@@ -16,12 +19,23 @@ pub struct AnalyticsQueryParams {
     pub per_page: Option<i64>,
     pub user_id: Option<Uuid>,
     pub word_id: Option<Uuid>,
+    #[serde(alias = "usage_type")]
     pub event_type: Option<String>,
+    /// Only records at or after this time
+    pub from: Option<DateTime<Utc>>,
+    /// Only records before this time
+    pub to: Option<DateTime<Utc>>,
 }
 
 #[derive(Deserialize, IntoParams)]
 pub struct WordStatsParams {
     pub user_id: Option<Uuid>,
+    /// Start of the range (inclusive, default: 90 days before `to`)
+    pub from: Option<DateTime<Utc>>,
+    /// End of the range (exclusive, default: now)
+    pub to: Option<DateTime<Utc>>,
+    /// Maximum number of days to return, each with all its event types (default: 100, max: 1000)
+    pub limit: Option<i64>,
 }
 
 /// Create a new analytics record
@@ -42,9 +56,16 @@ pub struct WordStatsParams {
 )]
 pub async fn create_analytics(
     pool: web::Data<sqlx::PgPool>,
+    settings: web::Data<Settings>,
     user: AuthenticatedUser,
     req: web::Json<CreateAnalyticsRequest>,
 ) -> Result<HttpResponse, AppError> {
+    req.validate()?;
+    analytics_service::check_metadata_size(
+        req.metadata.as_ref(),
+        settings.analytics.max_metadata_bytes,
+    )?;
+
     let analytics = analytics_service::create_analytics_record(
         pool.get_ref(),
         Some(user.user_id),
@@ -69,8 +90,15 @@ pub async fn create_analytics(
 )]
 pub async fn create_anonymous_analytics(
     pool: web::Data<sqlx::PgPool>,
+    settings: web::Data<Settings>,
     req: web::Json<CreateAnalyticsRequest>,
 ) -> Result<HttpResponse, AppError> {
+    req.validate()?;
+    analytics_service::check_metadata_size(
+        req.metadata.as_ref(),
+        settings.analytics.max_metadata_bytes,
+    )?;
+
     let analytics =
         analytics_service::create_analytics_record(pool.get_ref(), None, req.into_inner()).await?;
 
@@ -123,28 +151,38 @@ pub async fn get_analytics(
 )]
 pub async fn list_analytics(
     pool: web::Data<sqlx::PgPool>,
+    settings: web::Data<Settings>,
     user: AuthenticatedUser,
     query: web::Query<AnalyticsQueryParams>,
 ) -> Result<HttpResponse, AppError> {
-    let page = query.page.unwrap_or(1);
-    let per_page = query.per_page.unwrap_or(20);
+    let (page, per_page) = settings.pagination.resolve(query.page, query.per_page);
 
-    // Only allow viewing all user analytics if user is admin
-    let user_id = if user.role == "admin" {
+    // Moderators may filter by any user; everyone else only sees their own records
+    let user_id = if user.is_moderator() {
         query.user_id
     } else {
         Some(user.user_id)
     };
 
-    let analytics = analytics_service::list_analytics_records(
-        pool.get_ref(),
+    if let (Some(from), Some(to)) = (query.from, query.to) {
+        if from > to {
+            return Err(AppError::Validation(
+                "from must not be later than to".to_string(),
+            ));
+        }
+    }
+
+    let query = query.into_inner();
+    let filter = AnalyticsFilter {
         user_id,
-        query.word_id,
-        query.event_type.clone(),
-        page,
-        per_page,
-    )
-    .await?;
+        word_id: query.word_id,
+        event_type: query.event_type,
+        from: query.from,
+        to: query.to,
+    };
+
+    let analytics =
+        analytics_service::list_analytics_records(pool.get_ref(), filter, page, per_page).await?;
 
     Ok(HttpResponse::Ok().json(analytics))
 }
@@ -170,10 +208,16 @@ pub async fn list_analytics(
 )]
 pub async fn update_analytics(
     pool: web::Data<sqlx::PgPool>,
+    settings: web::Data<Settings>,
     _user: AuthenticatedUser,
     path: web::Path<Uuid>,
     req: web::Json<UpdateAnalyticsRequest>,
 ) -> Result<HttpResponse, AppError> {
+    analytics_service::check_metadata_size(
+        req.metadata.as_ref(),
+        settings.analytics.max_metadata_bytes,
+    )?;
+
     let analytics = analytics_service::update_analytics_record(
         pool.get_ref(),
         path.into_inner(),
@@ -223,6 +267,7 @@ pub async fn delete_analytics(
     ),
     responses(
         (status = 200, description = "Word usage statistics retrieved successfully", body = serde_json::Value),
+        (status = 400, description = "Invalid date range"),
         (status = 404, description = "Word not found"),
         (status = 401, description = "Unauthorized"),
         (status = 500, description = "Internal server error")
@@ -244,8 +289,24 @@ pub async fn get_word_stats(
         Some(user.user_id)
     };
 
-    let stats =
-        analytics_service::get_word_usage_stats(pool.get_ref(), path.into_inner(), user_id).await?;
+    let to = query.to.unwrap_or_else(Utc::now);
+    let from = query.from.unwrap_or(to - Duration::days(90));
+    if from > to {
+        return Err(AppError::Validation(
+            "from must not be later than to".to_string(),
+        ));
+    }
+    let limit = query.limit.unwrap_or(100).clamp(1, 1000);
+
+    let stats = analytics_service::get_word_usage_stats(
+        pool.get_ref(),
+        path.into_inner(),
+        user_id,
+        from,
+        to,
+        limit,
+    )
+    .await?;
 
     Ok(HttpResponse::Ok().json(stats))
 }
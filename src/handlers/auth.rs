@@ -1,10 +1,17 @@
 use crate::{
-    dto::{responses::AuthApiResponse, ApiResponse, LoginRequest, RegisterRequest},
+    config::{JwtSettings, Settings},
+    dto::{
+        responses::{AuthApiResponse, AuthResponse},
+        ApiResponse, LoginRequest, RegisterRequest,
+    },
     error::AppError,
     middleware::auth::AuthenticatedUser,
     services::{auth_service, user_service},
 };
-use actix_web::{get, post, web, HttpResponse};
+use actix_web::{
+    cookie::{time::Duration, Cookie, SameSite},
+    get, post, web, HttpResponse, HttpResponseBuilder,
+};
 use sqlx::PgPool;
 use validator::Validate;
 
@@ -22,13 +29,24 @@ use validator::Validate;
 #[post("/register")]
 pub async fn register(
     pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
     request: web::Json<RegisterRequest>,
 ) -> Result<HttpResponse, AppError> {
     request.validate()?;
 
-    let auth_response = auth_service::register_user(&pool, request.into_inner()).await?;
+    let auth_response = auth_service::register_user(
+        &pool,
+        &settings.jwt,
+        &settings.security,
+        request.into_inner(),
+    )
+    .await?;
 
-    Ok(HttpResponse::Created().json(AuthApiResponse::new(auth_response)))
+    let mut response = HttpResponse::Created();
+    Ok(
+        with_session_cookie(&mut response, &settings.jwt, &auth_response)
+            .json(AuthApiResponse::new(auth_response)),
+    )
 }
 
 #[utoipa::path(
@@ -45,13 +63,55 @@ pub async fn register(
 #[post("/login")]
 pub async fn login(
     pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
     request: web::Json<LoginRequest>,
 ) -> Result<HttpResponse, AppError> {
     request.validate()?;
 
-    let auth_response = auth_service::login_user(&pool, request.into_inner()).await?;
+    let auth_response = auth_service::login_user(
+        &pool,
+        &settings.jwt,
+        &settings.security,
+        request.into_inner(),
+    )
+    .await?;
+
+    let mut response = HttpResponse::Ok();
+    Ok(
+        with_session_cookie(&mut response, &settings.jwt, &auth_response)
+            .json(AuthApiResponse::new(auth_response)),
+    )
+}
+
+/// Build the session cookie carrying `value`, per the JWT cookie settings
+fn session_cookie(settings: &JwtSettings, value: String) -> Cookie<'static> {
+    let mut cookie = Cookie::build(settings.cookie_name.clone(), value)
+        .path("/")
+        .http_only(true)
+        .secure(settings.cookie_secure)
+        .same_site(SameSite::Lax)
+        .finish();
+
+    if let Some(domain) = &settings.cookie_domain {
+        cookie.set_domain(domain.clone());
+    }
 
-    Ok(HttpResponse::Ok().json(AuthApiResponse::new(auth_response)))
+    cookie
+}
+
+/// Set the access token cookie when cookie auth is enabled
+fn with_session_cookie<'a>(
+    response: &'a mut HttpResponseBuilder,
+    settings: &JwtSettings,
+    auth: &AuthResponse,
+) -> &'a mut HttpResponseBuilder {
+    if settings.cookie_auth {
+        let mut cookie = session_cookie(settings, auth.access_token.clone());
+        cookie.set_max_age(Duration::seconds(auth.expires_in));
+        response.cookie(cookie);
+    }
+
+    response
 }
 
 #[utoipa::path(
@@ -67,10 +127,20 @@ pub async fn login(
     )
 )]
 #[post("/logout")]
-pub async fn logout(_user: AuthenticatedUser) -> Result<HttpResponse, AppError> {
+pub async fn logout(
+    settings: web::Data<Settings>,
+    _user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
     // In a stateless JWT system, logout is typically handled client-side
     // For enhanced security, you might want to implement a token blacklist
-    Ok(HttpResponse::Ok().json(ApiResponse::new("Logged out successfully")))
+    let mut response = HttpResponse::Ok();
+    if settings.jwt.cookie_auth {
+        let mut cookie = session_cookie(&settings.jwt, String::new());
+        cookie.make_removal();
+        response.cookie(cookie);
+    }
+
+    Ok(response.json(ApiResponse::new("Logged out successfully")))
 }
 
 #[utoipa::path(
@@ -1,13 +1,15 @@
-use actix_web::{web, HttpResponse, Result};
+use actix_web::{web, HttpRequest, HttpResponse, Result};
 use serde::Deserialize;
 use utoipa::IntoParams;
 use uuid::Uuid;
 
 use crate::{
+    config::Settings,
     dto::{CreateContributionRequest, UpdateContributionRequest},
     error::AppError,
-    middleware::auth::AuthenticatedUser,
+    middleware::auth::{AuthenticatedUser, ModeratorUser},
     services::contribution_service,
+    utils::moderation::{json_strings, Blocklist},
 };
 
 #[derive(Deserialize, IntoParams)]
@@ -17,12 +19,25 @@ pub struct ContributionQueryParams {
     pub all: Option<bool>,
 }
 
+#[derive(Deserialize, IntoParams)]
+pub struct ContributionReviewQueryParams {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+    /// Only include contributions with this status (e.g. "pending")
+    pub status: Option<String>,
+    /// Set to false to skip the total count and only report `has_more`
+    pub count: Option<bool>,
+}
+
 /// Create a new contribution
 #[utoipa::path(
     post,
     path = "/api/contributions",
     tag = "contributions",
     request_body = CreateContributionRequest,
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Retries with the same key return the original contribution")
+    ),
     responses(
         (status = 201, description = "Contribution created successfully", body = ContributionResponse),
         (status = 400, description = "Bad request"),
@@ -34,17 +49,48 @@ pub struct ContributionQueryParams {
     )
 )]
 pub async fn create_contribution(
+    http_req: HttpRequest,
     pool: web::Data<sqlx::PgPool>,
+    settings: web::Data<Settings>,
     user: AuthenticatedUser,
     req: web::Json<CreateContributionRequest>,
 ) -> Result<HttpResponse, AppError> {
-    let contribution =
-        contribution_service::create_contribution(pool.get_ref(), user.user_id, req.into_inner())
-            .await?;
+    let idempotency_key = idempotency_key(&http_req)?;
+    let flagged_terms = Blocklist::new(&settings.moderation.blocked_terms)
+        .matches(req.new_value.as_ref().map(json_strings).unwrap_or_default());
+
+    let contribution = contribution_service::create_contribution(
+        pool.get_ref(),
+        user.user_id,
+        req.into_inner(),
+        idempotency_key,
+        flagged_terms,
+    )
+    .await?;
 
     Ok(HttpResponse::Created().json(contribution))
 }
 
+/// Read the optional Idempotency-Key header
+fn idempotency_key(req: &HttpRequest) -> Result<Option<String>, AppError> {
+    let Some(value) = req.headers().get("Idempotency-Key") else {
+        return Ok(None);
+    };
+
+    let key = value
+        .to_str()
+        .map_err(|_| AppError::Validation("Idempotency-Key must be visible ASCII".to_string()))?
+        .trim();
+
+    if key.is_empty() || key.len() > 255 {
+        return Err(AppError::Validation(
+            "Idempotency-Key must be between 1 and 255 characters".to_string(),
+        ));
+    }
+
+    Ok(Some(key.to_string()))
+}
+
 /// Get a contribution by ID
 #[utoipa::path(
     get,
@@ -92,11 +138,11 @@ pub async fn get_contribution(
 )]
 pub async fn list_contributions(
     pool: web::Data<sqlx::PgPool>,
+    settings: web::Data<Settings>,
     user: AuthenticatedUser,
     query: web::Query<ContributionQueryParams>,
 ) -> Result<HttpResponse, AppError> {
-    let page = query.page.unwrap_or(1);
-    let per_page = query.per_page.unwrap_or(20);
+    let (page, per_page) = settings.pagination.resolve(query.page, query.per_page);
 
     // Only allow viewing all contributions if user is admin
     let user_id = if query.all.unwrap_or(false) && user.role == "admin" {
@@ -111,6 +157,42 @@ pub async fn list_contributions(
     Ok(HttpResponse::Ok().json(contributions))
 }
 
+/// List contributions from all users for review (moderators only)
+#[utoipa::path(
+    get,
+    path = "/api/v1/contributions/all",
+    tag = "contributions",
+    params(ContributionReviewQueryParams),
+    responses(
+        (status = 200, description = "Contributions retrieved successfully", body = ContributionPaginatedResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Moderator access required"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_contributions_for_review(
+    pool: web::Data<sqlx::PgPool>,
+    settings: web::Data<Settings>,
+    _moderator: ModeratorUser,
+    query: web::Query<ContributionReviewQueryParams>,
+) -> Result<HttpResponse, AppError> {
+    let (page, per_page) = settings.pagination.resolve(query.page, query.per_page);
+
+    let contributions = contribution_service::list_contributions_for_review(
+        pool.get_ref(),
+        query.status.clone(),
+        page,
+        per_page,
+        query.count.unwrap_or(true),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(contributions))
+}
+
 /// Update a contribution
 #[utoipa::path(
     put,
@@ -1,25 +1,153 @@
 use crate::{
+    config::Settings,
     dto::{
         dictionary::{
-            CreateDictionaryEntryRequest, SearchDictionaryRequest, UpdateDictionaryEntryRequest,
+            BatchGetDictionaryEntriesRequest, CreateDictionaryEntryRequest,
+            MergeDictionaryEntriesRequest, ReassignDictionaryEntryRequest, SearchDictionaryRequest,
+            SearchType, SuggestDictionaryEntryRequest, UpdateDictionaryEntryRequest,
+            MAX_ENTRIES_PER_BATCH_GET, MAX_ENTRIES_PER_IMPORT, MAX_IMPORT_FILE_BYTES,
         },
-        responses::ApiResponse,
+        responses::{
+            ApiResponse, DictionaryEntryResponse, DictionaryEntryWithRelatedResponse,
+            DictionarySearchResponse, DictionaryValidationResponse,
+        },
+    },
+    error::{validation_messages, AppError},
+    middleware::{
+        auth::{AuthenticatedUser, ModeratorUser},
+        concurrency::ExpensiveRequestPermit,
+        transaction::Tx,
+    },
+    services::{
+        dictionary_service::{
+            self, DictionaryExportLimiter, DictionaryStatsCache, EntryFilters, EntryOrder,
+            ExportFormat, VerifiedEntryFeed,
+        },
+        notification_service,
+    },
+    utils::moderation::Blocklist,
+};
+use actix_multipart::Multipart;
+use actix_web::{
+    delete, get,
+    http::{
+        header::{
+            self, ContentDisposition, DispositionParam, DispositionType, ETag, EntityTag,
+            IfModifiedSince, LastModified,
+        },
+        Method,
     },
-    error::AppError,
-    middleware::auth::AuthenticatedUser,
-    services::dictionary_service,
+    patch, post, put, route,
+    web::{self, Bytes},
+    HttpMessage, HttpRequest, HttpResponse,
+};
+use chrono::{DateTime, Utc};
+use futures_util::{
+    stream::{self, StreamExt},
+    TryStreamExt,
 };
-use actix_web::{delete, get, post, put, web, HttpResponse};
 use serde::Deserialize;
 use sqlx::PgPool;
+use std::time::{Duration, SystemTime};
+use tokio::{sync::broadcast::error::RecvError, time::interval};
+use tracing::warn;
 use utoipa;
 use uuid::Uuid;
 use validator::Validate;
 
+/// Most missed events replayed when a stream client resumes with Last-Event-ID
+const STREAM_REPLAY_LIMIT: i64 = 500;
+
+/// How often an idle stream sends a comment so proxies keep it open
+const STREAM_KEEP_ALIVE: Duration = Duration::from_secs(15);
+
+/// Entry fields a client may select with `?fields=`
+const SELECTABLE_FIELDS: &[&str] = &[
+    "id",
+    "pnar_word",
+    "english_word",
+    "part_of_speech",
+    "definition",
+    "example_pnar",
+    "example_english",
+    "difficulty_level",
+    "usage_frequency",
+    "cultural_context",
+    "related_words",
+    "pronunciation",
+    "etymology",
+    "verified",
+    "created_at",
+    "updated_at",
+    "created_by",
+    "version",
+];
+
 #[derive(Debug, Deserialize)]
 pub struct PaginationQuery {
     pub page: Option<i64>,
     pub per_page: Option<i64>,
+    /// Set to false to skip the total count and only report `has_more`
+    pub count: Option<bool>,
+    /// Only include entries created at or after this time
+    pub created_after: Option<DateTime<Utc>>,
+    /// Only include entries created at or before this time
+    pub created_before: Option<DateTime<Utc>>,
+    /// Only verified (true) or unverified (false) entries
+    pub verified: Option<bool>,
+    /// Only entries with this part of speech, e.g. "noun"
+    pub part_of_speech: Option<String>,
+    /// Comma-separated entry fields to return instead of the full entry
+    pub fields: Option<String>,
+    /// "created_at" (default, newest first) or "lookup_count" (most looked-up first)
+    pub order_by: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MissingFieldQuery {
+    /// Column that must be empty, e.g. "pronunciation"
+    pub field: String,
+    /// Only verified (true) or unverified (false) entries
+    pub verified: Option<bool>,
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EntryQuery {
+    /// Set to "related" to include the entries named in `related_words`
+    pub expand: Option<String>,
+    /// Comma-separated entry fields to return instead of the full entry
+    pub fields: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExportQuery {
+    /// "csv" or "json" (default)
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RecentlyVerifiedQuery {
+    /// Number of entries to return (default: 10, max: 50)
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearchCountQuery {
+    pub q: String,
+    /// How to match `q`, as in a search (default: substring)
+    pub search_type: Option<SearchType>,
+    /// Only count verified entries (default: false)
+    pub verified_only: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SuggestionQueryParams {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+    /// Suggestion status to list (default: "pending")
+    pub status: Option<String>,
 }
 
 /// Create a new dictionary entry
@@ -40,16 +168,402 @@ pub struct PaginationQuery {
 #[post("")]
 pub async fn create_entry(
     pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
+    stats_cache: web::Data<DictionaryStatsCache>,
     user: AuthenticatedUser,
     request: web::Json<CreateDictionaryEntryRequest>,
 ) -> Result<HttpResponse, AppError> {
     request.validate()?;
 
+    let flagged_terms = Blocklist::new(&settings.moderation.blocked_terms).matches(
+        [
+            request.definition.as_deref(),
+            request.example_pnar.as_deref(),
+            request.example_english.as_deref(),
+        ]
+        .into_iter()
+        .flatten(),
+    );
+
     let entry = dictionary_service::create_entry(&pool, user.user_id, request.into_inner()).await?;
+    stats_cache.invalidate();
+
+    // New entries start unverified, so flagged ones are already awaiting review
+    if !flagged_terms.is_empty() {
+        notification_service::notify_flagged_submission(
+            &mut *pool.acquire().await?,
+            "dictionary_entry",
+            entry.id,
+            &flagged_terms,
+        )
+        .await?;
+    }
 
     Ok(HttpResponse::Created().json(ApiResponse::new(entry)))
 }
 
+/// Validate a new dictionary entry without saving it
+///
+/// Runs the same field validation and duplicate-word check as entry creation
+/// and reports the outcome instead of inserting.
+#[utoipa::path(
+    post,
+    path = "/api/v1/dictionary/validate",
+    tag = "dictionary",
+    security(("bearer_auth" = [])),
+    request_body = CreateDictionaryEntryRequest,
+    responses(
+        (status = 200, description = "Validation outcome", body = DictionaryValidationResponse),
+        (status = 400, description = "Bad request"),
+        (status = 401, description = "Unauthorized")
+    )
+)]
+#[post("/validate")]
+pub async fn validate_entry(
+    pool: web::Data<PgPool>,
+    _user: AuthenticatedUser,
+    request: web::Json<CreateDictionaryEntryRequest>,
+) -> Result<HttpResponse, AppError> {
+    let errors = match request.validate() {
+        Ok(()) => Vec::new(),
+        Err(err) => validation_messages(&err),
+    };
+    let conflicts = dictionary_service::find_conflicts(&pool, &request).await?;
+
+    Ok(
+        HttpResponse::Ok().json(ApiResponse::new(DictionaryValidationResponse {
+            valid: errors.is_empty() && conflicts.is_empty(),
+            conflicts,
+            errors,
+        })),
+    )
+}
+
+/// Import dictionary entries from an uploaded CSV file
+///
+/// The upload is a multipart form with the file in a part named `file`. The
+/// first row is a header naming columns after the create-entry fields (see
+/// `dictionary_service::CSV_IMPORT_COLUMNS`); `pnar_word` and `english_word` are
+/// required. A UTF-8 BOM and quoted fields are accepted. Malformed or duplicate
+/// rows are reported in `failed` by zero-based data row without aborting the import.
+#[utoipa::path(
+    post,
+    path = "/api/v1/dictionary/import/csv",
+    tag = "dictionary",
+    security(("bearer_auth" = [])),
+    request_body(content = String, content_type = "multipart/form-data", description = "CSV file in a part named `file`"),
+    responses(
+        (status = 200, description = "Import processed; see failed rows for rejected entries", body = DictionaryBatchResult),
+        (status = 400, description = "Missing file, invalid header or too many rows"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Moderator access required")
+    )
+)]
+#[post("/import/csv")]
+pub async fn import_csv(
+    pool: web::Data<PgPool>,
+    stats_cache: web::Data<DictionaryStatsCache>,
+    mut payload: Multipart,
+    moderator: ModeratorUser,
+) -> Result<HttpResponse, AppError> {
+    let mut file = None;
+
+    while let Some(mut field) = payload
+        .try_next()
+        .await
+        .map_err(|e| AppError::Validation(format!("Invalid multipart body: {}", e)))?
+    {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let mut data = Vec::new();
+        while let Some(chunk) = field
+            .try_next()
+            .await
+            .map_err(|e| AppError::Validation(format!("Invalid multipart body: {}", e)))?
+        {
+            if data.len() + chunk.len() > MAX_IMPORT_FILE_BYTES {
+                return Err(AppError::Validation(format!(
+                    "CSV file must be at most {} bytes",
+                    MAX_IMPORT_FILE_BYTES
+                )));
+            }
+            data.extend_from_slice(&chunk);
+        }
+        file = Some(data);
+    }
+
+    let file = file.ok_or_else(|| {
+        AppError::Validation("Multipart body must include a 'file' part".to_string())
+    })?;
+
+    let rows = dictionary_service::parse_csv_entries(&file)?;
+    if rows.is_empty() || rows.len() > MAX_ENTRIES_PER_IMPORT {
+        return Err(AppError::Validation(format!(
+            "CSV must contain between 1 and {} data rows",
+            MAX_ENTRIES_PER_IMPORT
+        )));
+    }
+
+    let result = dictionary_service::create_entries_batch(&pool, moderator.0.user_id, rows).await?;
+    if !result.created.is_empty() {
+        stats_cache.invalidate();
+    }
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Get aggregate dictionary statistics
+#[utoipa::path(
+    get,
+    path = "/api/v1/dictionary/stats",
+    tag = "dictionary",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Dictionary statistics retrieved successfully", body = DictionaryStatsResponse),
+        (status = 401, description = "Unauthorized")
+    )
+)]
+#[get("/stats")]
+pub async fn get_stats(
+    pool: web::Data<PgPool>,
+    stats_cache: web::Data<DictionaryStatsCache>,
+    _user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let stats = dictionary_service::cached_stats(&pool, &stats_cache).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::new(stats)))
+}
+
+/// Report stale and long-unverified dictionary content (moderators only)
+#[utoipa::path(
+    get,
+    path = "/api/v1/dictionary/freshness",
+    tag = "dictionary",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Freshness report generated successfully", body = DictionaryFreshnessResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Moderator access required")
+    )
+)]
+#[get("/freshness")]
+pub async fn get_freshness(
+    pool: web::Data<PgPool>,
+    _moderator: ModeratorUser,
+) -> Result<HttpResponse, AppError> {
+    let report = dictionary_service::freshness_report(&pool).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::new(report)))
+}
+
+/// List entries missing a given field, as a curation worklist (moderators only)
+#[utoipa::path(
+    get,
+    path = "/api/v1/dictionary/missing",
+    tag = "dictionary",
+    security(("bearer_auth" = [])),
+    params(
+        ("field" = String, Query, description = "One of: pronunciation, etymology, example_pnar, cultural_context, definition"),
+        ("verified" = Option<bool>, Query, description = "Only verified (true) or unverified (false) entries"),
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default and maximum come from pagination settings)")
+    ),
+    responses(
+        (status = 200, description = "Entries with the field empty, oldest first", body = DictionaryPaginatedResponse),
+        (status = 400, description = "Unsupported field"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Moderator access required")
+    )
+)]
+#[get("/missing")]
+pub async fn list_missing_field(
+    pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
+    query: web::Query<MissingFieldQuery>,
+    _moderator: ModeratorUser,
+) -> Result<HttpResponse, AppError> {
+    let (page, per_page) = settings.pagination.resolve(query.page, query.per_page);
+
+    let result = dictionary_service::entries_missing_field(
+        &pool,
+        query.field.trim(),
+        query.verified,
+        page,
+        per_page,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Stream newly verified dictionary entries as server-sent events
+#[utoipa::path(
+    get,
+    path = "/api/v1/dictionary/stream",
+    tag = "dictionary",
+    security(("bearer_auth" = [])),
+    params(
+        ("Last-Event-ID" = Option<String>, Header, description = "Resume after this event id, replaying entries verified since")
+    ),
+    responses(
+        (status = 200, description = "text/event-stream of entry_verified events", content_type = "text/event-stream", body = String),
+        (status = 400, description = "Invalid Last-Event-ID"),
+        (status = 401, description = "Unauthorized")
+    )
+)]
+#[get("/stream")]
+pub async fn stream_verified_entries(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    feed: web::Data<VerifiedEntryFeed>,
+    _user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    // Subscribe before replaying so nothing verified in between is lost
+    let receiver = feed.subscribe();
+
+    let backlog = match last_event_id(&req)? {
+        Some(since) => {
+            dictionary_service::list_verified_since(&pool, since, STREAM_REPLAY_LIMIT).await?
+        }
+        None => Vec::new(),
+    };
+    let replayed_until = backlog.last().map(|(verified_at, _)| *verified_at);
+
+    let replay = stream::iter(
+        backlog
+            .into_iter()
+            .map(|(verified_at, entry)| sse_event(verified_at, &entry)),
+    );
+
+    let live = stream::unfold(
+        (receiver, interval(STREAM_KEEP_ALIVE)),
+        move |(mut receiver, mut keep_alive)| async move {
+            loop {
+                tokio::select! {
+                    event = receiver.recv() => match event {
+                        Ok(entry) => {
+                            // verify_entry sets updated_at and verified_at together
+                            if replayed_until.is_some_and(|until| entry.updated_at <= until) {
+                                continue;
+                            }
+                            let chunk = sse_event(entry.updated_at, &entry);
+                            return Some((chunk, (receiver, keep_alive)));
+                        }
+                        Err(RecvError::Lagged(skipped)) => {
+                            warn!("Dictionary stream subscriber lagged, skipped {} events", skipped);
+                        }
+                        Err(RecvError::Closed) => return None,
+                    },
+                    _ = keep_alive.tick() => {
+                        let chunk = Ok(Bytes::from_static(b": keep-alive\n\n"));
+                        return Some((chunk, (receiver, keep_alive)));
+                    }
+                }
+            }
+        },
+    );
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header((header::CACHE_CONTROL, "no-cache"))
+        .streaming(replay.chain(live)))
+}
+
+/// Format a verified entry as an SSE event whose id is its verification time
+fn sse_event(
+    verified_at: DateTime<Utc>,
+    entry: &DictionaryEntryResponse,
+) -> Result<Bytes, AppError> {
+    let data = serde_json::to_string(entry)
+        .map_err(|e| AppError::Internal(format!("Failed to serialize entry: {}", e)))?;
+
+    Ok(Bytes::from(format!(
+        "id: {}\nevent: entry_verified\ndata: {}\n\n",
+        verified_at.timestamp_micros(),
+        data
+    )))
+}
+
+/// Parse the Last-Event-ID header back into a verification time
+fn last_event_id(req: &HttpRequest) -> Result<Option<DateTime<Utc>>, AppError> {
+    let Some(value) = req.headers().get("Last-Event-ID") else {
+        return Ok(None);
+    };
+
+    value
+        .to_str()
+        .ok()
+        .and_then(|id| id.trim().parse::<i64>().ok())
+        .and_then(DateTime::from_timestamp_micros)
+        .map(Some)
+        .ok_or_else(|| {
+            AppError::Validation("Last-Event-ID must be an event id from this stream".to_string())
+        })
+}
+
+/// Get a dictionary entry by its Pnar word
+///
+/// Matching ignores surrounding whitespace and case.
+#[utoipa::path(
+    get,
+    path = "/api/v1/dictionary/word/{pnar_word}",
+    tag = "dictionary",
+    security(("bearer_auth" = [])),
+    params(
+        ("pnar_word" = String, Path, description = "Pnar word to look up")
+    ),
+    responses(
+        (status = 200, description = "Dictionary entry retrieved successfully", body = DictionaryEntryResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "No entry for this word")
+    )
+)]
+#[get("/word/{pnar_word}")]
+pub async fn get_entry_by_word(
+    pool: web::Data<PgPool>,
+    path: web::Path<String>,
+    _user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let entry = dictionary_service::get_by_pnar_word(&pool, &path).await?;
+    dictionary_service::record_lookup(&pool, entry.id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::new(entry)))
+}
+
+/// Get several dictionary entries by ID in one call
+#[utoipa::path(
+    post,
+    path = "/api/v1/dictionary/batch-get",
+    tag = "dictionary",
+    security(("bearer_auth" = [])),
+    request_body = BatchGetDictionaryEntriesRequest,
+    responses(
+        (status = 200, description = "Found entries in request order; unknown ids are omitted", body = [DictionaryEntryResponse]),
+        (status = 400, description = "Empty or oversized id list"),
+        (status = 401, description = "Unauthorized")
+    )
+)]
+#[post("/batch-get")]
+pub async fn batch_get_entries(
+    pool: web::Data<PgPool>,
+    request: web::Json<BatchGetDictionaryEntriesRequest>,
+    _user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let ids = request.into_inner().ids;
+    if ids.is_empty() || ids.len() > MAX_ENTRIES_PER_BATCH_GET {
+        return Err(AppError::Validation(format!(
+            "Batch must contain between 1 and {} ids",
+            MAX_ENTRIES_PER_BATCH_GET
+        )));
+    }
+
+    let entries = dictionary_service::get_entries_by_ids(&pool, ids).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::new(entries)))
+}
+
 /// Get a dictionary entry by ID
 #[utoipa::path(
     get,
@@ -57,24 +571,94 @@ pub async fn create_entry(
     tag = "dictionary",
     security(("bearer_auth" = [])),
     params(
-        ("id" = Uuid, Path, description = "Dictionary entry ID")
+        ("id" = Uuid, Path, description = "Dictionary entry ID"),
+        ("expand" = Option<String>, Query, description = "Set to \"related\" to include related entries under related_entries"),
+        ("fields" = Option<String>, Query, description = "Comma-separated entry fields to return, e.g. id,pnar_word,english_word")
     ),
     responses(
         (status = 200, description = "Dictionary entry retrieved successfully", body = DictionaryEntryResponse),
+        (status = 304, description = "Entry not modified since If-Modified-Since"),
         (status = 401, description = "Unauthorized"),
         (status = 404, description = "Dictionary entry not found")
     )
 )]
-#[get("/{id}")]
+#[route("/{id}", method = "GET", method = "HEAD")]
 pub async fn get_entry(
+    req: HttpRequest,
     pool: web::Data<PgPool>,
     path: web::Path<Uuid>,
+    query: web::Query<EntryQuery>,
     _user: AuthenticatedUser,
 ) -> Result<HttpResponse, AppError> {
+    let expand_related = match query.expand.as_deref() {
+        None => false,
+        Some("related") => true,
+        Some(other) => {
+            return Err(AppError::Validation(format!(
+                "Unsupported expand value '{}'; expected 'related'",
+                other
+            )))
+        }
+    };
+
+    let fields = parse_fields(query.fields.as_deref())?;
+
     let entry_id = path.into_inner();
     let entry = dictionary_service::get_entry(&pool, entry_id).await?;
 
-    Ok(HttpResponse::Ok().json(ApiResponse::new(entry)))
+    // HEAD requests only probe the entry, so they don't count as lookups
+    if req.method() == Method::GET {
+        dictionary_service::record_lookup(&pool, entry_id).await?;
+    }
+
+    let last_modified = http_date(entry.updated_at);
+    let etag = EntityTag::new_strong(format!(
+        "{}-{}{}{}",
+        entry.id,
+        entry.updated_at.timestamp_micros(),
+        if expand_related { "-related" } else { "" },
+        fields
+            .as_ref()
+            .map(|fields| format!("-{}", fields.join(".")))
+            .unwrap_or_default()
+    ));
+
+    if not_modified_since(&req, last_modified) {
+        return Ok(HttpResponse::NotModified()
+            .insert_header(ETag(etag))
+            .insert_header(LastModified(last_modified.into()))
+            .finish());
+    }
+
+    let mut response = HttpResponse::Ok();
+    response
+        .insert_header(ETag(etag))
+        .insert_header(LastModified(last_modified.into()));
+
+    // HEAD requests get the same status and headers; actix drops the body
+    if expand_related {
+        let related_entries = dictionary_service::get_related_entries(&pool, &entry).await?;
+        let Some(fields) = fields else {
+            return Ok(
+                response.json(ApiResponse::new(DictionaryEntryWithRelatedResponse {
+                    entry,
+                    related_entries,
+                })),
+            );
+        };
+
+        let mut body = project_fields(&entry, &fields)?;
+        body["related_entries"] = related_entries
+            .iter()
+            .map(|related| project_fields(related, &fields))
+            .collect::<Result<_, _>>()?;
+        return Ok(response.json(ApiResponse::new(body)));
+    }
+
+    match fields {
+        Some(fields) => Ok(response.json(ApiResponse::new(project_fields(&entry, &fields)?))),
+        None => Ok(response.json(ApiResponse::new(entry))),
+    }
 }
 
 /// List dictionary entries with pagination
@@ -85,26 +669,159 @@ pub async fn get_entry(
     security(("bearer_auth" = [])),
     params(
         ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
-        ("per_page" = Option<i64>, Query, description = "Items per page (default: 20, max: 100)")
+        ("per_page" = Option<i64>, Query, description = "Items per page (default and maximum come from pagination settings)"),
+        ("count" = Option<bool>, Query, description = "Compute the total count (default: true); false only reports has_more and omits Last-Modified"),
+        ("created_after" = Option<String>, Query, description = "Only entries created at or after this RFC 3339 timestamp"),
+        ("created_before" = Option<String>, Query, description = "Only entries created at or before this RFC 3339 timestamp"),
+        ("verified" = Option<bool>, Query, description = "Only verified (true) or unverified (false) entries"),
+        ("part_of_speech" = Option<String>, Query, description = "Only entries with this part of speech (case-insensitive)"),
+        ("fields" = Option<String>, Query, description = "Comma-separated entry fields to return, e.g. id,pnar_word,english_word"),
+        ("order_by" = Option<String>, Query, description = "created_at (default, newest first) or lookup_count (most looked-up first)")
     ),
     responses(
         (status = 200, description = "Dictionary entries retrieved successfully", body = DictionaryPaginatedResponse),
+        (status = 304, description = "Entries not modified since If-Modified-Since"),
         (status = 400, description = "Bad request"),
         (status = 401, description = "Unauthorized")
     )
 )]
-#[get("")]
+#[route("", method = "GET", method = "HEAD")]
 pub async fn list_entries(
+    req: HttpRequest,
     pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
     query: web::Query<PaginationQuery>,
     _user: AuthenticatedUser,
 ) -> Result<HttpResponse, AppError> {
-    let page = query.page.unwrap_or(1).max(1);
-    let per_page = query.per_page.unwrap_or(20).clamp(1, 100);
+    let (page, per_page) = settings.pagination.resolve(query.page, query.per_page);
 
-    let result = dictionary_service::list_entries(&pool, page, per_page).await?;
+    let count = query.count.unwrap_or(true);
+    let fields = parse_fields(query.fields.as_deref())?;
+    let order = match query.order_by.as_deref() {
+        None | Some("created_at") => EntryOrder::CreatedAt,
+        Some("lookup_count") => EntryOrder::LookupCount,
+        Some(other) => {
+            return Err(AppError::Validation(format!(
+                "Unsupported order_by '{}'; expected 'created_at' or 'lookup_count'",
+                other
+            )))
+        }
+    };
 
-    Ok(HttpResponse::Ok().json(result))
+    if let (Some(after), Some(before)) = (query.created_after, query.created_before) {
+        if after > before {
+            return Err(AppError::Validation(
+                "created_after must not be later than created_before".to_string(),
+            ));
+        }
+    }
+
+    let filters = EntryFilters {
+        created_after: query.created_after,
+        created_before: query.created_before,
+        verified: query.verified,
+        part_of_speech: query
+            .part_of_speech
+            .as_deref()
+            .map(str::trim)
+            .filter(|pos| !pos.is_empty())
+            .map(str::to_string),
+    };
+
+    let (result, last_modified) =
+        dictionary_service::list_entries(&pool, page, per_page, count, &filters, order).await?;
+
+    let mut response = HttpResponse::Ok();
+
+    // Taken over the whole filtered set, so a change on any page (or a
+    // deletion) moves it, not just changes to the rows on this one
+    if let Some(last_modified) = last_modified {
+        let last_modified = http_date(last_modified);
+
+        if not_modified_since(&req, last_modified) {
+            return Ok(HttpResponse::NotModified()
+                .insert_header(LastModified(last_modified.into()))
+                .finish());
+        }
+
+        response.insert_header(LastModified(last_modified.into()));
+    }
+
+    let Some(fields) = fields else {
+        return Ok(response.json(result));
+    };
+
+    let data = result
+        .data
+        .iter()
+        .map(|entry| project_fields(entry, &fields))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(response.json(serde_json::json!({
+        "data": data,
+        "pagination": result.pagination,
+        "timestamp": result.timestamp,
+    })))
+}
+
+/// Parse a `?fields=` list, rejecting names that are not entry fields
+fn parse_fields(fields: Option<&str>) -> Result<Option<Vec<&str>>, AppError> {
+    let Some(fields) = fields else {
+        return Ok(None);
+    };
+
+    let fields: Vec<&str> = fields
+        .split(',')
+        .map(str::trim)
+        .filter(|field| !field.is_empty())
+        .collect();
+
+    if fields.is_empty() {
+        return Err(AppError::Validation(
+            "fields must name at least one field".to_string(),
+        ));
+    }
+
+    if let Some(unknown) = fields
+        .iter()
+        .find(|field| !SELECTABLE_FIELDS.contains(field))
+    {
+        return Err(AppError::Validation(format!(
+            "Unknown field '{}'; expected any of: {}",
+            unknown,
+            SELECTABLE_FIELDS.join(", ")
+        )));
+    }
+
+    Ok(Some(fields))
+}
+
+/// Serialize an entry and keep only the requested fields
+fn project_fields(
+    entry: &DictionaryEntryResponse,
+    fields: &[&str],
+) -> Result<serde_json::Value, AppError> {
+    let mut value = serde_json::to_value(entry)
+        .map_err(|e| AppError::Internal(format!("Failed to serialize entry: {}", e)))?;
+
+    if let Some(object) = value.as_object_mut() {
+        object.retain(|key, _| fields.contains(&key.as_str()));
+    }
+
+    Ok(value)
+}
+
+/// Truncate a timestamp to the whole-second precision of HTTP dates
+fn http_date(timestamp: DateTime<Utc>) -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_secs(timestamp.timestamp().max(0) as u64)
+}
+
+/// Whether the request's If-Modified-Since is at or after `last_modified`
+fn not_modified_since(req: &HttpRequest, last_modified: SystemTime) -> bool {
+    match req.get_header::<IfModifiedSince>() {
+        Some(IfModifiedSince(since)) => last_modified <= SystemTime::from(since),
+        None => false,
+    }
 }
 
 /// Search dictionary entries
@@ -115,23 +832,75 @@ pub async fn list_entries(
     security(("bearer_auth" = [])),
     request_body = SearchDictionaryRequest,
     responses(
-        (status = 200, description = "Search results retrieved successfully", body = DictionaryPaginatedResponse),
+        (status = 200, description = "One page of search results with the query and total match count", body = DictionarySearchResponse),
         (status = 400, description = "Bad request"),
         (status = 401, description = "Unauthorized"),
-        (status = 422, description = "Validation error")
+        (status = 422, description = "Validation error"),
+        (status = 429, description = "Too many concurrent expensive requests for this user")
     )
 )]
 #[post("/search")]
 pub async fn search_entries(
     pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
     request: web::Json<SearchDictionaryRequest>,
     _user: AuthenticatedUser,
+    _permit: ExpensiveRequestPermit,
 ) -> Result<HttpResponse, AppError> {
+    // A blank query would match every entry, so reject it once trimmed
+    let mut request = request.into_inner();
+    request.query = request.query.trim().to_string();
     request.validate()?;
 
-    let entries = dictionary_service::search_entries(&pool, request.into_inner()).await?;
+    let query = request.query.clone();
+    let entries = dictionary_service::search_entries(&pool, request, &settings.dictionary).await?;
 
-    Ok(HttpResponse::Ok().json(ApiResponse::new(entries)))
+    Ok(HttpResponse::Ok().json(DictionarySearchResponse::new(query, entries)))
+}
+
+/// Count search matches without returning the entries
+#[utoipa::path(
+    get,
+    path = "/api/v1/dictionary/search/count",
+    tag = "dictionary",
+    security(("bearer_auth" = [])),
+    params(
+        ("q" = String, Query, description = "Search text, matched like POST /search"),
+        ("search_type" = Option<String>, Query, description = "pnar, english, definition, all or fuzzy, as in POST /search"),
+        ("verified_only" = Option<bool>, Query, description = "Only count verified entries (default: false)")
+    ),
+    responses(
+        (status = 200, description = "Match count computed successfully", body = SearchCountResponse),
+        (status = 400, description = "Bad request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 429, description = "Too many concurrent expensive requests for this user")
+    )
+)]
+#[get("/search/count")]
+pub async fn search_count(
+    pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
+    query: web::Query<SearchCountQuery>,
+    _user: AuthenticatedUser,
+    _permit: ExpensiveRequestPermit,
+) -> Result<HttpResponse, AppError> {
+    let q = query.q.trim();
+    if q.is_empty() {
+        return Err(AppError::Validation(
+            "Search query cannot be empty".to_string(),
+        ));
+    }
+
+    let count = dictionary_service::search_count(
+        &pool,
+        q,
+        query.search_type.as_ref(),
+        query.verified_only.unwrap_or(false),
+        &settings.dictionary,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::new(count)))
 }
 
 /// Update a dictionary entry
@@ -150,12 +919,14 @@ pub async fn search_entries(
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Forbidden"),
         (status = 404, description = "Dictionary entry not found"),
+        (status = 409, description = "Entry version is stale or pnar word already exists"),
         (status = 422, description = "Validation error")
     )
 )]
 #[put("/{id}")]
 pub async fn update_entry(
     pool: web::Data<PgPool>,
+    stats_cache: web::Data<DictionaryStatsCache>,
     user: AuthenticatedUser,
     path: web::Path<Uuid>,
     request: web::Json<UpdateDictionaryEntryRequest>,
@@ -166,6 +937,46 @@ pub async fn update_entry(
     let entry =
         dictionary_service::update_entry(&pool, entry_id, user.user_id, request.into_inner())
             .await?;
+    stats_cache.invalidate();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::new(entry)))
+}
+
+/// Apply a JSON Patch (RFC 6902) to a dictionary entry
+#[utoipa::path(
+    patch,
+    path = "/api/v1/dictionary/{id}",
+    tag = "dictionary",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Dictionary entry ID")
+    ),
+    request_body(
+        content = [Object],
+        content_type = "application/json-patch+json",
+        description = "RFC 6902 operations; id, created_by, verified, created_at and updated_at are immutable"
+    ),
+    responses(
+        (status = 200, description = "Dictionary entry patched successfully", body = DictionaryEntryResponse),
+        (status = 400, description = "Invalid patch, immutable field or invalid result"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Dictionary entry not found"),
+        (status = 409, description = "Pnar word already exists or entry changed concurrently")
+    )
+)]
+#[patch("/{id}")]
+pub async fn patch_entry(
+    pool: web::Data<PgPool>,
+    stats_cache: web::Data<DictionaryStatsCache>,
+    user: AuthenticatedUser,
+    path: web::Path<Uuid>,
+    patch: web::Json<json_patch::Patch>,
+) -> Result<HttpResponse, AppError> {
+    let entry_id = path.into_inner();
+    let entry =
+        dictionary_service::patch_entry(&pool, entry_id, user.user_id, patch.into_inner()).await?;
+    stats_cache.invalidate();
 
     Ok(HttpResponse::Ok().json(ApiResponse::new(entry)))
 }
@@ -189,11 +1000,13 @@ pub async fn update_entry(
 #[delete("/{id}")]
 pub async fn delete_entry(
     pool: web::Data<PgPool>,
+    stats_cache: web::Data<DictionaryStatsCache>,
     user: AuthenticatedUser,
     path: web::Path<Uuid>,
 ) -> Result<HttpResponse, AppError> {
     let entry_id = path.into_inner();
     dictionary_service::delete_entry(&pool, entry_id, user.user_id).await?;
+    stats_cache.invalidate();
 
     Ok(HttpResponse::NoContent().finish())
 }
@@ -216,12 +1029,333 @@ pub async fn delete_entry(
 )]
 #[put("/{id}/verify")]
 pub async fn verify_entry(
-    pool: web::Data<PgPool>,
+    tx: Tx,
+    stats_cache: web::Data<DictionaryStatsCache>,
+    feed: web::Data<VerifiedEntryFeed>,
     user: AuthenticatedUser,
     path: web::Path<Uuid>,
 ) -> Result<HttpResponse, AppError> {
     let entry_id = path.into_inner();
-    let entry = dictionary_service::verify_entry(&pool, entry_id, user.user_id).await?;
+
+    // The entry update and the author's notification commit or roll back together
+    let mut conn = tx.lock().await?;
+    let entry = dictionary_service::verify_entry(&mut conn, entry_id, user.user_id).await?;
+
+    if let Some(author_id) = entry.created_by.filter(|author| *author != user.user_id) {
+        notification_service::create_notification(
+            &mut conn,
+            author_id,
+            "entry_verified",
+            "Your dictionary entry was verified",
+            &format!("'{}' has been verified by a moderator", entry.pnar_word),
+            serde_json::json!({ "entry_id": entry.id }),
+        )
+        .await?;
+    }
+    drop(conn);
+
+    // Only announce the verification once it is durable
+    let published = entry.clone();
+    tx.after_commit(move || {
+        stats_cache.invalidate();
+        feed.publish(&published);
+    });
+
+    Ok(HttpResponse::Ok().json(ApiResponse::new(entry)))
+}
+
+/// Merge a duplicate dictionary entry into another (moderators only)
+#[utoipa::path(
+    post,
+    path = "/api/v1/dictionary/merge",
+    tag = "dictionary",
+    security(("bearer_auth" = [])),
+    request_body = MergeDictionaryEntriesRequest,
+    responses(
+        (status = 200, description = "Entries merged; returns the kept entry", body = DictionaryEntryResponse),
+        (status = 400, description = "Cannot merge an entry into itself"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Moderator access required"),
+        (status = 404, description = "Dictionary entry not found")
+    )
+)]
+#[post("/merge")]
+pub async fn merge_entries(
+    pool: web::Data<PgPool>,
+    stats_cache: web::Data<DictionaryStatsCache>,
+    moderator: ModeratorUser,
+    request: web::Json<MergeDictionaryEntriesRequest>,
+) -> Result<HttpResponse, AppError> {
+    let entry = dictionary_service::merge_entries(
+        &pool,
+        request.keep_id,
+        request.merge_id,
+        moderator.0.user_id,
+    )
+    .await?;
+    stats_cache.invalidate();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::new(entry)))
+}
+
+/// Transfer a dictionary entry to another owner (moderators only)
+#[utoipa::path(
+    post,
+    path = "/api/v1/dictionary/{id}/reassign",
+    tag = "dictionary",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Dictionary entry ID")
+    ),
+    request_body = ReassignDictionaryEntryRequest,
+    responses(
+        (status = 200, description = "Dictionary entry reassigned successfully", body = DictionaryEntryResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Moderator access required"),
+        (status = 404, description = "Dictionary entry or new owner not found"),
+        (status = 422, description = "New owner account is inactive")
+    )
+)]
+#[post("/{id}/reassign")]
+pub async fn reassign_entry(
+    pool: web::Data<PgPool>,
+    stats_cache: web::Data<DictionaryStatsCache>,
+    moderator: ModeratorUser,
+    path: web::Path<Uuid>,
+    request: web::Json<ReassignDictionaryEntryRequest>,
+) -> Result<HttpResponse, AppError> {
+    let entry = dictionary_service::reassign_owner(
+        &pool,
+        path.into_inner(),
+        request.new_owner_id,
+        moderator.0.user_id,
+    )
+    .await?;
+    stats_cache.invalidate();
 
     Ok(HttpResponse::Ok().json(ApiResponse::new(entry)))
 }
+
+/// Download the whole dictionary as CSV or JSON
+#[utoipa::path(
+    get,
+    path = "/api/v1/dictionary/export",
+    tag = "dictionary",
+    params(
+        ("format" = Option<String>, Query, description = "csv or json (default: json)")
+    ),
+    responses(
+        (status = 200, description = "Every entry, oldest first, streamed as a CSV file or a JSON array of DictionaryEntryResponse"),
+        (status = 400, description = "Unsupported format"),
+        (status = 429, description = "Too many exports in progress")
+    )
+)]
+#[get("/export")]
+pub async fn export_entries(
+    pool: web::Data<PgPool>,
+    export_limiter: web::Data<DictionaryExportLimiter>,
+    query: web::Query<ExportQuery>,
+) -> Result<HttpResponse, AppError> {
+    let format = match query.format.as_deref() {
+        None | Some("json") => ExportFormat::Json,
+        Some("csv") => ExportFormat::Csv,
+        Some(other) => {
+            return Err(AppError::Validation(format!(
+                "Unsupported format '{}'; expected 'csv' or 'json'",
+                other
+            )))
+        }
+    };
+
+    let permit = export_limiter.try_acquire().ok_or_else(|| {
+        AppError::TooManyRequests(
+            "Too many dictionary exports in progress; try again later".to_string(),
+        )
+    })?;
+    let body = dictionary_service::export_entries(pool.get_ref().clone(), format, permit);
+
+    Ok(HttpResponse::Ok()
+        .content_type(format.content_type())
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(format!(
+                "pnar-dictionary.{}",
+                format.file_extension()
+            ))],
+        })
+        .streaming(body.map_ok(Bytes::from)))
+}
+
+/// List the most recently verified dictionary entries
+#[utoipa::path(
+    get,
+    path = "/api/v1/dictionary/recently-verified",
+    tag = "dictionary",
+    params(
+        ("limit" = Option<i64>, Query, description = "Number of entries to return (default: 10, max: 50)")
+    ),
+    responses(
+        (status = 200, description = "Recently verified entries, newest verification first", body = [DictionaryEntryResponse])
+    )
+)]
+#[get("/recently-verified")]
+pub async fn recently_verified(
+    pool: web::Data<PgPool>,
+    query: web::Query<RecentlyVerifiedQuery>,
+) -> Result<HttpResponse, AppError> {
+    let limit = query.limit.unwrap_or(10).clamp(1, 50);
+
+    let entries = dictionary_service::recently_verified(&pool, limit).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::new(entries)))
+}
+
+/// Suggest a new dictionary entry without an account
+#[utoipa::path(
+    post,
+    path = "/api/v1/dictionary/suggest",
+    tag = "dictionary",
+    request_body = SuggestDictionaryEntryRequest,
+    responses(
+        (status = 201, description = "Suggestion queued for moderator review", body = DictionarySuggestionResponse),
+        (status = 400, description = "Bad request")
+    )
+)]
+#[post("/suggest")]
+pub async fn suggest_entry(
+    pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
+    request: web::Json<SuggestDictionaryEntryRequest>,
+) -> Result<HttpResponse, AppError> {
+    request.validate()?;
+
+    let flagged_terms = Blocklist::new(&settings.moderation.blocked_terms).matches(
+        [
+            Some(request.pnar_word.as_str()),
+            Some(request.english_word.as_str()),
+            request.definition.as_deref(),
+            request.example_pnar.as_deref(),
+            request.example_english.as_deref(),
+            request.contact_name.as_deref(),
+        ]
+        .into_iter()
+        .flatten(),
+    );
+
+    let suggestion =
+        dictionary_service::suggest_entry(&pool, request.into_inner(), flagged_terms).await?;
+
+    Ok(HttpResponse::Created().json(ApiResponse::new(suggestion)))
+}
+
+/// List dictionary suggestions for review (moderators only)
+#[utoipa::path(
+    get,
+    path = "/api/v1/dictionary/suggestions",
+    tag = "dictionary",
+    security(("bearer_auth" = [])),
+    params(
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default and maximum come from pagination settings)"),
+        ("status" = Option<String>, Query, description = "Suggestion status (default: pending)")
+    ),
+    responses(
+        (status = 200, description = "Suggestions retrieved successfully", body = [DictionarySuggestionResponse]),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Moderator access required")
+    )
+)]
+#[get("/suggestions")]
+pub async fn list_suggestions(
+    pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
+    query: web::Query<SuggestionQueryParams>,
+    _moderator: ModeratorUser,
+) -> Result<HttpResponse, AppError> {
+    let (page, per_page) = settings.pagination.resolve(query.page, query.per_page);
+    let status = query.status.as_deref().unwrap_or("pending");
+
+    let suggestions = dictionary_service::list_suggestions(&pool, status, page, per_page).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::new(suggestions)))
+}
+
+/// Approve a suggestion, creating a dictionary entry from it (moderators only)
+#[utoipa::path(
+    post,
+    path = "/api/v1/dictionary/suggestions/{id}/approve",
+    tag = "dictionary",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "Suggestion ID")
+    ),
+    responses(
+        (status = 201, description = "Suggestion approved and entry created", body = DictionaryEntryResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Moderator access required"),
+        (status = 404, description = "Suggestion not found"),
+        (status = 409, description = "Suggestion already reviewed or word already exists")
+    )
+)]
+#[post("/suggestions/{id}/approve")]
+pub async fn approve_suggestion(
+    pool: web::Data<PgPool>,
+    stats_cache: web::Data<DictionaryStatsCache>,
+    moderator: ModeratorUser,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let suggestion_id = path.into_inner();
+    let entry =
+        dictionary_service::approve_suggestion(&pool, suggestion_id, moderator.0.user_id).await?;
+    stats_cache.invalidate();
+
+    Ok(HttpResponse::Created().json(ApiResponse::new(entry)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::get_configuration;
+    use actix_web::{
+        dev::Service,
+        test::{call_service, init_service, TestRequest},
+        App,
+    };
+    use sqlx::postgres::PgPoolOptions;
+    use std::time::Duration;
+
+    #[actix_web::test]
+    async fn blank_search_query_is_rejected_before_querying() {
+        // Any query against this pool would fail with a connection error
+        let pool = PgPoolOptions::new()
+            .acquire_timeout(Duration::from_millis(100))
+            .connect_lazy("postgres://nobody@127.0.0.1:1/unreachable")
+            .unwrap();
+        let settings = get_configuration().unwrap();
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(pool))
+                .app_data(web::Data::new(settings))
+                .wrap_fn(|req, srv| {
+                    req.extensions_mut().insert(AuthenticatedUser {
+                        user_id: Uuid::new_v4(),
+                        role: "user".to_string(),
+                    });
+                    srv.call(req)
+                })
+                .service(search_entries),
+        )
+        .await;
+
+        for query in ["", "   \t"] {
+            let req = TestRequest::post()
+                .uri("/search")
+                .set_json(serde_json::json!({ "query": query }))
+                .to_request();
+
+            let res = call_service(&app, req).await;
+
+            assert_eq!(res.status(), 400, "query {:?}", query);
+        }
+    }
+}
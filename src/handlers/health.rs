@@ -1,5 +1,9 @@
-use crate::{database, dto::HealthResponse, error::AppError};
-use actix_web::{get, web, HttpResponse};
+use crate::{
+    database::{self, Readiness},
+    dto::{responses::ReadinessResponse, HealthResponse},
+    error::AppError,
+};
+use actix_web::{get, http::header::RETRY_AFTER, web, HttpResponse};
 use sqlx::PgPool;
 use utoipa;
 
@@ -22,3 +26,84 @@ pub async fn health_check(pool: web::Data<PgPool>) -> Result<HttpResponse, AppEr
             .json(HealthResponse::unhealthy(version, "disconnected"))),
     }
 }
+
+/// Cheapest possible liveness probe: no database, env or JSON work
+#[utoipa::path(
+    get,
+    path = "/api/v1/ping",
+    tag = "health",
+    responses(
+        (status = 200, description = "Service is alive", body = String, content_type = "text/plain")
+    )
+)]
+#[get("/ping")]
+pub async fn ping() -> HttpResponse {
+    HttpResponse::Ok().content_type("text/plain").body("pong")
+}
+
+/// Readiness probe for orchestrators
+///
+/// Reports not ready until connection pool warmup has completed, the
+/// configured startup grace period has elapsed and the database answers.
+#[utoipa::path(
+    get,
+    path = "/api/v1/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "Ready to receive traffic", body = ReadinessResponse),
+        (status = 503, description = "Not ready yet", body = ReadinessResponse)
+    )
+)]
+#[get("/ready")]
+pub async fn ready(
+    pool: web::Data<PgPool>,
+    readiness: web::Data<Readiness>,
+) -> Result<HttpResponse, AppError> {
+    let remaining = match readiness.grace_remaining() {
+        Some(remaining) => remaining,
+        None => {
+            return Ok(
+                HttpResponse::ServiceUnavailable().json(ReadinessResponse::not_ready(
+                    "connection pool is warming up",
+                )),
+            )
+        }
+    };
+
+    if !remaining.is_zero() {
+        let seconds = remaining.as_secs_f64().ceil() as u64;
+        return Ok(HttpResponse::ServiceUnavailable()
+            .insert_header((RETRY_AFTER, seconds))
+            .json(ReadinessResponse::not_ready(format!(
+                "startup grace period has {}s left",
+                seconds
+            ))));
+    }
+
+    match database::health_check(&pool).await {
+        Ok(_) => Ok(HttpResponse::Ok().json(ReadinessResponse::ready())),
+        Err(_) => Ok(HttpResponse::ServiceUnavailable()
+            .json(ReadinessResponse::not_ready("database is unreachable"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{
+        http::{header::CONTENT_TYPE, StatusCode},
+        test::{call_service, init_service, read_body, TestRequest},
+        App,
+    };
+
+    #[actix_web::test]
+    async fn ping_returns_plain_pong() {
+        let app = init_service(App::new().service(ping)).await;
+
+        let res = call_service(&app, TestRequest::get().uri("/ping").to_request()).await;
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers().get(CONTENT_TYPE).unwrap(), "text/plain");
+        assert_eq!(read_body(res).await, "pong");
+    }
+}
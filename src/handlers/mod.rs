@@ -1,7 +1,10 @@
+pub mod admin;
 pub mod analytics;
 pub mod auth;
 pub mod contribution;
 pub mod dictionary;
 pub mod health;
+pub mod metrics;
+pub mod notification;
 pub mod translation;
 pub mod user;
@@ -0,0 +1,49 @@
+use crate::{
+    config::Settings, dto::responses::ApiResponse, error::AppError,
+    middleware::auth::AuthenticatedUser, services::notification_service,
+};
+use actix_web::{post, web, HttpResponse};
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+use sqlx::PgPool;
+
+#[derive(Debug, Deserialize)]
+pub struct DigestQuery {
+    /// Only include notifications created after this RFC 3339 timestamp
+    pub since: Option<DateTime<Utc>>,
+}
+
+/// Compose a digest of the caller's unread notifications
+///
+/// The digest is also recorded as a single `digest` notification, unless one
+/// was already sent for the same window.
+#[utoipa::path(
+    post,
+    path = "/api/v1/notifications/digest",
+    tag = "notifications",
+    security(("bearer_auth" = [])),
+    params(
+        ("since" = Option<String>, Query, description = "Cutoff as an RFC 3339 timestamp (default: one digest interval ago)")
+    ),
+    responses(
+        (status = 200, description = "Digest of unread notifications", body = NotificationDigestResponse),
+        (status = 401, description = "Unauthorized")
+    )
+)]
+#[post("/digest")]
+pub async fn send_digest(
+    pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
+    query: web::Query<DigestQuery>,
+    user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let since = query.since.unwrap_or_else(|| {
+        let interval = settings.notifications.digest_interval_seconds.max(1);
+        Utc::now() - Duration::seconds(interval as i64)
+    });
+
+    let digest = notification_service::build_digest(&pool, user.user_id, since).await?;
+    notification_service::send_digest(&pool, user.user_id, since, &digest).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::new(digest)))
+}
@@ -2,9 +2,11 @@ use actix_web::{web, HttpResponse, Result};
 use serde::Deserialize;
 use utoipa::IntoParams;
 use uuid::Uuid;
+use validator::Validate;
 
 use crate::{
-    dto::{CreateTranslationRequest, UpdateTranslationRequest},
+    config::Settings,
+    dto::{CreateTranslationRequest, SaveTranslationDraftRequest, UpdateTranslationRequest},
     error::AppError,
     middleware::auth::AuthenticatedUser,
     services::translation_service,
@@ -97,13 +99,13 @@ pub async fn get_translation(
 )]
 pub async fn list_translations(
     pool: web::Data<sqlx::PgPool>,
+    settings: web::Data<Settings>,
     user: AuthenticatedUser,
     query: web::Query<TranslationQueryParams>,
 ) -> Result<HttpResponse, AppError> {
-    let page = query.page.unwrap_or(1);
-    let per_page = query.per_page.unwrap_or(20);
+    let (page, per_page) = settings.pagination.resolve(query.page, query.per_page);
 
-    let translations = translation_service::list_translation_requests(
+    let response = translation_service::list_translation_requests(
         pool.get_ref(),
         user.user_id,
         page,
@@ -111,7 +113,7 @@ pub async fn list_translations(
     )
     .await?;
 
-    Ok(HttpResponse::Ok().json(translations))
+    Ok(HttpResponse::Ok().json(response))
 }
 
 /// Update a translation request
@@ -151,6 +153,47 @@ pub async fn update_translation(
     Ok(HttpResponse::Ok().json(translation))
 }
 
+/// Autosave a partial translation as a draft
+#[utoipa::path(
+    patch,
+    path = "/api/v1/translations/{id}/draft",
+    tag = "translations",
+    params(
+        ("id" = Uuid, Path, description = "Translation request ID")
+    ),
+    request_body = SaveTranslationDraftRequest,
+    responses(
+        (status = 200, description = "Draft saved; status is in_progress", body = TranslationResponse),
+        (status = 400, description = "Validation error"),
+        (status = 404, description = "Translation request not found"),
+        (status = 409, description = "Translation request is no longer editable"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn save_translation_draft(
+    pool: web::Data<sqlx::PgPool>,
+    user: AuthenticatedUser,
+    path: web::Path<Uuid>,
+    req: web::Json<SaveTranslationDraftRequest>,
+) -> Result<HttpResponse, AppError> {
+    let request = req.into_inner();
+    request.validate()?;
+
+    let translation = translation_service::save_draft(
+        pool.get_ref(),
+        user.user_id,
+        path.into_inner(),
+        &request.translated_text,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(translation))
+}
+
 /// Delete a translation request
 #[utoipa::path(
     delete,
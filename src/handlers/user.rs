@@ -1,21 +1,33 @@
 use crate::{
+    config::Settings,
     dto::{
         responses::{ApiResponse, SuccessResponse},
         user::{
-            AwardPointsRequest, CreateUserRequest, UpdatePasswordRequest, UpdateUserRequest,
-            UserQueryParams,
+            ActivityQueryParams, AwardPointsRequest, BulkUpdateRolesRequest, CreateUserRequest,
+            CreateUsersBatchRequest, UpdatePasswordRequest, UpdateUserRequest, UserQueryParams,
+            MAX_USERS_PER_BATCH,
         },
     },
     error::AppError,
-    middleware::auth::{AdminUser, AuthenticatedUser},
+    middleware::{
+        auth::{AdminUser, AuthenticatedUser, ModeratorUser},
+        concurrency::ExpensiveRequestPermit,
+    },
     services::user_service,
 };
 use actix_web::{delete, get, patch, post, put, web, HttpResponse};
+use serde::Deserialize;
 use sqlx::PgPool;
 use utoipa;
 use uuid::Uuid;
 use validator::Validate;
 
+#[derive(Debug, Deserialize)]
+pub struct PageQueryParams {
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+}
+
 /// Create a new user
 /// POST /api/v1/users
 #[utoipa::path(
@@ -37,17 +49,97 @@ use validator::Validate;
 #[post("")]
 pub async fn create_user(
     pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
     request: web::Json<CreateUserRequest>,
     _admin_user: AdminUser, // Only admins can create users
 ) -> Result<HttpResponse, AppError> {
     // Validate request
     request.validate()?;
 
-    let user = user_service::create_user(&pool, request.into_inner()).await?;
+    let user = user_service::create_user(&pool, &settings.security, request.into_inner()).await?;
 
     Ok(HttpResponse::Created().json(ApiResponse::new(user)))
 }
 
+/// Create several users at once
+/// POST /api/v1/users/batch
+#[utoipa::path(
+    post,
+    path = "/api/v1/users/batch",
+    tag = "users",
+    request_body = CreateUsersBatchRequest,
+    responses(
+        (status = 200, description = "Batch processed; see failed rows for rejected users", body = UserBatchResult),
+        (status = 400, description = "Invalid input data"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+#[post("/batch")]
+pub async fn create_users_batch(
+    pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
+    request: web::Json<CreateUsersBatchRequest>,
+    _admin_user: AdminUser, // Only admins can create users
+) -> Result<HttpResponse, AppError> {
+    if request.users.is_empty() || request.users.len() > MAX_USERS_PER_BATCH {
+        return Err(AppError::Validation(format!(
+            "Batch must contain between 1 and {} users",
+            MAX_USERS_PER_BATCH
+        )));
+    }
+
+    let result =
+        user_service::create_users_batch(&pool, &settings.security, request.into_inner().users)
+            .await?;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Assign roles to several users by email
+/// POST /api/v1/users/roles/bulk
+#[utoipa::path(
+    post,
+    path = "/api/v1/users/roles/bulk",
+    tag = "users",
+    request_body = BulkUpdateRolesRequest,
+    responses(
+        (status = 200, description = "Batch processed; see failed rows for rejected assignments", body = UserBatchResult),
+        (status = 400, description = "Invalid input data"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+#[post("/roles/bulk")]
+pub async fn bulk_update_roles(
+    pool: web::Data<PgPool>,
+    request: web::Json<BulkUpdateRolesRequest>,
+    admin_user: AdminUser,
+) -> Result<HttpResponse, AppError> {
+    if request.assignments.is_empty() || request.assignments.len() > MAX_USERS_PER_BATCH {
+        return Err(AppError::Validation(format!(
+            "Batch must contain between 1 and {} assignments",
+            MAX_USERS_PER_BATCH
+        )));
+    }
+
+    let result = user_service::bulk_update_roles(
+        &pool,
+        admin_user.0.user_id,
+        &admin_user.0.role,
+        request.into_inner().assignments,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(result))
+}
+
 /// Get user by ID
 /// GET /api/v1/users/{id}
 #[utoipa::path(
@@ -112,6 +204,172 @@ pub async fn get_current_user(
     Ok(HttpResponse::Ok().json(ApiResponse::new(current_user)))
 }
 
+/// Get current user's recent activity
+/// GET /api/v1/users/me/activity
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/me/activity",
+    tag = "users",
+    params(ActivityQueryParams),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Activity feed retrieved successfully", body = [ActivityItem]),
+        (status = 400, description = "Invalid query parameters"),
+        (status = 401, description = "Unauthorized")
+    )
+)]
+#[get("/me/activity")]
+pub async fn get_current_user_activity(
+    pool: web::Data<PgPool>,
+    query: web::Query<ActivityQueryParams>,
+    auth_user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    query.validate()?;
+
+    let limit = query.limit.unwrap_or(20);
+    let activity = user_service::activity_feed(&pool, auth_user.user_id, limit).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::new(activity)))
+}
+
+/// Get how the current user's translation points were earned
+/// GET /api/v1/users/me/points
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/me/points",
+    tag = "users",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default and maximum come from pagination settings)")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Point awards and deductions, newest first", body = PointsLedgerPaginatedResponse),
+        (status = 401, description = "Unauthorized")
+    )
+)]
+#[get("/me/points")]
+pub async fn get_current_user_points(
+    pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
+    query: web::Query<PageQueryParams>,
+    auth_user: AuthenticatedUser,
+) -> Result<HttpResponse, AppError> {
+    let (page, per_page) = settings.pagination.resolve(query.page, query.per_page);
+
+    let history = user_service::points_history(&pool, auth_user.user_id, page, per_page).await?;
+
+    Ok(HttpResponse::Ok().json(history))
+}
+
+/// List a user's recent submissions for vetting (moderators only)
+/// GET /api/v1/users/{id}/submissions
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{id}/submissions",
+    tag = "users",
+    params(
+        ("id" = Uuid, Path, description = "User ID"),
+        ("page" = Option<i64>, Query, description = "Page number (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Items per page (default and maximum come from pagination settings)")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "Dictionary entries, translations and contributions, newest first", body = UserSubmissionPaginatedResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Moderator access required"),
+        (status = 404, description = "User not found")
+    )
+)]
+#[get("/{id}/submissions")]
+pub async fn list_user_submissions(
+    pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
+    path: web::Path<Uuid>,
+    query: web::Query<PageQueryParams>,
+    _moderator: ModeratorUser,
+) -> Result<HttpResponse, AppError> {
+    let (page, per_page) = settings.pagination.resolve(query.page, query.per_page);
+
+    let submissions =
+        user_service::list_submissions(&pool, path.into_inner(), page, per_page).await?;
+
+    Ok(HttpResponse::Ok().json(submissions))
+}
+
+/// Export all data stored about the current user
+/// GET /api/v1/users/me/export
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/me/export",
+    tag = "users",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "User data exported successfully", body = UserDataExport),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "User not found"),
+        (status = 429, description = "Too many concurrent expensive requests for this user")
+    )
+)]
+#[get("/me/export")]
+pub async fn export_current_user_data(
+    pool: web::Data<PgPool>,
+    auth_user: AuthenticatedUser,
+    _permit: ExpensiveRequestPermit,
+) -> Result<HttpResponse, AppError> {
+    let export = user_service::export_user_data(&pool, auth_user.user_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::new(export)))
+}
+
+/// Export all data stored about a user (self or admin)
+/// GET /api/v1/users/{id}/export
+#[utoipa::path(
+    get,
+    path = "/api/v1/users/{id}/export",
+    tag = "users",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "User data exported successfully", body = UserDataExport),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - can only export own data or admin required"),
+        (status = 404, description = "User not found"),
+        (status = 429, description = "Too many concurrent expensive requests for this user")
+    )
+)]
+#[get("/{id}/export")]
+pub async fn export_user_data(
+    pool: web::Data<PgPool>,
+    path: web::Path<Uuid>,
+    auth_user: AuthenticatedUser,
+    _permit: ExpensiveRequestPermit,
+) -> Result<HttpResponse, AppError> {
+    let user_id = path.into_inner();
+
+    if !auth_user.can_access_user(user_id) {
+        return Err(AppError::Forbidden(
+            "You can only export your own data or you need admin privileges".to_string(),
+        ));
+    }
+
+    let export = user_service::export_user_data(&pool, user_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::new(export)))
+}
+
 /// List users with pagination and filtering
 /// GET /api/v1/users
 #[utoipa::path(
@@ -132,13 +390,15 @@ pub async fn get_current_user(
 #[get("")]
 pub async fn list_users(
     pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
     query: web::Query<UserQueryParams>,
     _admin_user: AdminUser, // Only admins can list all users
 ) -> Result<HttpResponse, AppError> {
     // Validate query parameters
     query.validate()?;
 
-    let users = user_service::list_users(&pool, query.into_inner()).await?;
+    let (page, per_page) = settings.pagination.resolve(query.page, query.per_page);
+    let users = user_service::list_users(&pool, query.into_inner(), page, per_page).await?;
 
     Ok(HttpResponse::Ok().json(users))
 }
@@ -229,6 +489,7 @@ pub async fn update_current_user(
 #[patch("/{id}/password")]
 pub async fn update_user_password(
     pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
     path: web::Path<Uuid>,
     request: web::Json<UpdatePasswordRequest>,
     auth_user: AuthenticatedUser,
@@ -245,7 +506,8 @@ pub async fn update_user_password(
         ));
     }
 
-    user_service::update_user_password(&pool, user_id, request.into_inner()).await?;
+    user_service::update_user_password(&pool, &settings.security, user_id, request.into_inner())
+        .await?;
 
     Ok(HttpResponse::Ok().json(SuccessResponse::new(
         "Password updated successfully".to_string(),
@@ -257,13 +519,20 @@ pub async fn update_user_password(
 #[patch("/me/password")]
 pub async fn update_current_user_password(
     pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
     request: web::Json<UpdatePasswordRequest>,
     auth_user: AuthenticatedUser,
 ) -> Result<HttpResponse, AppError> {
     // Validate request
     request.validate()?;
 
-    user_service::update_user_password(&pool, auth_user.user_id, request.into_inner()).await?;
+    user_service::update_user_password(
+        &pool,
+        &settings.security,
+        auth_user.user_id,
+        request.into_inner(),
+    )
+    .await?;
 
     Ok(HttpResponse::Ok().json(SuccessResponse::new(
         "Password updated successfully".to_string(),
@@ -311,6 +580,39 @@ pub async fn delete_user(
     )))
 }
 
+/// Permanently erase a user's personal data (admin only)
+/// DELETE /api/v1/users/{id}/purge
+#[utoipa::path(
+    delete,
+    path = "/api/v1/users/{id}/purge",
+    tag = "users",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "User data purged successfully", body = SuccessResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 404, description = "User not found")
+    )
+)]
+#[delete("/{id}/purge")]
+pub async fn purge_user(
+    pool: web::Data<PgPool>,
+    settings: web::Data<Settings>,
+    path: web::Path<Uuid>,
+    _admin_user: AdminUser,
+) -> Result<HttpResponse, AppError> {
+    user_service::purge_user(&pool, &settings.security, path.into_inner()).await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::new(
+        "User data purged successfully".to_string(),
+    )))
+}
+
 /// Delete current user account (soft delete)
 /// DELETE /api/v1/users/me
 #[delete("/me")]
@@ -351,14 +653,16 @@ pub async fn award_points(
     pool: web::Data<PgPool>,
     path: web::Path<Uuid>,
     request: web::Json<AwardPointsRequest>,
-    _admin_user: AdminUser, // Only admins can award points
+    admin_user: AdminUser, // Only admins can award points
 ) -> Result<HttpResponse, AppError> {
     let user_id = path.into_inner();
 
     // Validate request
     request.validate()?;
 
-    let user = user_service::award_points(&pool, user_id, request.into_inner()).await?;
+    let user =
+        user_service::award_points(&pool, user_id, admin_user.0.user_id, request.into_inner())
+            .await?;
 
     Ok(HttpResponse::Ok().json(ApiResponse::new(user)))
 }
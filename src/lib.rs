@@ -4,6 +4,7 @@ pub mod dto;
 pub mod error;
 pub mod handlers;
 pub mod logging;
+pub mod metrics;
 pub mod middleware;
 pub mod openapi;
 pub mod services;
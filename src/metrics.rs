@@ -0,0 +1,89 @@
+//! Process-wide counters exposed in the Prometheus text format.
+
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        OnceLock,
+    },
+    time::{Duration, Instant},
+};
+
+static STARTED_AT: OnceLock<Instant> = OnceLock::new();
+
+/// Start the uptime clock; later calls keep the first start time
+pub fn mark_started() {
+    STARTED_AT.get_or_init(Instant::now);
+}
+
+/// Time since `mark_started` was first called
+pub fn uptime() -> Duration {
+    STARTED_AT.get_or_init(Instant::now).elapsed()
+}
+
+/// Why a request failed authentication
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthFailureReason {
+    MissingToken,
+    Expired,
+    InvalidSignature,
+    InvalidToken,
+    UserNotFound,
+}
+
+impl AuthFailureReason {
+    const ALL: [AuthFailureReason; 5] = [
+        AuthFailureReason::MissingToken,
+        AuthFailureReason::Expired,
+        AuthFailureReason::InvalidSignature,
+        AuthFailureReason::InvalidToken,
+        AuthFailureReason::UserNotFound,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuthFailureReason::MissingToken => "missing_token",
+            AuthFailureReason::Expired => "expired",
+            AuthFailureReason::InvalidSignature => "invalid_signature",
+            AuthFailureReason::InvalidToken => "invalid_token",
+            AuthFailureReason::UserNotFound => "user_not_found",
+        }
+    }
+
+    fn counter(&self) -> &'static AtomicU64 {
+        &AUTH_FAILURES[*self as usize]
+    }
+}
+
+static AUTH_FAILURES: [AtomicU64; 5] = [
+    AtomicU64::new(0),
+    AtomicU64::new(0),
+    AtomicU64::new(0),
+    AtomicU64::new(0),
+    AtomicU64::new(0),
+];
+
+pub fn record_auth_failure(reason: AuthFailureReason) {
+    reason.counter().fetch_add(1, Ordering::Relaxed);
+}
+
+pub fn auth_failures(reason: AuthFailureReason) -> u64 {
+    reason.counter().load(Ordering::Relaxed)
+}
+
+/// Render every counter in the Prometheus text exposition format
+pub fn render() -> String {
+    let mut output = String::from(
+        "# HELP auth_failures_total Authentication failures by reason\n\
+         # TYPE auth_failures_total counter\n",
+    );
+
+    for reason in AuthFailureReason::ALL {
+        output.push_str(&format!(
+            "auth_failures_total{{reason=\"{}\"}} {}\n",
+            reason.as_str(),
+            auth_failures(reason)
+        ));
+    }
+
+    output
+}
@@ -1,4 +1,9 @@
-use crate::{error::AppError, utils::jwt};
+use crate::{
+    config::Settings,
+    error::AppError,
+    metrics::{self, AuthFailureReason},
+    utils::jwt,
+};
 use actix_web::{
     dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
     web, Error, FromRequest, HttpMessage, HttpRequest,
@@ -9,6 +14,7 @@ use std::{
     future::{ready, Ready},
     rc::Rc,
 };
+use tracing::debug;
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
@@ -23,6 +29,11 @@ impl AuthenticatedUser {
         self.role == "admin"
     }
 
+    /// Check if the user has moderator privileges (moderator or admin)
+    pub fn is_moderator(&self) -> bool {
+        self.role == "moderator" || self.is_admin()
+    }
+
     /// Check if the user can access another user's data (admin or same user)
     pub fn can_access_user(&self, target_user_id: Uuid) -> bool {
         self.is_admin() || self.user_id == target_user_id
@@ -60,6 +71,25 @@ impl FromRequest for AdminUser {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct ModeratorUser(pub AuthenticatedUser);
+
+impl FromRequest for ModeratorUser {
+    type Error = AppError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut actix_web::dev::Payload) -> Self::Future {
+        let extensions = req.extensions();
+        let user = extensions.get::<AuthenticatedUser>().cloned();
+
+        ready(match user {
+            Some(user) if user.is_moderator() => Ok(ModeratorUser(user)),
+            Some(_) => Err(AppError::Forbidden("Moderator access required".to_string())),
+            None => Err(AppError::Unauthorized("User not authenticated".to_string())),
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AuthMiddleware;
 
@@ -105,26 +135,38 @@ where
             .get("Authorization")
             .and_then(|auth_header| auth_header.to_str().ok())
             .and_then(|auth_str| {
-                println!("Auth header: {}", auth_str); // Debug log
-                if auth_str.starts_with("Bearer ") {
-                    Some(auth_str[7..].to_string())
-                } else {
-                    None
+                auth_str
+                    .strip_prefix("Bearer ")
+                    .map(|token| token.to_string())
+            })
+            // Browser clients may send the session cookie instead of the header
+            .or_else(|| {
+                let settings = req.app_data::<web::Data<Settings>>()?;
+                if !settings.jwt.cookie_auth {
+                    return None;
                 }
+                req.cookie(&settings.jwt.cookie_name)
+                    .map(|cookie| cookie.value().to_string())
             });
 
         let service = self.service.clone();
 
         Box::pin(async move {
             if let Some(token) = token {
-                println!("Token found: {}", &token[..std::cmp::min(20, token.len())]); // Debug log
-                match jwt::verify_token(&token) {
+                let settings = req
+                    .app_data::<web::Data<Settings>>()
+                    .ok_or_else(|| AppError::Internal("Settings not found".to_string()))?;
+
+                match jwt::verify_token(&token, &settings.jwt) {
                     Ok(claims) => {
-                        let user_id = claims.user_id()?;
-                        
+                        let user_id = claims.user_id().inspect_err(|_| {
+                            metrics::record_auth_failure(AuthFailureReason::InvalidToken)
+                        })?;
+
                         // Get the database pool from app data
-                        let pool = req.app_data::<web::Data<PgPool>>()
-                            .ok_or_else(|| AppError::Internal("Database pool not found".to_string()))?;
+                        let pool = req.app_data::<web::Data<PgPool>>().ok_or_else(|| {
+                            AppError::Internal("Database pool not found".to_string())
+                        })?;
 
                         // Fetch user role from database
                         let user_role = match sqlx::query("SELECT role FROM users WHERE id = $1")
@@ -133,7 +175,12 @@ where
                             .await
                         {
                             Ok(Some(row)) => row.get::<String, _>("role"),
-                            Ok(None) => return Err(AppError::Unauthorized("User not found".to_string()).into()),
+                            Ok(None) => {
+                                metrics::record_auth_failure(AuthFailureReason::UserNotFound);
+                                return Err(
+                                    AppError::Unauthorized("User not found".to_string()).into()
+                                );
+                            }
                             Err(_) => "user".to_string(), // Fallback to default role if DB query fails
                         };
 
@@ -145,12 +192,13 @@ where
                         service.call(req).await
                     }
                     Err(err) => {
-                        println!("JWT verification failed: {}", err); // Debug log
+                        debug!("JWT verification failed: {}", err);
                         Err(err.into())
                     }
                 }
             } else {
-                println!("No token found in request"); // Debug log
+                debug!("No token found in request");
+                metrics::record_auth_failure(AuthFailureReason::MissingToken);
                 Err(AppError::Unauthorized("Missing authentication token".to_string()).into())
             }
         })
@@ -0,0 +1,144 @@
+use crate::{error::AppError, middleware::auth::AuthenticatedUser};
+use actix_web::{dev::Payload, web, FromRequest, HttpMessage, HttpRequest};
+use std::{
+    collections::HashMap,
+    future::{ready, Ready},
+    sync::{Mutex, PoisonError},
+};
+use uuid::Uuid;
+
+/// Per-user count of in-flight expensive requests, shared across workers
+#[derive(Debug)]
+pub struct UserConcurrencyLimiter {
+    max_in_flight: usize,
+    in_flight: Mutex<HashMap<Uuid, usize>>,
+}
+
+impl UserConcurrencyLimiter {
+    pub fn new(max_in_flight: usize) -> Self {
+        Self {
+            max_in_flight,
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Take a slot for `user_id`, or `false` when all of the user's slots are busy
+    fn try_acquire(&self, user_id: Uuid) -> bool {
+        let mut in_flight = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let count = in_flight.entry(user_id).or_insert(0);
+
+        if *count >= self.max_in_flight {
+            return false;
+        }
+
+        *count += 1;
+        true
+    }
+
+    fn release(&self, user_id: Uuid) {
+        let mut in_flight = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(count) = in_flight.get_mut(&user_id) {
+            *count -= 1;
+            if *count == 0 {
+                in_flight.remove(&user_id);
+            }
+        }
+    }
+}
+
+/// Slot held by an expensive request for as long as the permit lives.
+///
+/// Extracting it fails with 429 once the caller already has the configured
+/// number of expensive requests in flight; excess requests are rejected rather
+/// than queued. Streaming handlers move the permit into the body stream so the
+/// slot stays taken until the download finishes. Without a registered
+/// [`UserConcurrencyLimiter`] every request is admitted.
+#[derive(Debug)]
+pub struct ExpensiveRequestPermit {
+    slot: Option<(web::Data<UserConcurrencyLimiter>, Uuid)>,
+}
+
+impl Drop for ExpensiveRequestPermit {
+    fn drop(&mut self) {
+        if let Some((limiter, user_id)) = &self.slot {
+            limiter.release(*user_id);
+        }
+    }
+}
+
+impl FromRequest for ExpensiveRequestPermit {
+    type Error = AppError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let Some(limiter) = req.app_data::<web::Data<UserConcurrencyLimiter>>().cloned() else {
+            return ready(Ok(Self { slot: None }));
+        };
+
+        let Some(user_id) = req
+            .extensions()
+            .get::<AuthenticatedUser>()
+            .map(|user| user.user_id)
+        else {
+            return ready(Err(AppError::Unauthorized(
+                "User not authenticated".to_string(),
+            )));
+        };
+
+        if !limiter.try_acquire(user_id) {
+            return ready(Err(AppError::TooManyRequests(format!(
+                "At most {} expensive requests may run at once per user",
+                limiter.max_in_flight
+            ))));
+        }
+
+        ready(Ok(Self {
+            slot: Some((limiter, user_id)),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+
+    async fn permit_for(
+        limiter: &web::Data<UserConcurrencyLimiter>,
+        user_id: Uuid,
+    ) -> Result<ExpensiveRequestPermit, AppError> {
+        let req = TestRequest::default()
+            .app_data(limiter.clone())
+            .to_http_request();
+        req.extensions_mut().insert(AuthenticatedUser {
+            user_id,
+            role: "user".to_string(),
+        });
+
+        ExpensiveRequestPermit::extract(&req).await
+    }
+
+    #[actix_web::test]
+    async fn request_beyond_the_users_limit_is_rejected() {
+        let limiter = web::Data::new(UserConcurrencyLimiter::new(2));
+        let user_id = Uuid::new_v4();
+
+        let first = permit_for(&limiter, user_id).await.unwrap();
+        let _second = permit_for(&limiter, user_id).await.unwrap();
+        let third = permit_for(&limiter, user_id).await;
+        let other_user = permit_for(&limiter, Uuid::new_v4()).await;
+
+        assert!(matches!(third, Err(AppError::TooManyRequests(_))));
+        assert!(other_user.is_ok());
+
+        drop(first);
+        assert!(permit_for(&limiter, user_id).await.is_ok());
+    }
+}
@@ -0,0 +1,170 @@
+use crate::error::AppError;
+use actix_web::{
+    body::EitherBody,
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    error::InternalError,
+    http::header::Accept,
+    mime, Error, HttpMessage, HttpRequest,
+};
+use futures_util::future::LocalBoxFuture;
+use std::{
+    future::{ready, Ready},
+    rc::Rc,
+};
+
+/// Re-renders `AppError` responses as plain text when the client's `Accept`
+/// header prefers `text/plain` over JSON. JSON stays the default.
+#[derive(Debug, Clone)]
+pub struct ErrorContentNegotiation;
+
+impl<S, B> Transform<S, ServiceRequest> for ErrorContentNegotiation
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = ErrorContentNegotiationService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(ErrorContentNegotiationService {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct ErrorContentNegotiationService<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for ErrorContentNegotiationService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+
+        if !prefers_plain_text(req.request()) {
+            return Box::pin(async move {
+                service
+                    .call(req)
+                    .await
+                    .map(ServiceResponse::map_into_left_body)
+            });
+        }
+
+        Box::pin(async move {
+            match service.call(req).await {
+                Ok(res) => {
+                    let plain = res
+                        .response()
+                        .error()
+                        .and_then(|err| err.as_error::<AppError>())
+                        .map(AppError::plain_text_response);
+
+                    Ok(match plain {
+                        Some(plain) => res.into_response(plain).map_into_right_body(),
+                        None => res.map_into_left_body(),
+                    })
+                }
+                // Errors raised by inner middleware (e.g. authentication). The
+                // request can't be cloned ahead of routing, so the plain-text
+                // body rides along on the error instead.
+                Err(err) => match err
+                    .as_error::<AppError>()
+                    .map(AppError::plain_text_response)
+                {
+                    Some(plain) => Err(InternalError::from_response(err, plain).into()),
+                    None => Err(err),
+                },
+            }
+        })
+    }
+}
+
+/// Whether the highest-ranked acceptable type is plain text rather than JSON
+fn prefers_plain_text(req: &HttpRequest) -> bool {
+    let Some(accept) = req.get_header::<Accept>() else {
+        return false;
+    };
+
+    for mime in accept.ranked() {
+        match (mime.type_(), mime.subtype()) {
+            (mime::TEXT, mime::PLAIN) | (mime::TEXT, mime::STAR) => return true,
+            (mime::APPLICATION, mime::JSON)
+            | (mime::APPLICATION, mime::STAR)
+            | (mime::STAR, mime::STAR) => return false,
+            _ => {}
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{
+        http::header,
+        test::{call_service, init_service, read_body, TestRequest},
+        web, App, HttpResponse,
+    };
+
+    fn prefers_plain(accept: Option<&str>) -> bool {
+        let mut req = TestRequest::default();
+        if let Some(accept) = accept {
+            req = req.insert_header((header::ACCEPT, accept));
+        }
+        prefers_plain_text(&req.to_http_request())
+    }
+
+    #[test]
+    fn json_is_the_default() {
+        assert!(!prefers_plain(None));
+        assert!(!prefers_plain(Some("*/*")));
+        assert!(!prefers_plain(Some("application/json")));
+    }
+
+    #[test]
+    fn plain_text_wins_when_ranked_first() {
+        assert!(prefers_plain(Some("text/plain")));
+        assert!(prefers_plain(Some("application/json;q=0.5, text/plain")));
+        assert!(!prefers_plain(Some("text/plain;q=0.5, application/json")));
+    }
+
+    #[actix_web::test]
+    async fn errors_render_as_plain_text_when_requested() {
+        let app = init_service(App::new().wrap(ErrorContentNegotiation).route(
+            "/",
+            web::get().to(|| async {
+                Err::<HttpResponse, _>(AppError::NotFound("Entry not found".to_string()))
+            }),
+        ))
+        .await;
+        let req = TestRequest::get()
+            .uri("/")
+            .insert_header((header::ACCEPT, "text/plain"))
+            .to_request();
+
+        let res = call_service(&app, req).await;
+
+        assert_eq!(res.status(), 404);
+        let content_type = res.headers().get(header::CONTENT_TYPE).unwrap();
+        assert!(content_type.to_str().unwrap().starts_with("text/plain"));
+        let body = read_body(res).await;
+        assert!(std::str::from_utf8(&body)
+            .unwrap()
+            .contains("Entry not found"));
+    }
+}
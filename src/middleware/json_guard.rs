@@ -0,0 +1,216 @@
+use crate::{config::JsonLimitSettings, error::AppError};
+use actix_web::{
+    dev::{forward_ready, Payload, Service, ServiceRequest, ServiceResponse, Transform},
+    mime,
+    web::BytesMut,
+    Error, HttpMessage,
+};
+use futures_util::{future::LocalBoxFuture, StreamExt};
+use std::{
+    future::{ready, Ready},
+    rc::Rc,
+};
+
+/// Rejects JSON request bodies that nest too deeply or carry too many object
+/// fields, before any handler deserializes them. Bodies are buffered up to
+/// `max_body_bytes`, scanned without building a value, then handed on intact.
+#[derive(Debug, Clone)]
+pub struct JsonBodyGuard {
+    limits: JsonLimitSettings,
+}
+
+impl JsonBodyGuard {
+    pub fn new(limits: JsonLimitSettings) -> Self {
+        Self { limits }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for JsonBodyGuard
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = JsonBodyGuardService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(JsonBodyGuardService {
+            service: Rc::new(service),
+            limits: self.limits.clone(),
+        }))
+    }
+}
+
+pub struct JsonBodyGuardService<S> {
+    service: Rc<S>,
+    limits: JsonLimitSettings,
+}
+
+impl<S, B> Service<ServiceRequest> for JsonBodyGuardService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+
+        let is_json = req.mime_type().ok().flatten().is_some_and(|mime_type| {
+            mime_type.subtype() == mime::JSON || mime_type.suffix() == Some(mime::JSON)
+        });
+        if !is_json {
+            return Box::pin(async move { service.call(req).await });
+        }
+
+        let limits = self.limits.clone();
+
+        Box::pin(async move {
+            let mut payload = req.take_payload();
+            let mut body = BytesMut::new();
+
+            while let Some(chunk) = payload.next().await {
+                let chunk = chunk?;
+                if body.len() + chunk.len() > limits.max_body_bytes {
+                    return Err(AppError::Validation(format!(
+                        "JSON body exceeds {} bytes",
+                        limits.max_body_bytes
+                    ))
+                    .into());
+                }
+                body.extend_from_slice(&chunk);
+            }
+
+            check_shape(&body, &limits)?;
+
+            req.set_payload(Payload::from(body.freeze()));
+            service.call(req).await
+        })
+    }
+}
+
+/// Scan raw JSON for nesting depth and object field count. Malformed input is
+/// left for the JSON extractor to report.
+fn check_shape(body: &[u8], limits: &JsonLimitSettings) -> Result<(), AppError> {
+    let mut depth = 0usize;
+    let mut fields = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for &byte in body {
+        if in_string {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match byte {
+            b'"' => in_string = true,
+            b'{' | b'[' => {
+                depth += 1;
+                if depth > limits.max_depth {
+                    return Err(AppError::Validation(format!(
+                        "JSON body nests deeper than {} levels",
+                        limits.max_depth
+                    )));
+                }
+            }
+            b'}' | b']' => depth = depth.saturating_sub(1),
+            // Each key/value separator outside a string is one object field
+            b':' => {
+                fields += 1;
+                if fields > limits.max_fields {
+                    return Err(AppError::Validation(format!(
+                        "JSON body has more than {} fields",
+                        limits.max_fields
+                    )));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{
+        test::{init_service, try_call_service, TestRequest},
+        web, App, HttpResponse,
+    };
+
+    fn limits() -> JsonLimitSettings {
+        JsonLimitSettings {
+            max_body_bytes: 1024,
+            max_depth: 4,
+            max_fields: 3,
+        }
+    }
+
+    #[test]
+    fn accepts_body_within_limits() {
+        let body = br#"{"a": [1, {"b": "x"}], "c": null}"#;
+
+        assert!(check_shape(body, &limits()).is_ok());
+    }
+
+    #[test]
+    fn rejects_deep_nesting() {
+        let body = format!("{}{}", "[".repeat(5), "]".repeat(5));
+
+        let err = check_shape(body.as_bytes(), &limits()).unwrap_err();
+
+        assert!(err.to_string().contains("deeper than 4 levels"));
+    }
+
+    #[test]
+    fn rejects_too_many_fields() {
+        let body = br#"{"a": 1, "b": 2, "c": 3, "d": 4}"#;
+
+        let err = check_shape(body, &limits()).unwrap_err();
+
+        assert!(err.to_string().contains("more than 3 fields"));
+    }
+
+    #[test]
+    fn ignores_structure_inside_strings() {
+        let body = br#"{"text": "[[[[[{:}:}:}\" ::: ]]]]]"}"#;
+
+        assert!(check_shape(body, &limits()).is_ok());
+    }
+
+    #[actix_web::test]
+    async fn pathologically_nested_body_is_rejected() {
+        let app = init_service(
+            App::new()
+                .wrap(JsonBodyGuard::new(limits()))
+                .route("/", web::post().to(HttpResponse::Ok)),
+        )
+        .await;
+        let body = format!("{}{}", "[".repeat(500), "]".repeat(500));
+        let req = TestRequest::post()
+            .uri("/")
+            .insert_header(("content-type", "application/json"))
+            .set_payload(body)
+            .to_request();
+
+        let err = try_call_service(&app, req).await.unwrap_err();
+
+        assert_eq!(err.error_response().status(), 400);
+    }
+}
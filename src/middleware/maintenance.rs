@@ -0,0 +1,183 @@
+use crate::{config::MaintenanceSettings, error::AppError};
+use actix_web::{
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    http::Method,
+    web, Error,
+};
+use futures_util::future::LocalBoxFuture;
+use std::{
+    future::{ready, Ready},
+    rc::Rc,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Paths that keep accepting writes while maintenance mode is on
+const EXEMPT_PATHS: &[&str] = &[
+    "/api/v1/health",
+    "/api/v1/ping",
+    "/api/v1/admin/maintenance",
+];
+
+/// Runtime maintenance-mode switch shared across workers
+#[derive(Debug)]
+pub struct MaintenanceMode {
+    enabled: AtomicBool,
+    retry_after_seconds: u64,
+}
+
+impl MaintenanceMode {
+    pub fn new(settings: &MaintenanceSettings) -> Self {
+        Self {
+            enabled: AtomicBool::new(settings.enabled),
+            retry_after_seconds: settings.retry_after_seconds,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn retry_after_seconds(&self) -> u64 {
+        self.retry_after_seconds
+    }
+}
+
+/// Rejects non-read requests with [`AppError::Maintenance`] (503) while
+/// maintenance mode is on
+#[derive(Debug, Clone)]
+pub struct MaintenanceMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for MaintenanceMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = MaintenanceMiddlewareService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(MaintenanceMiddlewareService {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct MaintenanceMiddlewareService<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for MaintenanceMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let is_read = matches!(*req.method(), Method::GET | Method::HEAD | Method::OPTIONS);
+        let retry_after = req
+            .app_data::<web::Data<MaintenanceMode>>()
+            .filter(|mode| mode.is_enabled())
+            .map(|mode| mode.retry_after_seconds());
+
+        if let Some(retry_after) = retry_after {
+            if !is_read && !EXEMPT_PATHS.contains(&req.path()) {
+                // Returned as an error so the outer middleware render it like
+                // any other: trace id, plain-text negotiation and all
+                return Box::pin(ready(Err(AppError::Maintenance {
+                    retry_after_seconds: retry_after,
+                }
+                .into())));
+            }
+        }
+
+        Box::pin(self.service.call(req))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::middleware::content_negotiation::ErrorContentNegotiation;
+    use actix_web::{
+        body::to_bytes,
+        http::{header, StatusCode},
+        test::{call_service, init_service, try_call_service, TestRequest},
+        App, HttpResponse,
+    };
+
+    #[actix_web::test]
+    async fn writes_are_rejected_only_while_maintenance_is_on() {
+        let mode = web::Data::new(MaintenanceMode::new(&MaintenanceSettings {
+            enabled: true,
+            retry_after_seconds: 120,
+        }));
+        let app = init_service(
+            App::new()
+                .app_data(mode.clone())
+                .wrap(MaintenanceMiddleware)
+                .route("/items", web::post().to(HttpResponse::Created))
+                .route("/items", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+
+        let err = try_call_service(&app, TestRequest::post().uri("/items").to_request())
+            .await
+            .expect_err("write during maintenance");
+        let res = err.error_response();
+        assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(res.headers().get("Retry-After").unwrap(), "120");
+
+        let res = call_service(&app, TestRequest::get().uri("/items").to_request()).await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        mode.set_enabled(false);
+        let res = call_service(&app, TestRequest::post().uri("/items").to_request()).await;
+        assert_eq!(res.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn rejection_is_negotiated_like_any_other_error() {
+        let mode = web::Data::new(MaintenanceMode::new(&MaintenanceSettings {
+            enabled: true,
+            retry_after_seconds: 30,
+        }));
+        let app = init_service(
+            App::new()
+                .app_data(mode)
+                .wrap(MaintenanceMiddleware)
+                .wrap(ErrorContentNegotiation)
+                .route("/items", web::post().to(HttpResponse::Created)),
+        )
+        .await;
+
+        let err = try_call_service(
+            &app,
+            TestRequest::post()
+                .uri("/items")
+                .insert_header((header::ACCEPT, "text/plain"))
+                .to_request(),
+        )
+        .await
+        .expect_err("write during maintenance");
+        let res = err.error_response();
+
+        assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(res.headers().get(header::RETRY_AFTER).unwrap(), "30");
+        let body = to_bytes(res.into_body()).await.unwrap();
+        assert!(body.starts_with(b"MAINTENANCE_MODE: "));
+    }
+}
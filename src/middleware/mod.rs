@@ -1 +1,9 @@
 pub mod auth;
+pub mod concurrency;
+pub mod content_negotiation;
+pub mod json_guard;
+pub mod maintenance;
+pub mod pretty_json;
+pub mod rate_limit;
+pub mod trace_context;
+pub mod transaction;
@@ -0,0 +1,169 @@
+use actix_web::{
+    body::{self, BodySize, BoxBody, EitherBody, MessageBody},
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    error::ErrorInternalServerError,
+    http::header::CONTENT_TYPE,
+    mime, Error,
+};
+use futures_util::future::LocalBoxFuture;
+use std::{
+    future::{ready, Ready},
+    rc::Rc,
+};
+
+/// Re-indents JSON response bodies for readability when enabled
+/// (`application.pretty_json`). Other content types, such as the SSE stream,
+/// and streamed bodies, such as the dictionary export, pass through untouched.
+#[derive(Debug, Clone)]
+pub struct PrettyJson {
+    enabled: bool,
+}
+
+impl PrettyJson {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for PrettyJson
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B, BoxBody>>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = PrettyJsonService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(PrettyJsonService {
+            service: Rc::new(service),
+            enabled: self.enabled,
+        }))
+    }
+}
+
+pub struct PrettyJsonService<S> {
+    service: Rc<S>,
+    enabled: bool,
+}
+
+impl<S, B> Service<ServiceRequest> for PrettyJsonService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B, BoxBody>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let enabled = self.enabled;
+
+        Box::pin(async move {
+            let res = service.call(req).await?;
+
+            if !enabled || !is_json(&res) || is_streamed(&res) {
+                return Ok(res.map_into_left_body());
+            }
+
+            let (req, res) = res.into_parts();
+            let (res, body) = res.into_parts();
+            let compact = body::to_bytes(body.boxed())
+                .await
+                .map_err(|e| ErrorInternalServerError(e.to_string()))?;
+
+            let body = match prettify(&compact) {
+                Some(pretty) => BoxBody::new(pretty),
+                None => BoxBody::new(compact),
+            };
+
+            Ok(ServiceResponse::new(req, res.set_body(body)).map_into_right_body())
+        })
+    }
+}
+
+fn is_json<B>(res: &ServiceResponse<B>) -> bool {
+    res.headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<mime::Mime>().ok())
+        .is_some_and(|mime| mime.type_() == mime::APPLICATION && mime.subtype() == mime::JSON)
+}
+
+/// Bodies of unknown length are streamed and must not be buffered
+fn is_streamed<B: MessageBody>(res: &ServiceResponse<B>) -> bool {
+    matches!(res.response().body().size(), BodySize::Stream)
+}
+
+/// Re-serialize a JSON document with indentation, keeping its key order
+fn prettify(compact: &[u8]) -> Option<Vec<u8>> {
+    let mut deserializer = serde_json::Deserializer::from_slice(compact);
+    let mut pretty = Vec::with_capacity(compact.len() * 2);
+    let mut serializer = serde_json::Serializer::pretty(&mut pretty);
+
+    serde_transcode::transcode(&mut deserializer, &mut serializer).ok()?;
+    deserializer.end().ok()?;
+
+    Some(pretty)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{
+        test::{call_service, init_service, read_body, TestRequest},
+        web::{self, Bytes},
+        App, HttpResponse,
+    };
+    use futures_util::stream;
+
+    async fn body_with(pretty_json: bool, path: &str) -> Bytes {
+        let app = init_service(
+            App::new()
+                .wrap(PrettyJson::new(pretty_json))
+                .route(
+                    "/json",
+                    web::get()
+                        .to(|| async { HttpResponse::Ok().json(serde_json::json!({"a": [1]})) }),
+                )
+                .route(
+                    "/stream",
+                    web::get().to(|| async {
+                        HttpResponse::Ok()
+                            .content_type("application/json")
+                            .streaming(stream::iter([Ok::<_, Error>(Bytes::from_static(
+                                br#"{"a":[1]}"#,
+                            ))]))
+                    }),
+                ),
+        )
+        .await;
+
+        read_body(call_service(&app, TestRequest::get().uri(path).to_request()).await).await
+    }
+
+    #[actix_web::test]
+    async fn indents_json_when_enabled() {
+        assert_eq!(
+            body_with(true, "/json").await,
+            "{\n  \"a\": [\n    1\n  ]\n}"
+        );
+    }
+
+    #[actix_web::test]
+    async fn leaves_json_compact_when_disabled() {
+        assert_eq!(body_with(false, "/json").await, r#"{"a":[1]}"#);
+    }
+
+    #[actix_web::test]
+    async fn leaves_streamed_json_untouched() {
+        assert_eq!(body_with(true, "/stream").await, r#"{"a":[1]}"#);
+    }
+}
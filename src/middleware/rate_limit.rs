@@ -0,0 +1,231 @@
+use crate::config::RateLimitSettings;
+use actix_web::{
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    error::InternalError,
+    http::header::{HeaderMap, HeaderName, HeaderValue},
+    web, Error,
+};
+use futures_util::future::LocalBoxFuture;
+use std::{
+    collections::HashMap,
+    future::{ready, Ready},
+    rc::Rc,
+    sync::{Mutex, PoisonError},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const X_RATELIMIT_LIMIT: HeaderName = HeaderName::from_static("x-ratelimit-limit");
+const X_RATELIMIT_REMAINING: HeaderName = HeaderName::from_static("x-ratelimit-remaining");
+const X_RATELIMIT_RESET: HeaderName = HeaderName::from_static("x-ratelimit-reset");
+
+/// A caller's standing in the current window
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitStatus {
+    pub limit: u32,
+    pub remaining: u32,
+    /// Unix timestamp at which the window resets
+    pub reset: u64,
+}
+
+/// Fixed-window request counts per caller, shared across workers.
+///
+/// Only the current window is kept: counts are dropped wholesale when a new
+/// window starts, so memory stays bounded by the callers seen in one window.
+#[derive(Debug)]
+pub struct RateLimiter {
+    limit: u32,
+    window_seconds: u64,
+    window: Mutex<(u64, HashMap<String, u32>)>,
+}
+
+impl RateLimiter {
+    pub fn new(settings: &RateLimitSettings) -> Self {
+        Self {
+            limit: settings.requests_per_window,
+            window_seconds: settings.window_seconds.max(1),
+            window: Mutex::new((0, HashMap::new())),
+        }
+    }
+
+    /// Count one request from `caller` and report its bucket state
+    pub fn record(&self, caller: &str) -> RateLimitStatus {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+        let index = now / self.window_seconds;
+
+        let mut window = self.window.lock().unwrap_or_else(PoisonError::into_inner);
+        if window.0 != index {
+            *window = (index, HashMap::new());
+        }
+
+        let count = window.1.entry(caller.to_string()).or_insert(0);
+        *count = count.saturating_add(1);
+
+        RateLimitStatus {
+            limit: self.limit,
+            remaining: self.limit.saturating_sub(*count),
+            reset: (index + 1) * self.window_seconds,
+        }
+    }
+}
+
+/// Adds `X-RateLimit-*` headers describing the caller's current window so
+/// clients can pace themselves. Requests over the limit are not rejected.
+#[derive(Debug, Clone)]
+pub struct SoftRateLimit;
+
+impl<S, B> Transform<S, ServiceRequest> for SoftRateLimit
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = SoftRateLimitService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(SoftRateLimitService {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct SoftRateLimitService<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for SoftRateLimitService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let status = req.app_data::<web::Data<RateLimiter>>().map(|limiter| {
+            let caller = req
+                .connection_info()
+                .realip_remote_addr()
+                .unwrap_or("unknown")
+                .to_string();
+            limiter.record(&caller)
+        });
+
+        let service = self.service.clone();
+
+        Box::pin(async move {
+            match service.call(req).await {
+                Ok(mut res) => {
+                    if let Some(status) = status {
+                        insert_headers(res.headers_mut(), status);
+                    }
+                    Ok(res)
+                }
+                // Errors raised by inner middleware (e.g. authentication). The
+                // request can't be cloned ahead of routing, so the response
+                // with the headers rides along on the error instead.
+                Err(err) => {
+                    let Some(status) = status else {
+                        return Err(err);
+                    };
+                    let mut response = err.error_response();
+                    insert_headers(response.headers_mut(), status);
+
+                    Err(InternalError::from_response(err, response).into())
+                }
+            }
+        })
+    }
+}
+
+fn insert_headers(headers: &mut HeaderMap, status: RateLimitStatus) {
+    headers.insert(X_RATELIMIT_LIMIT, HeaderValue::from(status.limit));
+    headers.insert(X_RATELIMIT_REMAINING, HeaderValue::from(status.remaining));
+    headers.insert(X_RATELIMIT_RESET, HeaderValue::from(status.reset));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::middleware::auth::AuthMiddleware;
+    use actix_web::{
+        http::StatusCode,
+        test::{call_service, init_service, try_call_service, TestRequest},
+        App, HttpResponse,
+    };
+
+    fn limiter() -> web::Data<RateLimiter> {
+        web::Data::new(RateLimiter::new(&RateLimitSettings {
+            enabled: true,
+            requests_per_window: 10,
+            window_seconds: 3600,
+            max_concurrent_per_user: 0,
+        }))
+    }
+
+    fn header(res: &ServiceResponse, name: HeaderName) -> u64 {
+        res.headers()
+            .get(name)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap()
+    }
+
+    #[actix_web::test]
+    async fn remaining_count_drops_with_each_request() {
+        let app = init_service(
+            App::new()
+                .app_data(limiter())
+                .wrap(SoftRateLimit)
+                .route("/", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let first = call_service(&app, TestRequest::get().uri("/").to_request()).await;
+        let second = call_service(&app, TestRequest::get().uri("/").to_request()).await;
+
+        assert_eq!(header(&first, X_RATELIMIT_LIMIT), 10);
+        assert_eq!(header(&first, X_RATELIMIT_REMAINING), 9);
+        assert_eq!(header(&second, X_RATELIMIT_REMAINING), 8);
+        let reset = header(&second, X_RATELIMIT_RESET);
+        assert!(reset > now && reset <= now + 3600, "reset {}", reset);
+    }
+
+    #[actix_web::test]
+    async fn rejected_request_still_carries_the_headers() {
+        let app = init_service(
+            App::new()
+                .app_data(limiter())
+                .wrap(AuthMiddleware)
+                .wrap(SoftRateLimit)
+                .route("/", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+
+        let err = try_call_service(&app, TestRequest::get().uri("/").to_request())
+            .await
+            .expect_err("a request without a token should be rejected");
+
+        let response = err.error_response();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let remaining = response.headers().get(X_RATELIMIT_REMAINING).unwrap();
+        assert_eq!(remaining, "9");
+        assert!(response.headers().contains_key(X_RATELIMIT_RESET));
+    }
+}
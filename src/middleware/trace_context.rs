@@ -0,0 +1,299 @@
+use crate::error::AppError;
+use actix_web::{
+    body::EitherBody,
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    error::InternalError,
+    http::header::{self, HeaderName, HeaderValue},
+    Error, HttpMessage,
+};
+use futures_util::future::LocalBoxFuture;
+use std::{
+    future::{ready, Ready},
+    rc::Rc,
+};
+use tracing::Instrument;
+use uuid::Uuid;
+
+const TRACEPARENT: HeaderName = HeaderName::from_static("traceparent");
+
+/// W3C trace context for the current request, available from request extensions
+#[derive(Debug, Clone)]
+pub struct TraceContext {
+    trace_id: String,
+    parent_id: Option<String>,
+    span_id: String,
+    flags: String,
+}
+
+impl TraceContext {
+    /// Continue the trace from an incoming `traceparent`, or start a new one
+    /// when the header is absent or malformed
+    fn from_header(value: Option<&str>) -> Self {
+        let span_id = random_hex(16);
+
+        match value.and_then(parse_traceparent) {
+            Some((trace_id, parent_id, flags)) => Self {
+                trace_id,
+                parent_id: Some(parent_id),
+                span_id,
+                flags,
+            },
+            None => Self {
+                trace_id: random_hex(32),
+                parent_id: None,
+                span_id,
+                flags: "01".to_string(),
+            },
+        }
+    }
+
+    pub fn trace_id(&self) -> &str {
+        &self.trace_id
+    }
+
+    /// `traceparent` value identifying this service's span to downstream callers
+    pub fn traceparent(&self) -> String {
+        format!("00-{}-{}-{}", self.trace_id, self.span_id, self.flags)
+    }
+}
+
+/// Parse `version-trace_id-parent_id-flags`, rejecting the invalid all-zero ids
+fn parse_traceparent(value: &str) -> Option<(String, String, String)> {
+    let mut parts = value.trim().split('-');
+    let version = parts.next()?;
+    let trace_id = parts.next()?;
+    let parent_id = parts.next()?;
+    let flags = parts.next()?;
+
+    // Version 00 has exactly four fields; later versions may append more
+    if version == "00" && parts.next().is_some() {
+        return None;
+    }
+
+    let is_hex = |s: &str, len: usize| {
+        s.len() == len && s.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+    };
+    let is_zero = |s: &str| s.bytes().all(|b| b == b'0');
+
+    if !is_hex(version, 2) || version == "ff" {
+        return None;
+    }
+    if !is_hex(trace_id, 32) || is_zero(trace_id) {
+        return None;
+    }
+    if !is_hex(parent_id, 16) || is_zero(parent_id) {
+        return None;
+    }
+    if !is_hex(flags, 2) {
+        return None;
+    }
+
+    Some((
+        trace_id.to_string(),
+        parent_id.to_string(),
+        flags.to_string(),
+    ))
+}
+
+fn random_hex(len: usize) -> String {
+    let mut hex = Uuid::new_v4().simple().to_string();
+    hex.truncate(len);
+    hex
+}
+
+/// Honors incoming `traceparent` headers, records the trace id on a tracing
+/// span and echoes a `traceparent` for this service's span on the response.
+/// JSON error bodies also carry the trace id for support correlation.
+#[derive(Debug, Clone)]
+pub struct TraceContextMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for TraceContextMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = TraceContextMiddlewareService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(TraceContextMiddlewareService {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct TraceContextMiddlewareService<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for TraceContextMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let context = TraceContext::from_header(
+            req.headers()
+                .get(&TRACEPARENT)
+                .and_then(|value| value.to_str().ok()),
+        );
+        let span = tracing::info_span!(
+            "trace_context",
+            trace_id = %context.trace_id,
+            parent_id = context.parent_id.as_deref().unwrap_or(""),
+            span_id = %context.span_id,
+        );
+        req.extensions_mut().insert(context.clone());
+
+        let service = self.service.clone();
+
+        Box::pin(
+            async move {
+                let mut res = match service.call(req).await {
+                    Ok(res) => {
+                        let is_json = res
+                            .headers()
+                            .get(header::CONTENT_TYPE)
+                            .and_then(|value| value.to_str().ok())
+                            .is_some_and(|value| value.starts_with("application/json"));
+                        let traced = res
+                            .response()
+                            .error()
+                            .and_then(|err| err.as_error::<AppError>())
+                            .filter(|_| is_json)
+                            .map(|app_err| app_err.json_response(Some(context.trace_id())));
+
+                        match traced {
+                            Some(traced) => res.into_response(traced).map_into_right_body(),
+                            None => res.map_into_left_body(),
+                        }
+                    }
+                    // Errors raised by inner middleware (e.g. authentication). The
+                    // request can't be cloned ahead of routing, so the traced
+                    // response rides along on the error instead.
+                    Err(err) => {
+                        let mut response = match err.as_error::<AppError>() {
+                            Some(app_err) => app_err.json_response(Some(context.trace_id())),
+                            None => err.error_response(),
+                        };
+                        if let Ok(value) = HeaderValue::from_str(&context.traceparent()) {
+                            response.headers_mut().insert(TRACEPARENT, value);
+                        }
+
+                        return Err(InternalError::from_response(err, response).into());
+                    }
+                };
+
+                if let Ok(value) = HeaderValue::from_str(&context.traceparent()) {
+                    res.headers_mut().insert(TRACEPARENT, value);
+                }
+
+                Ok(res)
+            }
+            .instrument(span),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{
+        test::{call_service, init_service, read_body, TestRequest},
+        web, App, HttpResponse,
+    };
+
+    const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+    const PARENT_ID: &str = "00f067aa0ba902b7";
+
+    #[test]
+    fn parses_valid_traceparent() {
+        let parsed = parse_traceparent(&format!("00-{}-{}-01", TRACE_ID, PARENT_ID));
+
+        assert_eq!(
+            parsed,
+            Some((
+                TRACE_ID.to_string(),
+                PARENT_ID.to_string(),
+                "01".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_traceparent() {
+        let zero_trace = "0".repeat(32);
+        let upper_trace = TRACE_ID.to_uppercase();
+        let invalid = [
+            String::new(),
+            format!("00-{}-{}", TRACE_ID, PARENT_ID),
+            format!("00-{}-{}-01-extra", TRACE_ID, PARENT_ID),
+            format!("ff-{}-{}-01", TRACE_ID, PARENT_ID),
+            format!("00-{}-{}-01", zero_trace, PARENT_ID),
+            format!("00-{}-0000000000000000-01", TRACE_ID),
+            format!("00-{}-{}-01", upper_trace, PARENT_ID),
+            format!("00-{}-{}-01", &TRACE_ID[1..], PARENT_ID),
+        ];
+
+        for value in invalid {
+            assert_eq!(parse_traceparent(&value), None, "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn future_versions_may_append_fields() {
+        let value = format!("01-{}-{}-01-extra", TRACE_ID, PARENT_ID);
+
+        assert!(parse_traceparent(&value).is_some());
+    }
+
+    #[actix_web::test]
+    async fn incoming_trace_id_is_echoed_on_errors() {
+        let app = init_service(App::new().wrap(TraceContextMiddleware).route(
+            "/",
+            web::get().to(|| async {
+                Err::<HttpResponse, _>(AppError::NotFound("Entry not found".to_string()))
+            }),
+        ))
+        .await;
+        let req = TestRequest::get()
+            .uri("/")
+            .insert_header((TRACEPARENT, format!("00-{}-{}-01", TRACE_ID, PARENT_ID)))
+            .to_request();
+
+        let res = call_service(&app, req).await;
+
+        let traceparent = res.headers().get(&TRACEPARENT).unwrap().to_str().unwrap();
+        let (trace_id, span_id, _) = parse_traceparent(traceparent).unwrap();
+        assert_eq!(trace_id, TRACE_ID);
+        assert_ne!(span_id, PARENT_ID);
+        let body = read_body(res).await;
+        assert!(std::str::from_utf8(&body).unwrap().contains(TRACE_ID));
+    }
+
+    #[actix_web::test]
+    async fn new_trace_is_started_without_traceparent() {
+        let app = init_service(
+            App::new()
+                .wrap(TraceContextMiddleware)
+                .route("/", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+
+        let res = call_service(&app, TestRequest::get().uri("/").to_request()).await;
+
+        let traceparent = res.headers().get(&TRACEPARENT).unwrap().to_str().unwrap();
+        assert!(parse_traceparent(traceparent).is_some());
+    }
+}
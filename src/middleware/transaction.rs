@@ -0,0 +1,168 @@
+use crate::error::{AppError, AppResult};
+use actix_web::{
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    web, Error, FromRequest, HttpMessage, HttpRequest,
+};
+use futures_util::future::LocalBoxFuture;
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+use std::{
+    cell::RefCell,
+    future::{ready, Ready},
+    ops::{Deref, DerefMut},
+    rc::Rc,
+};
+use tokio::sync::{Mutex, MutexGuard};
+use tracing::warn;
+
+/// The request transaction plus work deferred until it commits
+struct TxState {
+    transaction: Mutex<Option<Transaction<'static, Postgres>>>,
+    after_commit: RefCell<Vec<Box<dyn FnOnce()>>>,
+}
+
+type TxSlot = Rc<TxState>;
+
+/// A database transaction scoped to the current request.
+///
+/// Extracting `Tx` begins a transaction on the pool. [`TransactionMiddleware`]
+/// commits it when the handler responds with a 2xx status and rolls it back
+/// otherwise, so services called with it share one unit of work. Extract it at
+/// most once per handler.
+#[derive(Clone)]
+pub struct Tx(TxSlot);
+
+impl Tx {
+    /// Lock the transaction for use as an executor (`&mut *guard`)
+    pub async fn lock(&self) -> AppResult<TxGuard<'_>> {
+        let guard = self.0.transaction.lock().await;
+
+        if guard.is_none() {
+            return Err(AppError::Internal(
+                "Request transaction has already finished".to_string(),
+            ));
+        }
+
+        Ok(TxGuard(guard))
+    }
+
+    /// Run `hook` once the transaction has committed, e.g. to invalidate
+    /// caches or notify subscribers. It is dropped without running on rollback.
+    pub fn after_commit(&self, hook: impl FnOnce() + 'static) {
+        self.0.after_commit.borrow_mut().push(Box::new(hook));
+    }
+}
+
+/// Exclusive access to the request transaction's connection
+pub struct TxGuard<'a>(MutexGuard<'a, Option<Transaction<'static, Postgres>>>);
+
+impl Deref for TxGuard<'_> {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref().expect("checked in Tx::lock")
+    }
+}
+
+impl DerefMut for TxGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0.as_mut().expect("checked in Tx::lock")
+    }
+}
+
+impl FromRequest for Tx {
+    type Error = AppError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut actix_web::dev::Payload) -> Self::Future {
+        if let Some(slot) = req.extensions().get::<TxSlot>().cloned() {
+            return Box::pin(async move { Ok(Tx(slot)) });
+        }
+
+        let pool = req.app_data::<web::Data<PgPool>>().cloned();
+        let req = req.clone();
+
+        Box::pin(async move {
+            let pool =
+                pool.ok_or_else(|| AppError::Internal("Database pool not found".to_string()))?;
+            let transaction = pool.begin().await?;
+
+            let slot: TxSlot = Rc::new(TxState {
+                transaction: Mutex::new(Some(transaction)),
+                after_commit: RefCell::new(Vec::new()),
+            });
+            req.extensions_mut().insert(slot.clone());
+
+            Ok(Tx(slot))
+        })
+    }
+}
+
+/// Finishes the transaction opened by a [`Tx`] extractor once the handler
+/// responds, running its [`Tx::after_commit`] hooks after a successful commit
+#[derive(Debug, Clone)]
+pub struct TransactionMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for TransactionMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = TransactionMiddlewareService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(TransactionMiddlewareService {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct TransactionMiddlewareService<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for TransactionMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+
+        Box::pin(async move {
+            // On Err the request (and its slot) is dropped, which rolls back
+            let res = service.call(req).await?;
+
+            let slot = res.request().extensions_mut().remove::<TxSlot>();
+            let Some(slot) = slot else {
+                return Ok(res);
+            };
+
+            let transaction = slot.transaction.lock().await.take();
+            if let Some(transaction) = transaction {
+                if res.status().is_success() {
+                    transaction.commit().await.map_err(AppError::Database)?;
+
+                    for hook in slot.after_commit.take() {
+                        hook();
+                    }
+                } else if let Err(e) = transaction.rollback().await {
+                    warn!("Failed to roll back request transaction: {}", e);
+                }
+            }
+
+            Ok(res)
+        })
+    }
+}
@@ -3,23 +3,36 @@ use utoipa::OpenApi;
 use utoipa::{openapi, Modify};
 
 use crate::dto::{
+    admin::MaintenanceModeRequest,
     analytics::{CreateAnalyticsRequest, UpdateAnalyticsRequest},
     auth::{LoginRequest, RefreshTokenRequest, RegisterRequest},
     contribution::{CreateContributionRequest, UpdateContributionRequest},
     dictionary::{
-        CreateDictionaryEntryRequest, SearchDictionaryRequest, SearchType,
-        UpdateDictionaryEntryRequest,
+        BatchGetDictionaryEntriesRequest, CreateDictionaryEntryRequest,
+        MergeDictionaryEntriesRequest, ReassignDictionaryEntryRequest, SearchDictionaryRequest,
+        SearchType, SuggestDictionaryEntryRequest, UpdateDictionaryEntryRequest,
     },
     responses::{
-        AnalyticsResponse,     AnalyticsPaginatedResponse, AuthApiResponse, AuthResponse, 
-        ContributionResponse, ContributionPaginatedResponse, DictionaryEntryResponse, 
-        DictionaryPaginatedResponse, HealthResponse, PaginationInfo, SuccessResponse,
-        TranslationResponse, TranslationPaginatedResponse, UserApiResponse, UserPaginatedResponse, 
-        UserResponse,
+        ActivityItem, AdminDashboardResponse, AnalyticsPaginatedResponse, AnalyticsResponse,
+        AuthApiResponse, AuthResponse, BackupImportResponse, BatchError, CancelQueryResponse,
+        ContentCounts, ContributionPaginatedResponse, ContributionResponse, DbActivity,
+        DbActivityResponse, DictionaryBatchResult, DictionaryEntryResponse,
+        DictionaryEntryWithRelatedResponse, DictionaryFreshnessResponse,
+        DictionaryPaginatedResponse, DictionarySearchResponse, DictionaryStatsResponse,
+        DictionarySuggestionResponse, DictionaryValidationResponse, EntryConflict, FreshnessBucket,
+        HealthResponse, MaintenanceModeResponse, NotificationDigestResponse, NotificationResponse,
+        PaginationInfo, PointsLedgerEntry, PointsLedgerPaginatedResponse, PoolStats,
+        ReadinessResponse, ReindexResponse, ReviewQueueCounts, SearchCountResponse,
+        SuccessResponse, TableImportCounts, TranslationPaginatedResponse, TranslationResponse,
+        UserApiResponse, UserBatchResult, UserDataExport, UserPaginatedResponse, UserResponse,
+        UserSubmission, UserSubmissionPaginatedResponse,
+    },
+    translation::{
+        CreateTranslationRequest, SaveTranslationDraftRequest, UpdateTranslationRequest,
     },
-    translation::{CreateTranslationRequest, UpdateTranslationRequest},
     user::{
-        AwardPointsRequest, CreateUserRequest, UpdatePasswordRequest, UpdateUserRequest,
+        ActivityQueryParams, AwardPointsRequest, BulkUpdateRolesRequest, CreateUserRequest,
+        CreateUsersBatchRequest, RoleAssignment, UpdatePasswordRequest, UpdateUserRequest,
         UserQueryParams,
     },
 };
@@ -28,30 +41,60 @@ use crate::dto::{
 #[openapi(
     paths(
         crate::handlers::health::health_check,
+        crate::handlers::health::ping,
+        crate::handlers::health::ready,
+        crate::handlers::metrics::get_metrics,
         crate::handlers::auth::register,
         crate::handlers::auth::login,
         crate::handlers::auth::profile,
         crate::handlers::user::create_user,
+        crate::handlers::user::create_users_batch,
+        crate::handlers::user::bulk_update_roles,
         crate::handlers::user::get_user,
         crate::handlers::user::get_current_user,
+        crate::handlers::user::get_current_user_activity,
+        crate::handlers::user::export_current_user_data,
+        crate::handlers::user::export_user_data,
+        crate::handlers::user::list_user_submissions,
+        crate::handlers::user::get_current_user_points,
         crate::handlers::user::list_users,
         crate::handlers::user::update_user,
         crate::handlers::user::delete_user,
+        crate::handlers::user::purge_user,
         crate::handlers::dictionary::create_entry,
+        crate::handlers::dictionary::validate_entry,
+        crate::handlers::dictionary::import_csv,
+        crate::handlers::dictionary::get_stats,
+        crate::handlers::dictionary::get_freshness,
+        crate::handlers::dictionary::list_missing_field,
+        crate::handlers::dictionary::stream_verified_entries,
+        crate::handlers::dictionary::get_entry_by_word,
         crate::handlers::dictionary::get_entry,
         crate::handlers::dictionary::list_entries,
         crate::handlers::dictionary::search_entries,
+        crate::handlers::dictionary::export_entries,
+        crate::handlers::dictionary::batch_get_entries,
+        crate::handlers::dictionary::search_count,
+        crate::handlers::dictionary::recently_verified,
         crate::handlers::dictionary::update_entry,
+        crate::handlers::dictionary::patch_entry,
         crate::handlers::dictionary::delete_entry,
         crate::handlers::dictionary::verify_entry,
+        crate::handlers::dictionary::reassign_entry,
+        crate::handlers::dictionary::merge_entries,
+        crate::handlers::dictionary::suggest_entry,
+        crate::handlers::dictionary::list_suggestions,
+        crate::handlers::dictionary::approve_suggestion,
         crate::handlers::translation::create_translation,
         crate::handlers::translation::get_translation,
         crate::handlers::translation::list_translations,
         crate::handlers::translation::update_translation,
+        crate::handlers::translation::save_translation_draft,
         crate::handlers::translation::delete_translation,
         crate::handlers::contribution::create_contribution,
         crate::handlers::contribution::get_contribution,
         crate::handlers::contribution::list_contributions,
+        crate::handlers::contribution::list_contributions_for_review,
         crate::handlers::contribution::update_contribution,
         crate::handlers::contribution::delete_contribution,
         crate::handlers::analytics::create_analytics,
@@ -61,6 +104,15 @@ use crate::dto::{
         crate::handlers::analytics::update_analytics,
         crate::handlers::analytics::delete_analytics,
         crate::handlers::analytics::get_word_stats,
+        crate::handlers::notification::send_digest,
+        crate::handlers::admin::dashboard,
+        crate::handlers::admin::db_activity,
+        crate::handlers::admin::cancel_db_query,
+        crate::handlers::admin::get_maintenance_mode,
+        crate::handlers::admin::set_maintenance_mode,
+        crate::handlers::admin::reindex,
+        crate::handlers::admin::export,
+        crate::handlers::admin::import,
     ),
     components(
         schemas(
@@ -71,20 +123,29 @@ use crate::dto::{
 
             // User DTOs
             CreateUserRequest,
+            CreateUsersBatchRequest,
+            BulkUpdateRolesRequest,
+            RoleAssignment,
             UpdateUserRequest,
             UpdatePasswordRequest,
             UserQueryParams,
             AwardPointsRequest,
+            ActivityQueryParams,
 
             // Dictionary DTOs
             CreateDictionaryEntryRequest,
             UpdateDictionaryEntryRequest,
             SearchDictionaryRequest,
             SearchType,
+            ReassignDictionaryEntryRequest,
+            MergeDictionaryEntriesRequest,
+            SuggestDictionaryEntryRequest,
+            BatchGetDictionaryEntriesRequest,
 
             // Translation DTOs
             CreateTranslationRequest,
             UpdateTranslationRequest,
+            SaveTranslationDraftRequest,
 
             // Contribution DTOs
             CreateContributionRequest,
@@ -94,6 +155,9 @@ use crate::dto::{
             CreateAnalyticsRequest,
             UpdateAnalyticsRequest,
 
+            // Admin DTOs
+            MaintenanceModeRequest,
+
             // Response DTOs
             SuccessResponse,
             AuthResponse,
@@ -101,7 +165,16 @@ use crate::dto::{
             UserResponse,
             UserApiResponse,
             DictionaryEntryResponse,
+            DictionaryEntryWithRelatedResponse,
             DictionaryPaginatedResponse,
+            DictionarySearchResponse,
+            DictionaryStatsResponse,
+            DictionaryFreshnessResponse,
+            FreshnessBucket,
+            SearchCountResponse,
+            DictionaryValidationResponse,
+            EntryConflict,
+            DictionarySuggestionResponse,
             UserPaginatedResponse,
             TranslationResponse,
             TranslationPaginatedResponse,
@@ -111,6 +184,29 @@ use crate::dto::{
             AnalyticsPaginatedResponse,
             HealthResponse,
             PaginationInfo,
+            ActivityItem,
+            BatchError,
+            UserBatchResult,
+            DictionaryBatchResult,
+            MaintenanceModeResponse,
+            ReindexResponse,
+            ReadinessResponse,
+            NotificationDigestResponse,
+            UserSubmission,
+            UserSubmissionPaginatedResponse,
+            PointsLedgerEntry,
+            PointsLedgerPaginatedResponse,
+            AdminDashboardResponse,
+            ContentCounts,
+            PoolStats,
+            ReviewQueueCounts,
+            DbActivity,
+            DbActivityResponse,
+            CancelQueryResponse,
+            NotificationResponse,
+            BackupImportResponse,
+            TableImportCounts,
+            UserDataExport,
         )
     ),
     tags(
@@ -120,7 +216,9 @@ use crate::dto::{
         (name = "dictionary", description = "Dictionary management endpoints"),
         (name = "translations", description = "Translation request endpoints"),
         (name = "contributions", description = "User contribution endpoints"),
-        (name = "analytics", description = "Word usage analytics endpoints")
+        (name = "analytics", description = "Word usage analytics endpoints"),
+        (name = "notifications", description = "User notification endpoints"),
+        (name = "admin", description = "Operator endpoints")
     ),
     info(
         title = "Pnar World Dictionary API",
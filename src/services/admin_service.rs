@@ -0,0 +1,121 @@
+use crate::{
+    dto::responses::{ContentCounts, DbActivity, ReviewQueueCounts},
+    error::AppError,
+};
+use sqlx::{PgPool, Row};
+
+/// Row counts for the main content tables
+pub async fn content_counts(pool: &PgPool) -> Result<ContentCounts, AppError> {
+    let record = sqlx::query(
+        r#"
+        SELECT
+            (SELECT COUNT(*) FROM pnar_dictionary) AS dictionary_entries,
+            (SELECT COUNT(*) FROM pnar_dictionary WHERE verified = true) AS verified_entries,
+            (SELECT COUNT(*) FROM users) AS users,
+            (SELECT COUNT(*) FROM translation_requests) AS translations
+        "#,
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(ContentCounts {
+        dictionary_entries: record.get("dictionary_entries"),
+        verified_entries: record.get("verified_entries"),
+        users: record.get("users"),
+        translations: record.get("translations"),
+    })
+}
+
+/// Sizes of the queues waiting on moderator or admin review
+pub async fn review_queue_counts(pool: &PgPool) -> Result<ReviewQueueCounts, AppError> {
+    let record = sqlx::query(
+        r#"
+        SELECT
+            (SELECT COUNT(*) FROM dictionary_suggestions WHERE status = 'pending') AS pending_suggestions,
+            (SELECT COUNT(*) FROM pnar_dictionary WHERE verified = false) AS unverified_entries,
+            (SELECT COUNT(*) FROM translation_requests WHERE status = 'pending') AS pending_translations,
+            (SELECT COUNT(*) FROM user_contributions WHERE status = 'pending') AS pending_contributions
+        "#,
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(ReviewQueueCounts {
+        pending_suggestions: record.get("pending_suggestions"),
+        unverified_entries: record.get("unverified_entries"),
+        pending_translations: record.get("pending_translations"),
+        pending_contributions: record.get("pending_contributions"),
+    })
+}
+
+/// Non-idle backends on this database whose current query started at least
+/// `min_seconds` ago, longest first. The caller's own backend is excluded.
+pub async fn long_running_queries(
+    pool: &PgPool,
+    min_seconds: u64,
+    redact_query_text: bool,
+) -> Result<Vec<DbActivity>, AppError> {
+    let rows = sqlx::query(
+        r#"
+        SELECT pid, usename, application_name, client_addr::text AS client_addr,
+               state, wait_event_type, wait_event, query_start,
+               EXTRACT(EPOCH FROM (clock_timestamp() - query_start))::float8 AS duration_seconds,
+               query
+        FROM pg_stat_activity
+        WHERE datname = current_database()
+          AND pid <> pg_backend_pid()
+          AND backend_type = 'client backend'
+          AND state <> 'idle'
+          AND query_start <= clock_timestamp() - make_interval(secs => $1)
+        ORDER BY query_start
+        "#,
+    )
+    .bind(min_seconds as f64)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| DbActivity {
+            pid: row.get("pid"),
+            username: row.get("usename"),
+            application_name: row.get("application_name"),
+            client_addr: row.get("client_addr"),
+            state: row.get("state"),
+            wait_event_type: row.get("wait_event_type"),
+            wait_event: row.get("wait_event"),
+            query_start: row.get("query_start"),
+            duration_seconds: row.get("duration_seconds"),
+            query: if redact_query_text {
+                None
+            } else {
+                row.get("query")
+            },
+        })
+        .collect())
+}
+
+/// Ask Postgres to cancel the current query of `pid`. Only client backends
+/// connected to this database can be targeted, and never the caller's own.
+pub async fn cancel_query(pool: &PgPool, pid: i32) -> Result<bool, AppError> {
+    let cancelled: Option<bool> = sqlx::query_scalar(
+        r#"
+        SELECT pg_cancel_backend(pid)
+        FROM pg_stat_activity
+        WHERE pid = $1
+          AND datname = current_database()
+          AND pid <> pg_backend_pid()
+          AND backend_type = 'client backend'
+        "#,
+    )
+    .bind(pid)
+    .fetch_optional(pool)
+    .await?;
+
+    cancelled.ok_or_else(|| {
+        AppError::NotFound(format!(
+            "No client backend with pid {} on this database",
+            pid
+        ))
+    })
+}
@@ -1,10 +1,39 @@
 use crate::{
+    config::ImpactPointsSettings,
     dto::{responses::AnalyticsResponse, CreateAnalyticsRequest, UpdateAnalyticsRequest},
     error::AppError,
+    utils::database::with_transaction,
 };
+use chrono::{DateTime, Duration, NaiveTime, Utc};
 use sqlx::{PgPool, Row};
 use uuid::Uuid;
 
+/// Analytics event type counted as a lookup of an entry
+pub const LOOKUP_EVENT_TYPE: &str = "lookup";
+
+/// Reject event metadata whose serialized JSON is larger than `max_bytes`
+pub fn check_metadata_size(
+    metadata: Option<&serde_json::Value>,
+    max_bytes: usize,
+) -> Result<(), AppError> {
+    let Some(metadata) = metadata else {
+        return Ok(());
+    };
+
+    let size = serde_json::to_vec(metadata)
+        .map_err(|e| AppError::Internal(format!("Failed to serialize metadata: {}", e)))?
+        .len();
+
+    if size > max_bytes {
+        return Err(AppError::Validation(format!(
+            "Metadata is {} bytes; the limit is {} bytes",
+            size, max_bytes
+        )));
+    }
+
+    Ok(())
+}
+
 pub async fn create_analytics_record(
     pool: &PgPool,
     user_id: Option<Uuid>,
@@ -25,11 +54,11 @@ pub async fn create_analytics_record(
     )
     .bind(analytics_id)
     .bind(user_id)
-    .bind(&request.word_id)
+    .bind(request.word_id)
     .bind(&request.event_type)
-    .bind(&request.timestamp)
+    .bind(request.timestamp)
     .bind(&request.session_id)
-    .bind(&request.metadata.unwrap_or_else(|| serde_json::json!({})))
+    .bind(request.metadata.unwrap_or_else(|| serde_json::json!({})))
     .fetch_one(pool)
     .await?;
 
@@ -78,158 +107,49 @@ pub async fn get_analytics_record(
     })
 }
 
+/// Optional filters for [`list_analytics_records`]
+#[derive(Debug, Default)]
+pub struct AnalyticsFilter {
+    pub user_id: Option<Uuid>,
+    pub word_id: Option<Uuid>,
+    pub event_type: Option<String>,
+    /// Start of the time range (inclusive)
+    pub from: Option<DateTime<Utc>>,
+    /// End of the time range (exclusive)
+    pub to: Option<DateTime<Utc>>,
+}
+
 pub async fn list_analytics_records(
     pool: &PgPool,
-    user_id: Option<Uuid>,
-    word_id: Option<Uuid>,
-    event_type: Option<String>,
+    filter: AnalyticsFilter,
     page: i64,
     per_page: i64,
 ) -> Result<Vec<AnalyticsResponse>, AppError> {
     let offset = (page - 1) * per_page;
 
-    // Use separate queries based on parameters to avoid complex dynamic binding
-    let records = match (user_id, word_id, event_type) {
-        (Some(uid), Some(wid), Some(et)) => {
-            sqlx::query(
-                r#"
-                SELECT id, user_id, word_id, event_type, timestamp, session_id,
-                       metadata, created_at, updated_at
-                FROM word_usage_analytics 
-                WHERE user_id = $1 AND word_id = $2 AND event_type = $3
-                ORDER BY timestamp DESC
-                LIMIT $4 OFFSET $5
-                "#,
-            )
-            .bind(uid)
-            .bind(wid)
-            .bind(et)
-            .bind(per_page)
-            .bind(offset)
-            .fetch_all(pool)
-            .await?
-        }
-        (Some(uid), Some(wid), None) => {
-            sqlx::query(
-                r#"
-                SELECT id, user_id, word_id, event_type, timestamp, session_id,
-                       metadata, created_at, updated_at
-                FROM word_usage_analytics 
-                WHERE user_id = $1 AND word_id = $2
-                ORDER BY timestamp DESC
-                LIMIT $3 OFFSET $4
-                "#,
-            )
-            .bind(uid)
-            .bind(wid)
-            .bind(per_page)
-            .bind(offset)
-            .fetch_all(pool)
-            .await?
-        }
-        (Some(uid), None, Some(et)) => {
-            sqlx::query(
-                r#"
-                SELECT id, user_id, word_id, event_type, timestamp, session_id,
-                       metadata, created_at, updated_at
-                FROM word_usage_analytics 
-                WHERE user_id = $1 AND event_type = $2
-                ORDER BY timestamp DESC
-                LIMIT $3 OFFSET $4
-                "#,
-            )
-            .bind(uid)
-            .bind(et)
-            .bind(per_page)
-            .bind(offset)
-            .fetch_all(pool)
-            .await?
-        }
-        (Some(uid), None, None) => {
-            sqlx::query(
-                r#"
-                SELECT id, user_id, word_id, event_type, timestamp, session_id,
-                       metadata, created_at, updated_at
-                FROM word_usage_analytics 
-                WHERE user_id = $1
-                ORDER BY timestamp DESC
-                LIMIT $2 OFFSET $3
-                "#,
-            )
-            .bind(uid)
-            .bind(per_page)
-            .bind(offset)
-            .fetch_all(pool)
-            .await?
-        }
-        (None, Some(wid), Some(et)) => {
-            sqlx::query(
-                r#"
-                SELECT id, user_id, word_id, event_type, timestamp, session_id,
-                       metadata, created_at, updated_at
-                FROM word_usage_analytics 
-                WHERE word_id = $1 AND event_type = $2
-                ORDER BY timestamp DESC
-                LIMIT $3 OFFSET $4
-                "#,
-            )
-            .bind(wid)
-            .bind(et)
-            .bind(per_page)
-            .bind(offset)
-            .fetch_all(pool)
-            .await?
-        }
-        (None, Some(wid), None) => {
-            sqlx::query(
-                r#"
-                SELECT id, user_id, word_id, event_type, timestamp, session_id,
-                       metadata, created_at, updated_at
-                FROM word_usage_analytics 
-                WHERE word_id = $1
-                ORDER BY timestamp DESC
-                LIMIT $2 OFFSET $3
-                "#,
-            )
-            .bind(wid)
-            .bind(per_page)
-            .bind(offset)
-            .fetch_all(pool)
-            .await?
-        }
-        (None, None, Some(et)) => {
-            sqlx::query(
-                r#"
-                SELECT id, user_id, word_id, event_type, timestamp, session_id,
-                       metadata, created_at, updated_at
-                FROM word_usage_analytics 
-                WHERE event_type = $1
-                ORDER BY timestamp DESC
-                LIMIT $2 OFFSET $3
-                "#,
-            )
-            .bind(et)
-            .bind(per_page)
-            .bind(offset)
-            .fetch_all(pool)
-            .await?
-        }
-        (None, None, None) => {
-            sqlx::query(
-                r#"
-                SELECT id, user_id, word_id, event_type, timestamp, session_id,
-                       metadata, created_at, updated_at
-                FROM word_usage_analytics 
-                ORDER BY timestamp DESC
-                LIMIT $1 OFFSET $2
-                "#,
-            )
-            .bind(per_page)
-            .bind(offset)
-            .fetch_all(pool)
-            .await?
-        }
-    };
+    let records = sqlx::query(
+        r#"
+        SELECT id, user_id, word_id, event_type, timestamp, session_id,
+               metadata, created_at, updated_at
+        FROM word_usage_analytics
+        WHERE ($1::uuid IS NULL OR user_id = $1)
+          AND ($2::uuid IS NULL OR word_id = $2)
+          AND ($3::text IS NULL OR event_type = $3)
+          AND ($4::timestamptz IS NULL OR timestamp >= $4)
+          AND ($5::timestamptz IS NULL OR timestamp < $5)
+        ORDER BY timestamp DESC
+        LIMIT $6 OFFSET $7
+        "#,
+    )
+    .bind(filter.user_id)
+    .bind(filter.word_id)
+    .bind(filter.event_type)
+    .bind(filter.from)
+    .bind(filter.to)
+    .bind(per_page)
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
 
     Ok(records
         .into_iter()
@@ -297,42 +217,63 @@ pub async fn delete_analytics_record(pool: &PgPool, analytics_id: Uuid) -> Resul
     Ok(())
 }
 
+/// Per-day, per-event-type counts for a word, newest day first. `limit`
+/// counts days, so a returned day always carries every event type seen on it.
 pub async fn get_word_usage_stats(
     pool: &PgPool,
     word_id: Uuid,
     user_id: Option<Uuid>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    limit: i64,
 ) -> Result<serde_json::Value, AppError> {
     let records = if let Some(uid) = user_id {
         sqlx::query(
             r#"
-            SELECT 
-                event_type,
-                COUNT(*) as count,
-                DATE_TRUNC('day', timestamp) as date
-            FROM word_usage_analytics 
-            WHERE word_id = $1 AND user_id = $2
-            GROUP BY event_type, DATE_TRUNC('day', timestamp) 
-            ORDER BY date DESC
+            SELECT event_type, count, date
+            FROM (
+                SELECT
+                    event_type,
+                    COUNT(*) as count,
+                    DATE_TRUNC('day', timestamp) as date,
+                    DENSE_RANK() OVER (ORDER BY DATE_TRUNC('day', timestamp) DESC) as day_rank
+                FROM word_usage_analytics
+                WHERE word_id = $1 AND user_id = $2 AND timestamp >= $3 AND timestamp < $4
+                GROUP BY event_type, DATE_TRUNC('day', timestamp)
+            ) daily
+            WHERE day_rank <= $5
+            ORDER BY date DESC, event_type
             "#,
         )
         .bind(word_id)
         .bind(uid)
+        .bind(from)
+        .bind(to)
+        .bind(limit)
         .fetch_all(pool)
         .await?
     } else {
         sqlx::query(
             r#"
-            SELECT 
-                event_type,
-                COUNT(*) as count,
-                DATE_TRUNC('day', timestamp) as date
-            FROM word_usage_analytics 
-            WHERE word_id = $1
-            GROUP BY event_type, DATE_TRUNC('day', timestamp) 
-            ORDER BY date DESC
+            SELECT event_type, count, date
+            FROM (
+                SELECT
+                    event_type,
+                    COUNT(*) as count,
+                    DATE_TRUNC('day', timestamp) as date,
+                    DENSE_RANK() OVER (ORDER BY DATE_TRUNC('day', timestamp) DESC) as day_rank
+                FROM word_usage_analytics
+                WHERE word_id = $1 AND timestamp >= $2 AND timestamp < $3
+                GROUP BY event_type, DATE_TRUNC('day', timestamp)
+            ) daily
+            WHERE day_rank <= $4
+            ORDER BY date DESC, event_type
             "#,
         )
         .bind(word_id)
+        .bind(from)
+        .bind(to)
+        .bind(limit)
         .fetch_all(pool)
         .await?
     };
@@ -343,13 +284,151 @@ pub async fn get_word_usage_stats(
             serde_json::json!({
                 "event_type": record.get::<String, _>("event_type"),
                 "count": record.get::<i64, _>("count"),
-                "date": record.get::<chrono::DateTime<chrono::Utc>, _>("date")
+                "date": record.get::<DateTime<Utc>, _>("date")
             })
         })
         .collect();
 
     Ok(serde_json::json!({
         "word_id": word_id,
+        "from": from,
+        "to": to,
         "statistics": stats
     }))
 }
+
+/// Award impact points for lookups during the last completed UTC day.
+///
+/// Returns the number of creators awarded. See [`award_impact_points_for_window`].
+pub async fn award_impact_points(
+    pool: &PgPool,
+    settings: &ImpactPointsSettings,
+) -> Result<u64, AppError> {
+    let window_end = Utc::now().date_naive().and_time(NaiveTime::MIN).and_utc();
+    let window_start = window_end - Duration::days(1);
+
+    award_impact_points_for_window(pool, settings, window_start, window_end).await
+}
+
+/// Give entry creators points for lookups of their words in `[window_start, window_end)`.
+///
+/// Each creator gets at most one "impact" contribution per window, keyed by an
+/// idempotency key derived from the window start, so re-running a window is a
+/// no-op. Creators looking up their own words do not count.
+pub async fn award_impact_points_for_window(
+    pool: &PgPool,
+    settings: &ImpactPointsSettings,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Result<u64, AppError> {
+    let lookups_per_point = settings.lookups_per_point.max(1);
+
+    let creators = sqlx::query(
+        r#"
+        SELECT d.created_by AS user_id, COUNT(*) AS lookups
+        FROM word_usage_analytics a
+        JOIN pnar_dictionary d ON d.id = a.word_id
+        WHERE a.event_type = $1 AND a.timestamp >= $2 AND a.timestamp < $3
+          AND d.created_by IS NOT NULL
+          AND (a.user_id IS NULL OR a.user_id <> d.created_by)
+        GROUP BY d.created_by
+        HAVING COUNT(*) >= $4
+        "#,
+    )
+    .bind(LOOKUP_EVENT_TYPE)
+    .bind(window_start)
+    .bind(window_end)
+    .bind(lookups_per_point)
+    .fetch_all(pool)
+    .await?;
+
+    let idempotency_key = format!("impact:{}", window_start.to_rfc3339());
+    let mut awarded = 0;
+
+    for creator in creators {
+        let user_id: Uuid = creator.get("user_id");
+        let lookups: i64 = creator.get("lookups");
+        let points = (lookups / lookups_per_point).min(settings.max_points_per_window) as i32;
+        let details = serde_json::json!({
+            "window_start": window_start,
+            "window_end": window_end,
+            "lookups": lookups,
+        });
+        let idempotency_key = idempotency_key.clone();
+
+        let inserted = with_transaction(pool, |tx| {
+            Box::pin(async move {
+                let inserted = sqlx::query(
+                    r#"
+                    INSERT INTO user_contributions (
+                        user_id, contribution_type, entity_type, entity_id, action,
+                        new_value, points_awarded, status, idempotency_key, reviewed_at
+                    )
+                    VALUES ($1, 'impact', 'user', $1, 'award', $2, $3, 'approved', $4, NOW())
+                    ON CONFLICT (user_id, idempotency_key) WHERE idempotency_key IS NOT NULL
+                    DO NOTHING
+                    RETURNING id
+                    "#,
+                )
+                .bind(user_id)
+                .bind(&details)
+                .bind(points)
+                .bind(&idempotency_key)
+                .fetch_optional(&mut **tx)
+                .await?;
+
+                if inserted.is_none() {
+                    return Ok(false);
+                }
+
+                sqlx::query(
+                    r#"
+                    UPDATE users
+                    SET translation_points = translation_points + $2, updated_at = NOW()
+                    WHERE id = $1
+                    "#,
+                )
+                .bind(user_id)
+                .bind(points)
+                .execute(&mut **tx)
+                .await?;
+
+                Ok(true)
+            })
+        })
+        .await?;
+
+        if inserted {
+            awarded += 1;
+        }
+    }
+
+    Ok(awarded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn small_metadata_is_accepted() {
+        let metadata = json!({"source": "search", "position": 3});
+
+        assert!(check_metadata_size(Some(&metadata), 8192).is_ok());
+        assert!(check_metadata_size(None, 8192).is_ok());
+    }
+
+    #[test]
+    fn oversized_metadata_is_rejected() {
+        let metadata = json!({"blob": "x".repeat(9000)});
+
+        let err = check_metadata_size(Some(&metadata), 8192).unwrap_err();
+
+        assert!(
+            matches!(&err, AppError::Validation(message) if message.contains("the limit is 8192 bytes")),
+            "unexpected error: {:?}",
+            err
+        );
+    }
+}
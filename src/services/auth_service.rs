@@ -1,68 +1,74 @@
 use crate::{
+    config::{JwtSettings, SecuritySettings},
     dto::{
         responses::{AuthResponse, UserResponse},
         LoginRequest, RegisterRequest,
     },
     error::AppError,
-    utils::jwt,
+    utils::{database::with_transaction, email::normalize_email, jwt, password},
 };
-use argon2::password_hash::{rand_core::OsRng, SaltString};
-use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
 use sqlx::{PgPool, Row};
 use uuid::Uuid;
 
 pub async fn register_user(
     pool: &PgPool,
-    request: RegisterRequest,
+    jwt_settings: &JwtSettings,
+    security: &SecuritySettings,
+    mut request: RegisterRequest,
 ) -> Result<AuthResponse, AppError> {
-    // Check if user already exists
-    let existing_user = sqlx::query("SELECT id FROM users WHERE email = $1")
-        .bind(&request.email)
-        .fetch_optional(pool)
-        .await?;
-
-    if existing_user.is_some() {
-        return Err(AppError::Conflict("User already exists".to_string()));
-    }
+    request.email = normalize_email(&request.email);
 
     // Hash password
-    let salt = SaltString::generate(&mut OsRng);
-    let argon2 = Argon2::default();
-    let password_hash = argon2
-        .hash_password(request.password.as_bytes(), &salt)
-        .map_err(|e| AppError::Internal(format!("Failed to hash password: {}", e)))?
-        .to_string();
-
-    // Create user
+    let password_hash = password::hash_password(security, &request.password)?;
+
     let user_id = Uuid::new_v4();
-    sqlx::query(
-        r#"
-        INSERT INTO users (id, email, password, full_name, created_at, updated_at)
-        VALUES ($1, $2, $3, $4, NOW(), NOW())
-        "#,
-    )
-    .bind(user_id)
-    .bind(&request.email)
-    .bind(&password_hash)
-    .bind(&request.full_name)
-    .execute(pool)
+
+    let user_record = with_transaction(pool, |tx| {
+        Box::pin(async move {
+            // Check if user already exists
+            let existing_user = sqlx::query("SELECT id FROM users WHERE email = $1")
+                .bind(&request.email)
+                .fetch_optional(&mut **tx)
+                .await?;
+
+            if existing_user.is_some() {
+                return Err(AppError::Conflict("User already exists".to_string()));
+            }
+
+            // Create user
+            sqlx::query(
+                r#"
+                INSERT INTO users (id, email, password, full_name, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, NOW(), NOW())
+                "#,
+            )
+            .bind(user_id)
+            .bind(&request.email)
+            .bind(&password_hash)
+            .bind(&request.full_name)
+            .execute(&mut **tx)
+            .await?;
+
+            // Get the created user for response
+            let user_record = sqlx::query(
+                r#"SELECT 
+                    id, email, full_name, avatar_url, role, translation_points, 
+                    bio, preferred_language, settings, is_active, is_email_verified, 
+                    created_at, updated_at 
+                FROM users WHERE id = $1"#,
+            )
+            .bind(user_id)
+            .fetch_one(&mut **tx)
+            .await?;
+
+            Ok(user_record)
+        })
+    })
     .await?;
 
     // Generate JWT token
-    let token = jwt::generate_token(user_id)?;
-    let refresh_token = jwt::generate_refresh_token(user_id)?;
-
-    // Get the created user for response
-    let user_record = sqlx::query(
-        r#"SELECT 
-            id, email, full_name, avatar_url, role, translation_points, 
-            bio, preferred_language, settings, is_active, is_email_verified, 
-            created_at, updated_at 
-        FROM users WHERE id = $1"#,
-    )
-    .bind(user_id)
-    .fetch_one(pool)
-    .await?;
+    let token = jwt::generate_token(user_id, jwt_settings)?;
+    let refresh_token = jwt::generate_refresh_token(user_id, jwt_settings)?;
 
     let user_response = UserResponse {
         id: user_record.get("id"),
@@ -88,7 +94,14 @@ pub async fn register_user(
     })
 }
 
-pub async fn login_user(pool: &PgPool, request: LoginRequest) -> Result<AuthResponse, AppError> {
+pub async fn login_user(
+    pool: &PgPool,
+    jwt_settings: &JwtSettings,
+    security: &SecuritySettings,
+    mut request: LoginRequest,
+) -> Result<AuthResponse, AppError> {
+    request.email = normalize_email(&request.email);
+
     // Get user from database
     let user_record = sqlx::query("SELECT id, password FROM users WHERE email = $1")
         .bind(&request.email)
@@ -100,19 +113,15 @@ pub async fn login_user(pool: &PgPool, request: LoginRequest) -> Result<AuthResp
 
     // Verify password
     let password: String = user_record.get("password");
-    let parsed_hash = PasswordHash::new(&password)
-        .map_err(|e| AppError::Internal(format!("Failed to parse password hash: {}", e)))?;
-
-    let argon2 = Argon2::default();
-    argon2
-        .verify_password(request.password.as_bytes(), &parsed_hash)
-        .map_err(|_| AppError::Unauthorized("Invalid credentials".to_string()))?;
+    if !password::verify_password(security, &request.password, &password)? {
+        return Err(AppError::Unauthorized("Invalid credentials".to_string()));
+    }
 
     let user_id: Uuid = user_record.get("id");
 
     // Generate JWT token
-    let token = jwt::generate_token(user_id)?;
-    let refresh_token = jwt::generate_refresh_token(user_id)?;
+    let token = jwt::generate_token(user_id, jwt_settings)?;
+    let refresh_token = jwt::generate_refresh_token(user_id, jwt_settings)?;
 
     // Get user details for response
     let user_details = sqlx::query(
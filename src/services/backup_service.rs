@@ -0,0 +1,351 @@
+use crate::{
+    database,
+    dto::responses::{BackupImportResponse, TableImportCounts},
+    error::AppError,
+};
+use chrono::Utc;
+use futures_util::stream::{self, Stream, StreamExt};
+use serde_json::json;
+use sqlx::{PgPool, Postgres, Row, Transaction};
+use std::future::ready;
+
+/// A table included in backup archives
+pub struct BackupTable {
+    pub name: &'static str,
+    /// Column used to page through the table in a stable order
+    pub key: &'static str,
+    /// Columns left out of the archive
+    pub omit: &'static [&'static str],
+}
+
+/// Tables in a backup archive, in dependency order
+pub const BACKUP_TABLES: &[BackupTable] = &[
+    BackupTable {
+        name: "user_role",
+        key: "role_id",
+        omit: &[],
+    },
+    BackupTable {
+        name: "users",
+        key: "id",
+        omit: &["password"],
+    },
+    BackupTable {
+        name: "pnar_dictionary",
+        key: "id",
+        omit: &[],
+    },
+];
+
+/// Rows fetched per query while streaming an export
+const EXPORT_BATCH_SIZE: i64 = 500;
+
+/// Where an in-progress export has got to
+struct ExportCursor {
+    tx: Transaction<'static, Postgres>,
+    table: usize,
+    after: String,
+}
+
+/// Stream a point-in-time NDJSON backup archive.
+///
+/// The first line is a manifest with the schema version, export time and row
+/// count per table; every following line is one row as
+/// `{"type":"row","table":...,"data":{...}}`. Everything is read in a single
+/// repeatable-read transaction, a batch at a time, so the archive is
+/// consistent and memory use stays bounded.
+pub async fn export_archive(
+    pool: &PgPool,
+) -> Result<impl Stream<Item = Result<String, AppError>> + 'static, AppError> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+        .execute(&mut *tx)
+        .await?;
+
+    let mut counts = serde_json::Map::new();
+    for table in BACKUP_TABLES {
+        let count: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {}", table.name))
+            .fetch_one(&mut *tx)
+            .await?;
+        counts.insert(table.name.to_string(), count.into());
+    }
+
+    let manifest = json!({
+        "type": "manifest",
+        "schema_version": database::schema_version(),
+        "exported_at": Utc::now(),
+        "counts": counts,
+    });
+
+    let cursor = ExportCursor {
+        tx,
+        table: 0,
+        after: String::new(),
+    };
+
+    let rows = stream::try_unfold(cursor, |mut cursor| async move {
+        loop {
+            let Some(table) = BACKUP_TABLES.get(cursor.table) else {
+                cursor.tx.rollback().await?;
+                return Ok(None);
+            };
+
+            let batch: Vec<(String, serde_json::Value)> = sqlx::query_as(&format!(
+                "SELECT {key}::text, to_jsonb(t) - $3::text[] FROM {table} t \
+                 WHERE {key}::text > $1 ORDER BY {key}::text LIMIT $2",
+                key = table.key,
+                table = table.name,
+            ))
+            .bind(&cursor.after)
+            .bind(EXPORT_BATCH_SIZE)
+            .bind(table.omit)
+            .fetch_all(&mut *cursor.tx)
+            .await?;
+
+            let Some((last_key, _)) = batch.last() else {
+                cursor.table += 1;
+                cursor.after.clear();
+                continue;
+            };
+            cursor.after = last_key.clone();
+
+            let lines = batch
+                .into_iter()
+                .map(|(_, data)| {
+                    format!(
+                        "{}\n",
+                        json!({ "type": "row", "table": table.name, "data": data })
+                    )
+                })
+                .collect::<String>();
+
+            return Ok(Some((lines, cursor)));
+        }
+    });
+
+    Ok(stream::once(ready(Ok(format!("{}\n", manifest)))).chain(rows))
+}
+
+/// Restores a backup archive produced by [`export_archive`], one line at a
+/// time, inside a single transaction.
+///
+/// Rows are upserted by key in [`BACKUP_TABLES`] order. Omitted columns
+/// (only `users.password`) keep their current value on update; new users get
+/// an unusable password hash and must reset their password.
+pub struct ArchiveImporter {
+    tx: Transaction<'static, Postgres>,
+    placeholder_password_hash: String,
+    manifest_counts: Option<serde_json::Map<String, serde_json::Value>>,
+    table: usize,
+    upsert_sql: Option<String>,
+    counts: Vec<TableImportCounts>,
+}
+
+impl ArchiveImporter {
+    pub async fn begin(pool: &PgPool, placeholder_password_hash: String) -> Result<Self, AppError> {
+        Ok(Self {
+            tx: pool.begin().await?,
+            placeholder_password_hash,
+            manifest_counts: None,
+            table: 0,
+            upsert_sql: None,
+            counts: BACKUP_TABLES
+                .iter()
+                .map(|table| TableImportCounts {
+                    table: table.name.to_string(),
+                    inserted: 0,
+                    updated: 0,
+                    skipped: 0,
+                })
+                .collect(),
+        })
+    }
+
+    /// Apply one NDJSON line of the archive; blank lines are ignored
+    pub async fn import_line(&mut self, line: &[u8]) -> Result<(), AppError> {
+        if line.iter().all(u8::is_ascii_whitespace) {
+            return Ok(());
+        }
+
+        let mut record: serde_json::Value = serde_json::from_slice(line)
+            .map_err(|e| AppError::Validation(format!("Invalid archive line: {}", e)))?;
+
+        let Some(manifest_counts) = &self.manifest_counts else {
+            return self.read_manifest(record);
+        };
+
+        if record["type"] != "row" {
+            return Err(AppError::Validation(
+                "Archive lines after the manifest must be rows".to_string(),
+            ));
+        }
+
+        let table_name = record["table"].as_str().unwrap_or_default().to_string();
+        let index = BACKUP_TABLES
+            .iter()
+            .position(|table| table.name == table_name)
+            .ok_or_else(|| {
+                AppError::Validation(format!("Unknown table '{}' in archive", table_name))
+            })?;
+
+        if !manifest_counts.contains_key(&table_name) {
+            return Err(AppError::Validation(format!(
+                "Table '{}' is missing from the manifest",
+                table_name
+            )));
+        }
+
+        // Rows must arrive in dependency order so references already exist
+        if index < self.table {
+            return Err(AppError::Validation(format!(
+                "Rows for '{}' appear after rows of a dependent table",
+                table_name
+            )));
+        }
+        if index > self.table || self.upsert_sql.is_none() {
+            self.table = index;
+            self.upsert_sql = Some(self.upsert_sql(&BACKUP_TABLES[index]).await?);
+        }
+
+        let table = &BACKUP_TABLES[index];
+        let serde_json::Value::Object(mut data) = record["data"].take() else {
+            return Err(AppError::Validation(format!(
+                "Row for '{}' has no data object",
+                table_name
+            )));
+        };
+        for column in table.omit {
+            data.insert(
+                column.to_string(),
+                self.placeholder_password_hash.clone().into(),
+            );
+        }
+
+        let upsert_sql = self.upsert_sql.as_deref().unwrap_or_default();
+        let inserted: Option<bool> = sqlx::query(upsert_sql)
+            .bind(serde_json::Value::Object(data))
+            .fetch_optional(&mut *self.tx)
+            .await?
+            .map(|row| row.get("inserted"));
+
+        let counts = &mut self.counts[index];
+        match inserted {
+            Some(true) => counts.inserted += 1,
+            Some(false) => counts.updated += 1,
+            None => counts.skipped += 1,
+        }
+
+        Ok(())
+    }
+
+    /// Commit the import once every table's row count matches the manifest
+    pub async fn finish(self) -> Result<BackupImportResponse, AppError> {
+        let Some(manifest_counts) = &self.manifest_counts else {
+            return Err(AppError::Validation("Archive is empty".to_string()));
+        };
+
+        for counts in &self.counts {
+            let expected = manifest_counts
+                .get(&counts.table)
+                .and_then(serde_json::Value::as_i64)
+                .unwrap_or(0);
+            let received = counts.inserted + counts.updated + counts.skipped;
+
+            if received != expected {
+                return Err(AppError::Validation(format!(
+                    "Archive is incomplete: manifest lists {} '{}' rows but {} were found",
+                    expected, counts.table, received
+                )));
+            }
+        }
+
+        self.tx.commit().await?;
+
+        Ok(BackupImportResponse {
+            schema_version: database::schema_version(),
+            tables: self.counts,
+        })
+    }
+
+    fn read_manifest(&mut self, manifest: serde_json::Value) -> Result<(), AppError> {
+        if manifest["type"] != "manifest" {
+            return Err(AppError::Validation(
+                "Archive must start with a manifest line".to_string(),
+            ));
+        }
+
+        let schema_version = database::schema_version();
+        if manifest["schema_version"].as_i64() != Some(schema_version) {
+            return Err(AppError::Conflict(format!(
+                "Archive schema version {} does not match this server's schema version {}",
+                manifest["schema_version"], schema_version
+            )));
+        }
+
+        let counts = manifest["counts"].as_object().cloned().ok_or_else(|| {
+            AppError::Validation("Manifest is missing per-table counts".to_string())
+        })?;
+        self.manifest_counts = Some(counts);
+
+        Ok(())
+    }
+
+    /// Build the upsert for `table`, updating every column except its key.
+    ///
+    /// Omitted columns are only written on insert, and rows that already match
+    /// the archive are left alone so they count as skipped.
+    async fn upsert_sql(&mut self, table: &BackupTable) -> Result<String, AppError> {
+        let columns: Vec<String> = sqlx::query_scalar(
+            r#"
+            SELECT column_name::text
+            FROM information_schema.columns
+            WHERE table_schema = current_schema() AND table_name = $1
+            ORDER BY ordinal_position
+            "#,
+        )
+        .bind(table.name)
+        .fetch_all(&mut *self.tx)
+        .await?;
+
+        let updated: Vec<&String> = columns
+            .iter()
+            .filter(|column| *column != table.key && !table.omit.contains(&column.as_str()))
+            .collect();
+        let target = updated
+            .iter()
+            .map(|column| format!("t.{}", column))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let source = updated
+            .iter()
+            .map(|column| format!("EXCLUDED.{}", column))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let names = updated
+            .iter()
+            .map(|column| column.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        if updated.is_empty() {
+            return Ok(format!(
+                "INSERT INTO {table} \
+                 SELECT * FROM jsonb_populate_record(NULL::{table}, $1) \
+                 ON CONFLICT ({key}) DO NOTHING \
+                 RETURNING true AS inserted",
+                table = table.name,
+                key = table.key,
+            ));
+        }
+
+        Ok(format!(
+            "INSERT INTO {table} AS t \
+             SELECT * FROM jsonb_populate_record(NULL::{table}, $1) \
+             ON CONFLICT ({key}) DO UPDATE SET ({names}) = ROW({source}) \
+             WHERE ({target}) IS DISTINCT FROM ({source}) \
+             RETURNING (xmax = 0) AS inserted",
+            table = table.name,
+            key = table.key,
+        ))
+    }
+}
@@ -1,40 +1,92 @@
 use crate::{
-    dto::{responses::ContributionResponse, CreateContributionRequest, UpdateContributionRequest},
+    dto::{
+        responses::{ContributionPaginatedResponse, ContributionResponse},
+        CreateContributionRequest, UpdateContributionRequest,
+    },
     error::AppError,
+    services::notification_service,
+    utils::database::with_transaction,
 };
 use sqlx::{PgPool, Row};
 use uuid::Uuid;
 
+/// Create a contribution.
+///
+/// When an idempotency key is given and the user already created a contribution
+/// with the same key, the existing contribution is returned instead of a new one.
+/// Moderators are notified when a new contribution has `flagged_terms`.
 pub async fn create_contribution(
     pool: &PgPool,
     user_id: Uuid,
     request: CreateContributionRequest,
+    idempotency_key: Option<String>,
+    flagged_terms: Vec<String>,
 ) -> Result<ContributionResponse, AppError> {
     let contribution_id = Uuid::new_v4();
 
-    let record = sqlx::query(
-        r#"
-        INSERT INTO user_contributions (
-            id, user_id, contribution_type, entity_type, entity_id, action,
-            previous_value, new_value, points_awarded, status, created_at
-        )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, NOW())
-        RETURNING id, user_id, contribution_type, entity_type, entity_id, action,
-                  previous_value, new_value, points_awarded, status, reviewed_by, reviewed_at,
-                  created_at
-        "#,
-    )
-    .bind(contribution_id)
-    .bind(user_id)
-    .bind(&request.contribution_type)
-    .bind(&request.entity_type)
-    .bind(&request.entity_id)
-    .bind(&request.action)
-    .bind(&request.previous_value)
-    .bind(&request.new_value)
-    .bind(request.points_awarded.unwrap_or(0))
-    .bind("pending".to_string())
-    .fetch_one(pool)
+    let record = with_transaction(pool, |tx| {
+        Box::pin(async move {
+            let inserted = sqlx::query(
+                r#"
+                INSERT INTO user_contributions (
+                    id, user_id, contribution_type, entity_type, entity_id, action,
+                    previous_value, new_value, points_awarded, status, idempotency_key,
+                    created_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, NOW())
+                ON CONFLICT (user_id, idempotency_key) WHERE idempotency_key IS NOT NULL
+                DO NOTHING
+                RETURNING id, user_id, contribution_type, entity_type, entity_id, action,
+                          previous_value, new_value, points_awarded, status, reviewed_by, reviewed_at,
+                          created_at
+                "#,
+            )
+            .bind(contribution_id)
+            .bind(user_id)
+            .bind(&request.contribution_type)
+            .bind(&request.entity_type)
+            .bind(request.entity_id)
+            .bind(&request.action)
+            .bind(&request.previous_value)
+            .bind(&request.new_value)
+            .bind(request.points_awarded.unwrap_or(0))
+            .bind("pending".to_string())
+            .bind(&idempotency_key)
+            .fetch_optional(&mut **tx)
+            .await?;
+
+            if let Some(record) = inserted {
+                if !flagged_terms.is_empty() {
+                    notification_service::notify_flagged_submission(
+                        tx,
+                        "contribution",
+                        contribution_id,
+                        &flagged_terms,
+                    )
+                    .await?;
+                }
+
+                return Ok(record);
+            }
+
+            // Retried request: hand back the contribution recorded the first time
+            let existing = sqlx::query(
+                r#"
+                SELECT id, user_id, contribution_type, entity_type, entity_id, action,
+                       previous_value, new_value, points_awarded, status, reviewed_by, reviewed_at,
+                       created_at
+                FROM user_contributions
+                WHERE user_id = $1 AND idempotency_key = $2
+                "#,
+            )
+            .bind(user_id)
+            .bind(&idempotency_key)
+            .fetch_one(&mut **tx)
+            .await?;
+
+            Ok(existing)
+        })
+    })
     .await?;
 
     Ok(ContributionResponse {
@@ -49,6 +101,7 @@ pub async fn create_contribution(
         points_awarded: record.get("points_awarded"),
         status: record.get("status"),
         reviewed_by: record.get("reviewed_by"),
+        reviewed_by_email: None,
         reviewed_at: record.get("reviewed_at"),
         created_at: record.get("created_at"),
     })
@@ -87,6 +140,7 @@ pub async fn get_contribution(
         points_awarded: record.get("points_awarded"),
         status: record.get("status"),
         reviewed_by: record.get("reviewed_by"),
+        reviewed_by_email: None,
         reviewed_at: record.get("reviewed_at"),
         created_at: record.get("created_at"),
     })
@@ -148,12 +202,85 @@ pub async fn list_contributions(
             points_awarded: record.get("points_awarded"),
             status: record.get("status"),
             reviewed_by: record.get("reviewed_by"),
+            reviewed_by_email: None,
             reviewed_at: record.get("reviewed_at"),
             created_at: record.get("created_at"),
         })
         .collect())
 }
 
+/// List contributions across all users for review, optionally by status
+pub async fn list_contributions_for_review(
+    pool: &PgPool,
+    status: Option<String>,
+    page: i64,
+    per_page: i64,
+    count: bool,
+) -> Result<ContributionPaginatedResponse, AppError> {
+    let offset = (page - 1) * per_page;
+    // Without a COUNT, fetch one extra row to learn whether another page exists
+    let limit = if count { per_page } else { per_page + 1 };
+
+    let mut records = sqlx::query(
+        r#"
+        SELECT c.id, c.user_id, c.contribution_type, c.entity_type, c.entity_id, c.action,
+               c.previous_value, c.new_value, c.points_awarded, c.status, c.reviewed_by,
+               c.reviewed_at, c.created_at, r.email as reviewed_by_email
+        FROM user_contributions c
+        LEFT JOIN users r ON c.reviewed_by = r.id
+        WHERE ($1::text IS NULL OR c.status = $1)
+        ORDER BY c.created_at DESC
+        LIMIT $2 OFFSET $3
+        "#,
+    )
+    .bind(&status)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
+
+    let has_more = records.len() as i64 > per_page;
+    records.truncate(per_page as usize);
+
+    let items = records
+        .into_iter()
+        .map(|record| ContributionResponse {
+            id: record.get("id"),
+            user_id: record.get("user_id"),
+            contribution_type: record.get("contribution_type"),
+            entity_type: record.get("entity_type"),
+            entity_id: record.get("entity_id"),
+            action: record.get("action"),
+            previous_value: record.get("previous_value"),
+            new_value: record.get("new_value"),
+            points_awarded: record.get("points_awarded"),
+            status: record.get("status"),
+            reviewed_by: record.get("reviewed_by"),
+            reviewed_by_email: record.get("reviewed_by_email"),
+            reviewed_at: record.get("reviewed_at"),
+            created_at: record.get("created_at"),
+        })
+        .collect();
+
+    if !count {
+        return Ok(ContributionPaginatedResponse::without_total(
+            items, page, per_page, has_more,
+        ));
+    }
+
+    let total_result = sqlx::query(
+        "SELECT COUNT(*) FROM user_contributions WHERE ($1::text IS NULL OR status = $1)",
+    )
+    .bind(&status)
+    .fetch_one(pool)
+    .await?;
+    let total: i64 = total_result.get(0);
+
+    Ok(ContributionPaginatedResponse::new(
+        items, page, per_page, total,
+    ))
+}
+
 pub async fn update_contribution(
     pool: &PgPool,
     contribution_id: Uuid,
@@ -191,6 +318,7 @@ pub async fn update_contribution(
         points_awarded: record.get("points_awarded"),
         status: record.get("status"),
         reviewed_by: record.get("reviewed_by"),
+        reviewed_by_email: None,
         reviewed_at: record.get("reviewed_at"),
         created_at: record.get("created_at"),
     })
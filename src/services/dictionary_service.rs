@@ -1,12 +1,167 @@
 use crate::{
+    config::{DictionarySettings, MAX_PAGE},
     dto::{
-        responses::{DictionaryEntryResponse, DictionaryPaginatedResponse},
-        CreateDictionaryEntryRequest, SearchDictionaryRequest, UpdateDictionaryEntryRequest,
+        responses::{
+            BatchError, BatchResult, DictionaryEntryResponse, DictionaryFreshnessResponse,
+            DictionaryPaginatedResponse, DictionaryStatsResponse, DictionarySuggestionResponse,
+            EntryConflict, FreshnessBucket, SearchCountResponse,
+        },
+        CreateDictionaryEntryRequest, SearchDictionaryRequest, SearchType,
+        SuggestDictionaryEntryRequest, UpdateDictionaryEntryRequest,
     },
     error::AppError,
+    services::{analytics_service::LOOKUP_EVENT_TYPE, notification_service},
+    utils::database::with_transaction,
 };
-use sqlx::{PgPool, Row};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{self, Stream, StreamExt};
+use json_patch::{Patch, PatchOperation};
+use sqlx::{postgres::PgRow, Acquire, PgConnection, PgExecutor, PgPool, Row};
+use std::{
+    future::ready,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, PoisonError, RwLock,
+    },
+    time::{Duration, Instant},
+};
+use tokio::sync::{broadcast, OwnedSemaphorePermit, Semaphore};
+use tracing::warn;
 use uuid::Uuid;
+use validator::Validate;
+
+/// Number of entries listed in the freshness report's oldest unverified list
+const OLDEST_UNVERIFIED_LIMIT: i64 = 20;
+
+/// In-memory cache for [`stats`] with a fixed time-to-live.
+///
+/// Once the TTL elapses, one caller recomputes the stats while concurrent
+/// callers keep getting the stale value. Writes that change the counts call
+/// [`DictionaryStatsCache::invalidate`].
+pub struct DictionaryStatsCache {
+    ttl: Duration,
+    cached: RwLock<Option<(Instant, DictionaryStatsResponse)>>,
+    refreshing: AtomicBool,
+    generation: AtomicU64,
+}
+
+impl DictionaryStatsCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            cached: RwLock::new(None),
+            refreshing: AtomicBool::new(false),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    /// Drop the cached stats so the next read recomputes them
+    pub fn invalidate(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        *self.cached.write().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    fn get(&self) -> Option<(Instant, DictionaryStatsResponse)> {
+        self.cached
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn store(&self, generation: u64, stats: DictionaryStatsResponse) {
+        let mut cached = self.cached.write().unwrap_or_else(PoisonError::into_inner);
+        // Skip results computed before an invalidation
+        if self.generation.load(Ordering::Acquire) == generation {
+            *cached = Some((Instant::now(), stats));
+        }
+    }
+}
+
+/// Number of verification events a slow stream subscriber may fall behind by
+const VERIFIED_FEED_CAPACITY: usize = 256;
+
+/// In-process broadcast of entries as they are verified, feeding the SSE stream.
+pub struct VerifiedEntryFeed {
+    sender: broadcast::Sender<DictionaryEntryResponse>,
+}
+
+impl VerifiedEntryFeed {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(VERIFIED_FEED_CAPACITY);
+        Self { sender }
+    }
+
+    pub fn publish(&self, entry: &DictionaryEntryResponse) {
+        // No subscribers is not an error; the event is simply dropped
+        let _ = self.sender.send(entry.clone());
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<DictionaryEntryResponse> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for VerifiedEntryFeed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Caps how many dictionary exports run at once across all workers, since
+/// each one holds a pooled connection for as long as the download lasts.
+pub struct DictionaryExportLimiter {
+    permits: Arc<Semaphore>,
+    statement_timeout: Duration,
+}
+
+impl DictionaryExportLimiter {
+    pub fn new(max_concurrent: usize, statement_timeout: Duration) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(max_concurrent)),
+            statement_timeout,
+        }
+    }
+
+    /// Take an export slot, or `None` when every slot is busy
+    pub fn try_acquire(&self) -> Option<ExportPermit> {
+        let permit = self.permits.clone().try_acquire_owned().ok()?;
+
+        Some(ExportPermit {
+            _permit: permit,
+            statement_timeout: self.statement_timeout,
+        })
+    }
+}
+
+/// Export slot from [`DictionaryExportLimiter`], released when dropped
+pub struct ExportPermit {
+    _permit: OwnedSemaphorePermit,
+    statement_timeout: Duration,
+}
+
+/// Map a row selecting every [`DictionaryEntryResponse`] column
+pub fn entry_from_row(record: &PgRow) -> DictionaryEntryResponse {
+    DictionaryEntryResponse {
+        id: record.get("id"),
+        pnar_word: record.get("pnar_word"),
+        english_word: record.get("english_word"),
+        part_of_speech: record.get("part_of_speech"),
+        definition: record.get("definition"),
+        example_pnar: record.get("example_pnar"),
+        example_english: record.get("example_english"),
+        difficulty_level: record.get("difficulty_level"),
+        usage_frequency: record.get("usage_frequency"),
+        cultural_context: record.get("cultural_context"),
+        related_words: record.get("related_words"),
+        pronunciation: record.get("pronunciation"),
+        etymology: record.get("etymology"),
+        verified: record.get("verified"),
+        created_at: record.get("created_at"),
+        updated_at: record.get("updated_at"),
+        created_by: record.get("created_by"),
+        version: record.get("version"),
+    }
+}
 
 pub async fn create_entry(
     pool: &PgPool,
@@ -14,82 +169,261 @@ pub async fn create_entry(
     request: CreateDictionaryEntryRequest,
 ) -> Result<DictionaryEntryResponse, AppError> {
     let entry_id = Uuid::new_v4();
+    let pnar_word = request.pnar_word.clone();
 
-    // Check if pnar_word already exists
-    let existing = sqlx::query("SELECT id FROM pnar_dictionary WHERE pnar_word = $1")
-        .bind(&request.pnar_word)
-        .fetch_optional(pool)
-        .await?;
+    let entry_record = with_transaction(pool, |tx| {
+        Box::pin(async move {
+            // Check if pnar_word already exists
+            if let Some(existing_id) = find_id_by_pnar_word(&mut **tx, &request.pnar_word).await? {
+                return Err(duplicate_pnar_word(&request.pnar_word, Some(existing_id)));
+            }
 
-    if existing.is_some() {
-        return Err(AppError::Conflict(format!(
-            "Pnar word '{}' already exists",
-            request.pnar_word
+            let entry_record = sqlx::query(
+                r#"
+                INSERT INTO pnar_dictionary (
+                    id, pnar_word, english_word, part_of_speech, definition,
+                    example_pnar, example_english, difficulty_level, usage_frequency,
+                    cultural_context, related_words, pronunciation, etymology,
+                    created_by, created_at, updated_at, verified
+                )
+                VALUES (
+                    $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, NOW(), NOW(), $15
+                )
+                RETURNING id, pnar_word, english_word, part_of_speech, definition,
+                          example_pnar, example_english, difficulty_level, usage_frequency,
+                          cultural_context, related_words, pronunciation, etymology,
+                          verified, created_at, updated_at, created_by, version
+                "#,
+            )
+            .bind(entry_id)
+            .bind(&request.pnar_word)
+            .bind(&request.english_word)
+            .bind(&request.part_of_speech)
+            .bind(&request.definition)
+            .bind(&request.example_pnar)
+            .bind(&request.example_english)
+            .bind(request.difficulty_level.unwrap_or(1))
+            .bind(request.usage_frequency.unwrap_or(0))
+            .bind(&request.cultural_context)
+            .bind(&request.related_words)
+            .bind(&request.pronunciation)
+            .bind(&request.etymology)
+            .bind(author_id)
+            .bind(false) // verified default
+            .fetch_one(&mut **tx)
+            .await;
+
+            match entry_record {
+                Ok(record) => Ok(record),
+                Err(sqlx::Error::Database(db_err)) if db_err.code().as_deref() == Some("23505") => {
+                    // Lost a race with a concurrent insert; the id is looked up after rollback
+                    Err(duplicate_pnar_word(&request.pnar_word, None))
+                }
+                Err(e) => Err(AppError::Database(e)),
+            }
+        })
+    })
+    .await;
+
+    let entry_record = match entry_record {
+        Ok(record) => record,
+        Err(AppError::DuplicateResource {
+            existing_id: None, ..
+        }) => {
+            let existing_id = find_id_by_pnar_word(pool, &pnar_word).await?;
+            return Err(duplicate_pnar_word(&pnar_word, existing_id));
+        }
+        Err(e) => return Err(e),
+    };
+
+    Ok(entry_from_row(&entry_record))
+}
+
+/// Columns accepted in a CSV import header row.
+///
+/// Headers use the JSON field names of [`CreateDictionaryEntryRequest`] and may
+/// appear in any order. Only `pnar_word` and `english_word` are required; empty
+/// cells are treated as missing values.
+pub const CSV_IMPORT_COLUMNS: &[&str] = &[
+    "pnar_word",
+    "english_word",
+    "part_of_speech",
+    "definition",
+    "example_pnar",
+    "example_english",
+    "difficulty_level",
+    "usage_frequency",
+    "cultural_context",
+    "related_words",
+    "pronunciation",
+    "etymology",
+];
+
+/// Parse an uploaded CSV file into one entry request (or row error) per data row.
+///
+/// Header problems reject the whole file; a malformed row only fails that row.
+pub fn parse_csv_entries(
+    data: &[u8],
+) -> Result<Vec<Result<CreateDictionaryEntryRequest, String>>, AppError> {
+    let data = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(data);
+
+    let headers = reader
+        .headers()
+        .map_err(|e| AppError::Validation(format!("Invalid CSV header: {}", e)))?
+        .clone();
+
+    for required in ["pnar_word", "english_word"] {
+        if !headers.iter().any(|header| header == required) {
+            return Err(AppError::Validation(format!(
+                "CSV is missing the required '{}' column",
+                required
+            )));
+        }
+    }
+
+    if let Some(unknown) = headers
+        .iter()
+        .find(|header| !CSV_IMPORT_COLUMNS.contains(header))
+    {
+        return Err(AppError::Validation(format!(
+            "Unknown CSV column '{}'; expected any of: {}",
+            unknown,
+            CSV_IMPORT_COLUMNS.join(", ")
         )));
     }
 
-    let entry_record = sqlx::query(
-        r#"
-        INSERT INTO pnar_dictionary (
-            id, pnar_word, english_word, part_of_speech, definition,
-            example_pnar, example_english, difficulty_level, usage_frequency,
-            cultural_context, related_words, pronunciation, etymology,
-            created_by, created_at, updated_at, verified
-        )
-        VALUES (
-            $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, NOW(), NOW(), $15
+    Ok(reader
+        .deserialize::<CreateDictionaryEntryRequest>()
+        .map(|row| row.map_err(|e| format!("Malformed row: {}", e)))
+        .collect())
+}
+
+/// Create entries from parsed import rows, reporting failures per row.
+///
+/// Rows are zero-based data rows (the header is not counted). Each insert runs
+/// in its own savepoint so a duplicate or invalid row does not abort the rest.
+pub async fn create_entries_batch(
+    pool: &PgPool,
+    author_id: Uuid,
+    rows: Vec<Result<CreateDictionaryEntryRequest, String>>,
+) -> Result<BatchResult<DictionaryEntryResponse>, AppError> {
+    let mut created = Vec::new();
+    let mut failed = Vec::new();
+
+    let mut tx = pool.begin().await?;
+
+    for (index, row) in rows.into_iter().enumerate() {
+        let request = match row {
+            Ok(request) => request,
+            Err(message) => {
+                failed.push(BatchError { index, message });
+                continue;
+            }
+        };
+
+        if let Err(e) = request.validate() {
+            failed.push(BatchError {
+                index,
+                message: AppError::from(e).to_string(),
+            });
+            continue;
+        }
+
+        let mut savepoint = tx.begin().await?;
+        let result = sqlx::query(
+            r#"
+            INSERT INTO pnar_dictionary (
+                id, pnar_word, english_word, part_of_speech, definition,
+                example_pnar, example_english, difficulty_level, usage_frequency,
+                cultural_context, related_words, pronunciation, etymology,
+                created_by, created_at, updated_at, verified
+            )
+            VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, NOW(), NOW(), false
+            )
+            RETURNING id, pnar_word, english_word, part_of_speech, definition,
+                      example_pnar, example_english, difficulty_level, usage_frequency,
+                      cultural_context, related_words, pronunciation, etymology,
+                      verified, created_at, updated_at, created_by, version
+            "#,
         )
-        RETURNING id, pnar_word, english_word, part_of_speech, definition,
-                  example_pnar, example_english, difficulty_level, usage_frequency,
-                  cultural_context, related_words, pronunciation, etymology,
-                  verified, created_at, updated_at, created_by
-        "#
-    )
-    .bind(entry_id)
-    .bind(&request.pnar_word)
-    .bind(&request.english_word)
-    .bind(&request.part_of_speech)
-    .bind(&request.definition)
-    .bind(&request.example_pnar)
-    .bind(&request.example_english)
-    .bind(request.difficulty_level.unwrap_or(1))
-    .bind(request.usage_frequency.unwrap_or(0))
-    .bind(&request.cultural_context)
-    .bind(&request.related_words)
-    .bind(&request.pronunciation)
-    .bind(&request.etymology)
-    .bind(author_id)
-    .bind(false) // verified default
-    .fetch_one(pool)
-    .await
-    .map_err(|e| {
-        if let sqlx::Error::Database(db_err) = &e {
-            if db_err.code().as_deref() == Some("23505") {
-                return AppError::Conflict(format!("Dictionary entry with pnar_word '{}' already exists", request.pnar_word));
+        .bind(Uuid::new_v4())
+        .bind(&request.pnar_word)
+        .bind(&request.english_word)
+        .bind(&request.part_of_speech)
+        .bind(&request.definition)
+        .bind(&request.example_pnar)
+        .bind(&request.example_english)
+        .bind(request.difficulty_level.unwrap_or(1))
+        .bind(request.usage_frequency.unwrap_or(0))
+        .bind(&request.cultural_context)
+        .bind(&request.related_words)
+        .bind(&request.pronunciation)
+        .bind(&request.etymology)
+        .bind(author_id)
+        .fetch_one(&mut *savepoint)
+        .await;
+
+        match result {
+            Ok(record) => {
+                savepoint.commit().await?;
+                created.push(entry_from_row(&record));
+            }
+            Err(sqlx::Error::Database(db_err)) if db_err.code().as_deref() == Some("23505") => {
+                savepoint.rollback().await?;
+                failed.push(BatchError {
+                    index,
+                    message: format!("Pnar word '{}' already exists", request.pnar_word),
+                });
             }
+            Err(e) => return Err(AppError::Database(e)),
         }
-        AppError::Database(e)
-    })?;
+    }
 
-    Ok(DictionaryEntryResponse {
-        id: entry_record.get("id"),
-        pnar_word: entry_record.get("pnar_word"),
-        english_word: entry_record.get("english_word"),
-        part_of_speech: entry_record.get("part_of_speech"),
-        definition: entry_record.get("definition"),
-        example_pnar: entry_record.get("example_pnar"),
-        example_english: entry_record.get("example_english"),
-        difficulty_level: entry_record.get("difficulty_level"),
-        usage_frequency: entry_record.get("usage_frequency"),
-        cultural_context: entry_record.get("cultural_context"),
-        related_words: entry_record.get("related_words"),
-        pronunciation: entry_record.get("pronunciation"),
-        etymology: entry_record.get("etymology"),
-        verified: entry_record.get("verified"),
-        created_at: entry_record.get("created_at"),
-        updated_at: entry_record.get("updated_at"),
-        created_by: entry_record.get("created_by"),
-    })
+    tx.commit().await?;
+
+    Ok(BatchResult::new(created, failed))
+}
+
+async fn find_id_by_pnar_word<'e, E>(executor: E, pnar_word: &str) -> Result<Option<Uuid>, AppError>
+where
+    E: PgExecutor<'e>,
+{
+    let existing = sqlx::query("SELECT id FROM pnar_dictionary WHERE pnar_word = $1")
+        .bind(pnar_word)
+        .fetch_optional(executor)
+        .await?;
+
+    Ok(existing.map(|record| record.get("id")))
+}
+
+/// Existing entries that would make `create_entry` fail for this request
+pub async fn find_conflicts(
+    pool: &PgPool,
+    request: &CreateDictionaryEntryRequest,
+) -> Result<Vec<EntryConflict>, AppError> {
+    let existing_id = find_id_by_pnar_word(pool, &request.pnar_word).await?;
+
+    Ok(existing_id
+        .map(|existing_id| EntryConflict {
+            field: "pnar_word".to_string(),
+            value: request.pnar_word.clone(),
+            existing_id,
+        })
+        .into_iter()
+        .collect())
+}
+
+fn duplicate_pnar_word(pnar_word: &str, existing_id: Option<Uuid>) -> AppError {
+    AppError::DuplicateResource {
+        message: format!("Pnar word '{}' already exists", pnar_word),
+        conflict_field: "pnar_word".to_string(),
+        conflicting_value: pnar_word.to_string(),
+        existing_id,
+    }
 }
 
 pub async fn get_entry(pool: &PgPool, entry_id: Uuid) -> Result<DictionaryEntryResponse, AppError> {
@@ -98,7 +432,7 @@ pub async fn get_entry(pool: &PgPool, entry_id: Uuid) -> Result<DictionaryEntryR
         SELECT id, pnar_word, english_word, part_of_speech, definition,
                example_pnar, example_english, difficulty_level, usage_frequency,
                cultural_context, related_words, pronunciation, etymology,
-               verified, created_at, updated_at, created_by
+               verified, created_at, updated_at, created_by, version
         FROM pnar_dictionary 
         WHERE id = $1
         "#,
@@ -110,163 +444,501 @@ pub async fn get_entry(pool: &PgPool, entry_id: Uuid) -> Result<DictionaryEntryR
     let entry_record =
         entry_record.ok_or_else(|| AppError::NotFound("Dictionary entry not found".to_string()))?;
 
-    Ok(DictionaryEntryResponse {
-        id: entry_record.get("id"),
-        pnar_word: entry_record.get("pnar_word"),
-        english_word: entry_record.get("english_word"),
-        part_of_speech: entry_record.get("part_of_speech"),
-        definition: entry_record.get("definition"),
-        example_pnar: entry_record.get("example_pnar"),
-        example_english: entry_record.get("example_english"),
-        difficulty_level: entry_record.get("difficulty_level"),
-        usage_frequency: entry_record.get("usage_frequency"),
-        cultural_context: entry_record.get("cultural_context"),
-        related_words: entry_record.get("related_words"),
-        pronunciation: entry_record.get("pronunciation"),
-        etymology: entry_record.get("etymology"),
-        verified: entry_record.get("verified"),
-        created_at: entry_record.get("created_at"),
-        updated_at: entry_record.get("updated_at"),
-        created_by: entry_record.get("created_by"),
-    })
+    Ok(entry_from_row(&entry_record))
+}
+
+/// Resolve the words listed in an entry's `related_words` to their own entries.
+///
+/// `related_words` is free text; it is split on commas and semicolons and each
+/// part matched against `pnar_word`. Words without an entry are skipped.
+pub async fn get_related_entries(
+    pool: &PgPool,
+    entry: &DictionaryEntryResponse,
+) -> Result<Vec<DictionaryEntryResponse>, AppError> {
+    let words: Vec<String> = entry
+        .related_words
+        .as_deref()
+        .unwrap_or_default()
+        .split([',', ';'])
+        .map(str::trim)
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    if words.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let records = sqlx::query(
+        r#"
+        SELECT id, pnar_word, english_word, part_of_speech, definition,
+               example_pnar, example_english, difficulty_level, usage_frequency,
+               cultural_context, related_words, pronunciation, etymology,
+               verified, created_at, updated_at, created_by, version
+        FROM pnar_dictionary
+        WHERE pnar_word = ANY($1) AND id <> $2
+        ORDER BY pnar_word
+        "#,
+    )
+    .bind(&words)
+    .bind(entry.id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(records
+        .into_iter()
+        .map(|record| entry_from_row(&record))
+        .collect())
+}
+
+/// Sort order for [`list_entries`]
+#[derive(Debug, Clone, Copy, Default)]
+pub enum EntryOrder {
+    /// Newest first
+    #[default]
+    CreatedAt,
+    /// Most looked-up first
+    LookupCount,
+}
+
+impl EntryOrder {
+    fn order_by(self) -> &'static str {
+        match self {
+            EntryOrder::CreatedAt => "created_at DESC, id",
+            EntryOrder::LookupCount => "lookup_count DESC, id",
+        }
+    }
+}
+
+/// Optional filters for [`list_entries`]; unset fields match every entry
+#[derive(Debug, Default)]
+pub struct EntryFilters {
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub verified: Option<bool>,
+    /// Matched case-insensitively
+    pub part_of_speech: Option<String>,
 }
 
+/// One page of entries, plus when the filtered set last changed as a whole
+/// (its newest `updated_at`, or the latest delete or filter change). The
+/// timestamp comes from the COUNT query, so it is `None` when `count` is false.
 pub async fn list_entries(
     pool: &PgPool,
     page: i64,
     per_page: i64,
-) -> Result<DictionaryPaginatedResponse, AppError> {
+    count: bool,
+    filters: &EntryFilters,
+    order: EntryOrder,
+) -> Result<(DictionaryPaginatedResponse, Option<DateTime<Utc>>), AppError> {
     let offset = (page - 1) * per_page;
+    // Without a COUNT, fetch one extra row to learn whether another page exists
+    let limit = if count { per_page } else { per_page + 1 };
 
-    let entries = sqlx::query(
+    let mut entries = sqlx::query(&format!(
         r#"
         SELECT id, pnar_word, english_word, part_of_speech, definition,
                example_pnar, example_english, difficulty_level, usage_frequency,
                cultural_context, related_words, pronunciation, etymology,
-               verified, created_at, updated_at, created_by
+               verified, created_at, updated_at, created_by, version
         FROM pnar_dictionary 
-        ORDER BY created_at DESC
+        WHERE ($3::timestamptz IS NULL OR created_at >= $3)
+          AND ($4::timestamptz IS NULL OR created_at <= $4)
+          AND ($5::boolean IS NULL OR verified = $5)
+          AND ($6::text IS NULL OR LOWER(part_of_speech) = LOWER($6))
+        ORDER BY {}
         LIMIT $1 OFFSET $2
         "#,
-    )
-    .bind(per_page)
+        order.order_by()
+    ))
+    .bind(limit)
     .bind(offset)
+    .bind(filters.created_after)
+    .bind(filters.created_before)
+    .bind(filters.verified)
+    .bind(&filters.part_of_speech)
     .fetch_all(pool)
     .await?;
 
-    let total_result = sqlx::query("SELECT COUNT(*) FROM pnar_dictionary")
-        .fetch_one(pool)
-        .await?;
-    let total: i64 = total_result.get(0);
+    let has_more = entries.len() as i64 > per_page;
+    entries.truncate(per_page as usize);
 
     let items: Vec<DictionaryEntryResponse> = entries
         .into_iter()
-        .map(|record| DictionaryEntryResponse {
-            id: record.get("id"),
-            pnar_word: record.get("pnar_word"),
-            english_word: record.get("english_word"),
-            part_of_speech: record.get("part_of_speech"),
-            definition: record.get("definition"),
-            example_pnar: record.get("example_pnar"),
-            example_english: record.get("example_english"),
-            difficulty_level: record.get("difficulty_level"),
-            usage_frequency: record.get("usage_frequency"),
-            cultural_context: record.get("cultural_context"),
-            related_words: record.get("related_words"),
-            pronunciation: record.get("pronunciation"),
-            etymology: record.get("etymology"),
-            verified: record.get("verified"),
-            created_at: record.get("created_at"),
-            updated_at: record.get("updated_at"),
-            created_by: record.get("created_by"),
-        })
+        .map(|record| entry_from_row(&record))
+        .collect();
+
+    if !count {
+        return Ok((
+            DictionaryPaginatedResponse::without_total(items, page, per_page, has_more),
+            None,
+        ));
+    }
+
+    // Keep these filters in sync with the page query above
+    let total_result = sqlx::query(
+        r#"
+        SELECT COUNT(*),
+               GREATEST(
+                   MAX(updated_at),
+                   (SELECT changed_at FROM dictionary_changes)
+               ) AS last_modified
+        FROM pnar_dictionary
+        WHERE ($1::timestamptz IS NULL OR created_at >= $1)
+          AND ($2::timestamptz IS NULL OR created_at <= $2)
+          AND ($3::boolean IS NULL OR verified = $3)
+          AND ($4::text IS NULL OR LOWER(part_of_speech) = LOWER($4))
+        "#,
+    )
+    .bind(filters.created_after)
+    .bind(filters.created_before)
+    .bind(filters.verified)
+    .bind(&filters.part_of_speech)
+    .fetch_one(pool)
+    .await?;
+    let total: i64 = total_result.get(0);
+    let last_modified: Option<DateTime<Utc>> = total_result.get("last_modified");
+
+    Ok((
+        DictionaryPaginatedResponse::new(items, page, per_page, total),
+        last_modified,
+    ))
+}
+
+/// Optional entry columns curators can build a missing-field worklist for
+pub const MISSING_FIELD_COLUMNS: &[&str] = &[
+    "pronunciation",
+    "etymology",
+    "example_pnar",
+    "cultural_context",
+    "definition",
+];
+
+/// Entries whose `field` is NULL or blank, oldest first, optionally limited
+/// to verified or unverified entries
+pub async fn entries_missing_field(
+    pool: &PgPool,
+    field: &str,
+    verified: Option<bool>,
+    page: i64,
+    per_page: i64,
+) -> Result<DictionaryPaginatedResponse, AppError> {
+    // Only allowlisted names are interpolated into the SQL below
+    let Some(column) = MISSING_FIELD_COLUMNS
+        .iter()
+        .find(|column| **column == field)
+    else {
+        return Err(AppError::Validation(format!(
+            "Unsupported field '{}'; expected one of: {}",
+            field,
+            MISSING_FIELD_COLUMNS.join(", ")
+        )));
+    };
+    let offset = (page - 1) * per_page;
+
+    let records = sqlx::query(&format!(
+        r#"
+        SELECT id, pnar_word, english_word, part_of_speech, definition,
+               example_pnar, example_english, difficulty_level, usage_frequency,
+               cultural_context, related_words, pronunciation, etymology,
+               verified, created_at, updated_at, created_by, version
+        FROM pnar_dictionary
+        WHERE NULLIF(BTRIM({column}), '') IS NULL
+          AND ($3::boolean IS NULL OR verified = $3)
+        ORDER BY created_at ASC, id
+        LIMIT $1 OFFSET $2
+        "#
+    ))
+    .bind(per_page)
+    .bind(offset)
+    .bind(verified)
+    .fetch_all(pool)
+    .await?;
+
+    let items = records
+        .into_iter()
+        .map(|record| entry_from_row(&record))
         .collect();
 
+    let total: i64 = sqlx::query(&format!(
+        r#"
+        SELECT COUNT(*) FROM pnar_dictionary
+        WHERE NULLIF(BTRIM({column}), '') IS NULL
+          AND ($1::boolean IS NULL OR verified = $1)
+        "#
+    ))
+    .bind(verified)
+    .fetch_one(pool)
+    .await?
+    .get(0);
+
     Ok(DictionaryPaginatedResponse::new(
         items, page, per_page, total,
     ))
 }
 
+/// Search entries, returning at most `max_search_limit` results per page
+/// whatever the request asks for. Fuzzy searches fall back to substring
+/// matching when `pg_trgm` is not installed.
 pub async fn search_entries(
     pool: &PgPool,
     request: SearchDictionaryRequest,
-) -> Result<Vec<DictionaryEntryResponse>, AppError> {
+    settings: &DictionarySettings,
+) -> Result<DictionaryPaginatedResponse, AppError> {
     let query = format!("%{}%", request.query);
+    let verified_only = request.verified_only.unwrap_or(false);
+    let page = request.page.unwrap_or(1).clamp(1, MAX_PAGE);
+    let per_page = request
+        .per_page
+        .or(request.limit)
+        .unwrap_or(50)
+        .clamp(1, settings.max_search_limit.max(1));
+    let offset = (page - 1) * per_page;
+
+    if matches!(request.search_type, Some(SearchType::Fuzzy)) {
+        match fuzzy_search(
+            pool,
+            &request.query,
+            verified_only,
+            page,
+            per_page,
+            settings.fuzzy_min_similarity,
+        )
+        .await
+        {
+            Err(err) if is_trigram_unavailable(&err) => {
+                warn!("pg_trgm is unavailable; falling back to substring search");
+            }
+            result => return result,
+        }
+    }
 
     let entries = sqlx::query(
         r#"
         SELECT id, pnar_word, english_word, part_of_speech, definition,
                example_pnar, example_english, difficulty_level, usage_frequency,
                cultural_context, related_words, pronunciation, etymology,
-               verified, created_at, updated_at, created_by
+               verified, created_at, updated_at, created_by, version
         FROM pnar_dictionary 
-        WHERE pnar_word ILIKE $1 OR english_word ILIKE $1 OR definition ILIKE $1
+        WHERE (pnar_word ILIKE $1 OR english_word ILIKE $1 OR definition ILIKE $1)
+          AND (NOT $3 OR verified = true)
         ORDER BY 
             CASE WHEN pnar_word ILIKE $1 THEN 1 ELSE 2 END,
-            created_at DESC
-        LIMIT $2
+            created_at DESC,
+            id
+        LIMIT $2 OFFSET $4
         "#,
     )
     .bind(&query)
-    .bind(request.limit.unwrap_or(50))
+    .bind(per_page)
+    .bind(verified_only)
+    .bind(offset)
     .fetch_all(pool)
     .await?;
 
     let results: Vec<DictionaryEntryResponse> = entries
         .into_iter()
-        .map(|record| DictionaryEntryResponse {
-            id: record.get("id"),
-            pnar_word: record.get("pnar_word"),
-            english_word: record.get("english_word"),
-            part_of_speech: record.get("part_of_speech"),
-            definition: record.get("definition"),
-            example_pnar: record.get("example_pnar"),
-            example_english: record.get("example_english"),
-            difficulty_level: record.get("difficulty_level"),
-            usage_frequency: record.get("usage_frequency"),
-            cultural_context: record.get("cultural_context"),
-            related_words: record.get("related_words"),
-            pronunciation: record.get("pronunciation"),
-            etymology: record.get("etymology"),
-            verified: record.get("verified"),
-            created_at: record.get("created_at"),
-            updated_at: record.get("updated_at"),
-            created_by: record.get("created_by"),
-        })
+        .map(|record| entry_from_row(&record))
         .collect();
 
-    Ok(results)
+    let total = count_substring_matches(pool, &request.query, verified_only).await?;
+
+    Ok(DictionaryPaginatedResponse::new(
+        results, page, per_page, total,
+    ))
 }
 
-pub async fn update_entry(
+/// Trigram similarity search over Pnar and English words, most similar Pnar
+/// word first. Only words at least `min_similarity` similar to `query` match.
+async fn fuzzy_search(
     pool: &PgPool,
-    entry_id: Uuid,
-    user_id: Uuid,
-    request: UpdateDictionaryEntryRequest,
-) -> Result<DictionaryEntryResponse, AppError> {
-    // First, check if the entry exists and user has permission
-    let existing = sqlx::query("SELECT created_by FROM pnar_dictionary WHERE id = $1")
-        .bind(entry_id)
-        .fetch_optional(pool)
-        .await?;
+    query: &str,
+    verified_only: bool,
+    page: i64,
+    per_page: i64,
+    min_similarity: f32,
+) -> Result<DictionaryPaginatedResponse, AppError> {
+    let query = query.to_string();
 
-    let existing =
-        existing.ok_or_else(|| AppError::NotFound("Dictionary entry not found".to_string()))?;
-    let created_by: Option<Uuid> = existing.get("created_by");
+    with_transaction(pool, |tx| {
+        Box::pin(async move {
+            set_similarity_threshold(tx, min_similarity).await?;
 
-    if created_by != Some(user_id) {
-        return Err(AppError::Forbidden(
-            "You can only update your own entries".to_string(),
-        ));
-    }
+            let entries = sqlx::query(
+                r#"
+                SELECT id, pnar_word, english_word, part_of_speech, definition,
+                       example_pnar, example_english, difficulty_level, usage_frequency,
+                       cultural_context, related_words, pronunciation, etymology,
+                       verified, created_at, updated_at, created_by, version
+                FROM pnar_dictionary
+                WHERE (pnar_word % $1 OR english_word % $1)
+                  AND (NOT $3 OR verified = true)
+                ORDER BY
+                    similarity(pnar_word, $1) DESC,
+                    similarity(english_word, $1) DESC,
+                    id
+                LIMIT $2 OFFSET $4
+                "#,
+            )
+            .bind(&query)
+            .bind(per_page)
+            .bind(verified_only)
+            .bind((page - 1) * per_page)
+            .fetch_all(&mut **tx)
+            .await?;
 
-    let entry_record = sqlx::query(
-        r#"
-        UPDATE pnar_dictionary 
-        SET 
-            pnar_word = COALESCE($2, pnar_word),
-            english_word = COALESCE($3, english_word),
-            part_of_speech = COALESCE($4, part_of_speech),
+            let total = count_fuzzy_matches(tx, &query, verified_only).await?;
+
+            let results: Vec<DictionaryEntryResponse> = entries
+                .into_iter()
+                .map(|record| entry_from_row(&record))
+                .collect();
+
+            Ok(DictionaryPaginatedResponse::new(
+                results, page, per_page, total,
+            ))
+        })
+    })
+    .await
+}
+
+/// Whether `err` means the `%` operator from pg_trgm is missing
+/// (42883: undefined_function)
+fn is_trigram_unavailable(err: &AppError) -> bool {
+    matches!(
+        err,
+        AppError::Database(sqlx::Error::Database(db_err)) if db_err.code().as_deref() == Some("42883")
+    )
+}
+
+/// Set the similarity `%` compares against, for the rest of the transaction
+async fn set_similarity_threshold(
+    conn: &mut PgConnection,
+    min_similarity: f32,
+) -> Result<(), AppError> {
+    sqlx::query("SELECT set_config('pg_trgm.similarity_threshold', $1, true)")
+        .bind(min_similarity.to_string())
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+async fn count_fuzzy_matches(
+    conn: &mut PgConnection,
+    query: &str,
+    verified_only: bool,
+) -> Result<i64, AppError> {
+    let count = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*)
+        FROM pnar_dictionary
+        WHERE (pnar_word % $1 OR english_word % $1)
+          AND (NOT $2 OR verified = true)
+        "#,
+    )
+    .bind(query)
+    .bind(verified_only)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(count)
+}
+
+async fn count_substring_matches(
+    pool: &PgPool,
+    query: &str,
+    verified_only: bool,
+) -> Result<i64, AppError> {
+    let count = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*)
+        FROM pnar_dictionary
+        WHERE (pnar_word ILIKE $1 OR english_word ILIKE $1 OR definition ILIKE $1)
+          AND (NOT $2 OR verified = true)
+        "#,
+    )
+    .bind(format!("%{}%", query))
+    .bind(verified_only)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(count)
+}
+
+/// Count the entries [`search_entries`] would match for `query` across all
+/// pages, matching the same way for the given `search_type`
+pub async fn search_count(
+    pool: &PgPool,
+    query: &str,
+    search_type: Option<&SearchType>,
+    verified_only: bool,
+    settings: &DictionarySettings,
+) -> Result<SearchCountResponse, AppError> {
+    if matches!(search_type, Some(SearchType::Fuzzy)) {
+        let fuzzy_query = query.to_string();
+        let min_similarity = settings.fuzzy_min_similarity;
+        let fuzzy = with_transaction(pool, |tx| {
+            Box::pin(async move {
+                set_similarity_threshold(tx, min_similarity).await?;
+                count_fuzzy_matches(tx, &fuzzy_query, verified_only).await
+            })
+        })
+        .await;
+
+        match fuzzy {
+            Err(err) if is_trigram_unavailable(&err) => {
+                warn!("pg_trgm is unavailable; falling back to substring search");
+            }
+            result => {
+                return Ok(SearchCountResponse {
+                    query: query.to_string(),
+                    count: result?,
+                })
+            }
+        }
+    }
+
+    Ok(SearchCountResponse {
+        query: query.to_string(),
+        count: count_substring_matches(pool, query, verified_only).await?,
+    })
+}
+
+pub async fn update_entry(
+    pool: &PgPool,
+    entry_id: Uuid,
+    user_id: Uuid,
+    request: UpdateDictionaryEntryRequest,
+) -> Result<DictionaryEntryResponse, AppError> {
+    // First, check if the entry exists and user has permission
+    let existing = sqlx::query("SELECT created_by, version FROM pnar_dictionary WHERE id = $1")
+        .bind(entry_id)
+        .fetch_optional(pool)
+        .await?;
+
+    let existing =
+        existing.ok_or_else(|| AppError::NotFound("Dictionary entry not found".to_string()))?;
+    let created_by: Option<Uuid> = existing.get("created_by");
+
+    if created_by != Some(user_id) {
+        return Err(AppError::Forbidden(
+            "You can only update your own entries".to_string(),
+        ));
+    }
+
+    let current_version: i32 = existing.get("version");
+    if current_version != request.version {
+        return Err(stale_version(request.version, current_version));
+    }
+
+    let entry_record = sqlx::query(
+        r#"
+        UPDATE pnar_dictionary 
+        SET 
+            pnar_word = COALESCE($2, pnar_word),
+            english_word = COALESCE($3, english_word),
+            part_of_speech = COALESCE($4, part_of_speech),
             definition = COALESCE($5, definition),
             example_pnar = COALESCE($6, example_pnar),
             example_english = COALESCE($7, example_english),
@@ -276,12 +948,13 @@ pub async fn update_entry(
             related_words = COALESCE($11, related_words),
             pronunciation = COALESCE($12, pronunciation),
             etymology = COALESCE($13, etymology),
+            version = version + 1,
             updated_at = NOW()
-        WHERE id = $1
+        WHERE id = $1 AND version = $14
         RETURNING id, pnar_word, english_word, part_of_speech, definition,
                   example_pnar, example_english, difficulty_level, usage_frequency,
                   cultural_context, related_words, pronunciation, etymology,
-                  verified, created_at, updated_at, created_by
+                  verified, created_at, updated_at, created_by, version
         "#,
     )
     .bind(entry_id)
@@ -297,7 +970,8 @@ pub async fn update_entry(
     .bind(&request.related_words)
     .bind(&request.pronunciation)
     .bind(&request.etymology)
-    .fetch_one(pool)
+    .bind(request.version)
+    .fetch_optional(pool)
     .await
     .map_err(|e| {
         if let sqlx::Error::Database(db_err) = &e {
@@ -310,25 +984,148 @@ pub async fn update_entry(
         AppError::Database(e)
     })?;
 
-    Ok(DictionaryEntryResponse {
-        id: entry_record.get("id"),
-        pnar_word: entry_record.get("pnar_word"),
-        english_word: entry_record.get("english_word"),
-        part_of_speech: entry_record.get("part_of_speech"),
-        definition: entry_record.get("definition"),
-        example_pnar: entry_record.get("example_pnar"),
-        example_english: entry_record.get("example_english"),
-        difficulty_level: entry_record.get("difficulty_level"),
-        usage_frequency: entry_record.get("usage_frequency"),
-        cultural_context: entry_record.get("cultural_context"),
-        related_words: entry_record.get("related_words"),
-        pronunciation: entry_record.get("pronunciation"),
-        etymology: entry_record.get("etymology"),
-        verified: entry_record.get("verified"),
-        created_at: entry_record.get("created_at"),
-        updated_at: entry_record.get("updated_at"),
-        created_by: entry_record.get("created_by"),
-    })
+    // Another update landed between the version check and this write
+    let Some(entry_record) = entry_record else {
+        return Err(AppError::Conflict(format!(
+            "Dictionary entry was modified concurrently; version {} is stale",
+            request.version
+        )));
+    };
+
+    Ok(entry_from_row(&entry_record))
+}
+
+/// Fields a JSON Patch is not allowed to touch.
+///
+/// `version` can still be checked with a `test` operation to guard against
+/// lost updates.
+const IMMUTABLE_PATCH_FIELDS: &[&str] = &[
+    "id",
+    "created_by",
+    "verified",
+    "created_at",
+    "updated_at",
+    "version",
+];
+
+/// Conflict error for an update based on an outdated entry version
+fn stale_version(expected: i32, current: i32) -> AppError {
+    AppError::Conflict(format!(
+        "Dictionary entry has been modified: expected version {}, current version is {}",
+        expected, current
+    ))
+}
+
+/// Apply an RFC 6902 JSON Patch to an entry owned by `user_id`.
+///
+/// The patch is applied to the entry's JSON representation, and the result is
+/// validated like a new entry before it replaces the stored fields.
+pub async fn patch_entry(
+    pool: &PgPool,
+    entry_id: Uuid,
+    user_id: Uuid,
+    patch: Patch,
+) -> Result<DictionaryEntryResponse, AppError> {
+    for operation in patch.iter() {
+        if matches!(operation, PatchOperation::Test(_)) {
+            continue;
+        }
+
+        let mut touched = vec![operation.path()];
+        if let PatchOperation::Move(op) = operation {
+            touched.push(&op.from);
+        }
+
+        for path in touched {
+            match path.first().map(|token| token.decoded().into_owned()) {
+                None => {
+                    return Err(AppError::Validation(
+                        "JSON Patch cannot replace the whole entry".to_string(),
+                    ))
+                }
+                Some(field) if IMMUTABLE_PATCH_FIELDS.contains(&field.as_str()) => {
+                    return Err(AppError::Validation(format!(
+                        "Field '{}' cannot be modified",
+                        field
+                    )))
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    let existing = get_entry(pool, entry_id).await?;
+    if existing.created_by != Some(user_id) {
+        return Err(AppError::Forbidden(
+            "You can only update your own entries".to_string(),
+        ));
+    }
+
+    let mut document = serde_json::to_value(&existing)
+        .map_err(|e| AppError::Internal(format!("Failed to serialize entry: {}", e)))?;
+    json_patch::patch(&mut document, &patch)
+        .map_err(|e| AppError::Validation(format!("Invalid JSON Patch: {}", e)))?;
+
+    let patched: CreateDictionaryEntryRequest = serde_json::from_value(document)
+        .map_err(|e| AppError::Validation(format!("Patched entry is invalid: {}", e)))?;
+    patched.validate()?;
+
+    let entry_record = sqlx::query(
+        r#"
+        UPDATE pnar_dictionary
+        SET
+            pnar_word = $2,
+            english_word = $3,
+            part_of_speech = $4,
+            definition = $5,
+            example_pnar = $6,
+            example_english = $7,
+            difficulty_level = $8,
+            usage_frequency = $9,
+            cultural_context = $10,
+            related_words = $11,
+            pronunciation = $12,
+            etymology = $13,
+            version = version + 1,
+            updated_at = NOW()
+        WHERE id = $1 AND version = $14
+        RETURNING id, pnar_word, english_word, part_of_speech, definition,
+                  example_pnar, example_english, difficulty_level, usage_frequency,
+                  cultural_context, related_words, pronunciation, etymology,
+                  verified, created_at, updated_at, created_by, version
+        "#,
+    )
+    .bind(entry_id)
+    .bind(&patched.pnar_word)
+    .bind(&patched.english_word)
+    .bind(&patched.part_of_speech)
+    .bind(&patched.definition)
+    .bind(&patched.example_pnar)
+    .bind(&patched.example_english)
+    .bind(patched.difficulty_level)
+    .bind(patched.usage_frequency)
+    .bind(&patched.cultural_context)
+    .bind(&patched.related_words)
+    .bind(&patched.pronunciation)
+    .bind(&patched.etymology)
+    .bind(existing.version)
+    .fetch_optional(pool)
+    .await;
+
+    let entry_record = match entry_record {
+        Ok(Some(record)) => record,
+        Ok(None) => {
+            let current = get_entry(pool, entry_id).await?;
+            return Err(stale_version(existing.version, current.version));
+        }
+        Err(sqlx::Error::Database(db_err)) if db_err.code().as_deref() == Some("23505") => {
+            let existing_id = find_id_by_pnar_word(pool, &patched.pnar_word).await?;
+            return Err(duplicate_pnar_word(&patched.pnar_word, existing_id));
+        }
+        Err(e) => return Err(AppError::Database(e)),
+    };
+
+    Ok(entry_from_row(&entry_record))
 }
 
 pub async fn delete_entry(pool: &PgPool, entry_id: Uuid, user_id: Uuid) -> Result<(), AppError> {
@@ -356,47 +1153,889 @@ pub async fn delete_entry(pool: &PgPool, entry_id: Uuid, user_id: Uuid) -> Resul
     Ok(())
 }
 
-pub async fn verify_entry(
+/// Transfer ownership of an entry to another user (moderators only).
+///
+/// The previous and new owners are recorded as a contribution by `actor_id`
+/// so the handover shows up in the moderator's history.
+pub async fn reassign_owner(
+    pool: &PgPool,
+    entry_id: Uuid,
+    new_owner_id: Uuid,
+    actor_id: Uuid,
+) -> Result<DictionaryEntryResponse, AppError> {
+    let entry_record = with_transaction(pool, |tx| {
+        Box::pin(async move {
+            let existing =
+                sqlx::query("SELECT created_by FROM pnar_dictionary WHERE id = $1 FOR UPDATE")
+                    .bind(entry_id)
+                    .fetch_optional(&mut **tx)
+                    .await?
+                    .ok_or_else(|| AppError::NotFound("Dictionary entry not found".to_string()))?;
+            let previous_owner: Option<Uuid> = existing.get("created_by");
+
+            let owner_active: Option<bool> =
+                sqlx::query_scalar("SELECT is_active FROM users WHERE id = $1")
+                    .bind(new_owner_id)
+                    .fetch_optional(&mut **tx)
+                    .await?;
+            match owner_active {
+                None => return Err(AppError::NotFound("New owner not found".to_string())),
+                Some(false) => {
+                    return Err(AppError::Validation(
+                        "New owner account is inactive".to_string(),
+                    ))
+                }
+                Some(true) => {}
+            }
+
+            let entry_record = sqlx::query(
+                r#"
+                UPDATE pnar_dictionary
+                SET created_by = $2, version = version + 1, updated_at = NOW()
+                WHERE id = $1
+                RETURNING id, pnar_word, english_word, part_of_speech, definition,
+                          example_pnar, example_english, difficulty_level, usage_frequency,
+                          cultural_context, related_words, pronunciation, etymology,
+                          verified, created_at, updated_at, created_by, version
+                "#,
+            )
+            .bind(entry_id)
+            .bind(new_owner_id)
+            .fetch_one(&mut **tx)
+            .await?;
+
+            sqlx::query(
+                r#"
+                INSERT INTO user_contributions (
+                    user_id, contribution_type, entity_type, entity_id, action,
+                    previous_value, new_value, status, reviewed_by, reviewed_at
+                )
+                VALUES ($1, 'dictionary_entry', 'pnar_dictionary', $2, 'reassign',
+                        $3, $4, 'approved', $1, NOW())
+                "#,
+            )
+            .bind(actor_id)
+            .bind(entry_id)
+            .bind(serde_json::json!({ "created_by": previous_owner }))
+            .bind(serde_json::json!({ "created_by": new_owner_id }))
+            .execute(&mut **tx)
+            .await?;
+
+            Ok(entry_record)
+        })
+    })
+    .await?;
+
+    Ok(entry_from_row(&entry_record))
+}
+
+/// Fold the duplicate entry `merge_id` into `keep_id` (moderators only).
+///
+/// Usage analytics and suggestions pointing at the duplicate move to the kept
+/// entry, and optional fields the kept entry lacks are filled from the
+/// duplicate. The duplicate is then deleted; its last state is stored in the
+/// contribution recorded for `actor_id`.
+pub async fn merge_entries(
     pool: &PgPool,
+    keep_id: Uuid,
+    merge_id: Uuid,
+    actor_id: Uuid,
+) -> Result<DictionaryEntryResponse, AppError> {
+    if keep_id == merge_id {
+        return Err(AppError::Validation(
+            "Cannot merge an entry into itself".to_string(),
+        ));
+    }
+
+    let entry_record = with_transaction(pool, |tx| {
+        Box::pin(async move {
+            // Lock both rows in a stable order so concurrent merges can't deadlock
+            let locked: Vec<Uuid> = sqlx::query_scalar(
+                "SELECT id FROM pnar_dictionary WHERE id = ANY($1) ORDER BY id FOR UPDATE",
+            )
+            .bind([keep_id, merge_id])
+            .fetch_all(&mut **tx)
+            .await?;
+
+            if !locked.contains(&keep_id) {
+                return Err(AppError::NotFound(
+                    "Dictionary entry to keep not found".to_string(),
+                ));
+            }
+            if !locked.contains(&merge_id) {
+                return Err(AppError::NotFound(
+                    "Dictionary entry to merge not found".to_string(),
+                ));
+            }
+
+            let merged_snapshot: serde_json::Value =
+                sqlx::query_scalar("SELECT to_jsonb(d) FROM pnar_dictionary d WHERE id = $1")
+                    .bind(merge_id)
+                    .fetch_one(&mut **tx)
+                    .await?;
+
+            sqlx::query("UPDATE word_usage_analytics SET word_id = $1 WHERE word_id = $2")
+                .bind(keep_id)
+                .bind(merge_id)
+                .execute(&mut **tx)
+                .await?;
+
+            sqlx::query("UPDATE dictionary_suggestions SET entry_id = $1 WHERE entry_id = $2")
+                .bind(keep_id)
+                .bind(merge_id)
+                .execute(&mut **tx)
+                .await?;
+
+            let entry_record = sqlx::query(
+                r#"
+                UPDATE pnar_dictionary AS keep
+                SET
+                    part_of_speech = COALESCE(NULLIF(keep.part_of_speech, ''), dup.part_of_speech),
+                    definition = COALESCE(NULLIF(keep.definition, ''), dup.definition),
+                    example_pnar = COALESCE(NULLIF(keep.example_pnar, ''), dup.example_pnar),
+                    example_english = COALESCE(NULLIF(keep.example_english, ''), dup.example_english),
+                    difficulty_level = COALESCE(keep.difficulty_level, dup.difficulty_level),
+                    usage_frequency = COALESCE(keep.usage_frequency, dup.usage_frequency),
+                    cultural_context = COALESCE(NULLIF(keep.cultural_context, ''), dup.cultural_context),
+                    related_words = COALESCE(NULLIF(keep.related_words, ''), dup.related_words),
+                    pronunciation = COALESCE(NULLIF(keep.pronunciation, ''), dup.pronunciation),
+                    etymology = COALESCE(NULLIF(keep.etymology, ''), dup.etymology),
+                    version = keep.version + 1,
+                    updated_at = NOW()
+                FROM pnar_dictionary AS dup
+                WHERE keep.id = $1 AND dup.id = $2
+                RETURNING keep.id, keep.pnar_word, keep.english_word, keep.part_of_speech,
+                          keep.definition, keep.example_pnar, keep.example_english,
+                          keep.difficulty_level, keep.usage_frequency, keep.cultural_context,
+                          keep.related_words, keep.pronunciation, keep.etymology, keep.verified,
+                          keep.created_at, keep.updated_at, keep.created_by, keep.version
+                "#,
+            )
+            .bind(keep_id)
+            .bind(merge_id)
+            .fetch_one(&mut **tx)
+            .await?;
+
+            sqlx::query("DELETE FROM pnar_dictionary WHERE id = $1")
+                .bind(merge_id)
+                .execute(&mut **tx)
+                .await?;
+
+            sqlx::query(
+                r#"
+                INSERT INTO user_contributions (
+                    user_id, contribution_type, entity_type, entity_id, action,
+                    previous_value, new_value, status, reviewed_by, reviewed_at
+                )
+                VALUES ($1, 'dictionary_entry', 'pnar_dictionary', $2, 'merge',
+                        $3, $4, 'approved', $1, NOW())
+                "#,
+            )
+            .bind(actor_id)
+            .bind(keep_id)
+            .bind(&merged_snapshot)
+            .bind(serde_json::json!({ "merged_into": keep_id, "merged_id": merge_id }))
+            .execute(&mut **tx)
+            .await?;
+
+            Ok(entry_record)
+        })
+    })
+    .await?;
+
+    Ok(entry_from_row(&entry_record))
+}
+
+pub async fn verify_entry(
+    conn: &mut PgConnection,
     entry_id: Uuid,
     verifier_id: Uuid,
 ) -> Result<DictionaryEntryResponse, AppError> {
     let entry_record = sqlx::query(
         r#"
         UPDATE pnar_dictionary 
-        SET verified = true, verified_by = $2, verified_at = NOW(), updated_at = NOW()
+        SET verified = true, verified_by = $2, verified_at = NOW(),
+            version = version + 1, updated_at = NOW()
         WHERE id = $1
         RETURNING id, pnar_word, english_word, part_of_speech, definition,
                   example_pnar, example_english, difficulty_level, usage_frequency,
                   cultural_context, related_words, pronunciation, etymology,
-                  verified, created_at, updated_at, created_by
+                  verified, created_at, updated_at, created_by, version
         "#,
     )
     .bind(entry_id)
     .bind(verifier_id)
-    .fetch_optional(pool)
+    .fetch_optional(conn)
     .await?;
 
     let entry_record =
         entry_record.ok_or_else(|| AppError::NotFound("Dictionary entry not found".to_string()))?;
 
-    Ok(DictionaryEntryResponse {
-        id: entry_record.get("id"),
-        pnar_word: entry_record.get("pnar_word"),
-        english_word: entry_record.get("english_word"),
-        part_of_speech: entry_record.get("part_of_speech"),
-        definition: entry_record.get("definition"),
-        example_pnar: entry_record.get("example_pnar"),
-        example_english: entry_record.get("example_english"),
-        difficulty_level: entry_record.get("difficulty_level"),
-        usage_frequency: entry_record.get("usage_frequency"),
-        cultural_context: entry_record.get("cultural_context"),
-        related_words: entry_record.get("related_words"),
-        pronunciation: entry_record.get("pronunciation"),
-        etymology: entry_record.get("etymology"),
-        verified: entry_record.get("verified"),
-        created_at: entry_record.get("created_at"),
-        updated_at: entry_record.get("updated_at"),
-        created_by: entry_record.get("created_by"),
+    Ok(entry_from_row(&entry_record))
+}
+
+/// Look up an entry by its Pnar word, ignoring surrounding whitespace and case.
+/// An exact-case match wins when several entries differ only by case.
+pub async fn get_by_pnar_word(
+    pool: &PgPool,
+    pnar_word: &str,
+) -> Result<DictionaryEntryResponse, AppError> {
+    let pnar_word = pnar_word.trim();
+
+    let record = sqlx::query(
+        r#"
+        SELECT id, pnar_word, english_word, part_of_speech, definition,
+               example_pnar, example_english, difficulty_level, usage_frequency,
+               cultural_context, related_words, pronunciation, etymology,
+               verified, created_at, updated_at, created_by, version
+        FROM pnar_dictionary
+        WHERE LOWER(pnar_word) = LOWER($1)
+        ORDER BY (pnar_word = $1) DESC, created_at
+        LIMIT 1
+        "#,
+    )
+    .bind(pnar_word)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("No dictionary entry for '{}'", pnar_word)))?;
+
+    Ok(entry_from_row(&record))
+}
+
+/// Entries buffered between the database reader and a slow export client
+const EXPORT_CHANNEL_CAPACITY: usize = 256;
+
+/// CSV export header, in [`DictionaryEntryResponse`] field order
+const EXPORT_CSV_COLUMNS: &[&str] = &[
+    "id",
+    "pnar_word",
+    "english_word",
+    "part_of_speech",
+    "definition",
+    "example_pnar",
+    "example_english",
+    "difficulty_level",
+    "usage_frequency",
+    "cultural_context",
+    "related_words",
+    "pronunciation",
+    "etymology",
+    "verified",
+    "created_at",
+    "updated_at",
+    "created_by",
+    "version",
+];
+
+/// Body format of a full dictionary export
+#[derive(Debug, Clone, Copy)]
+pub enum ExportFormat {
+    /// Header row, then one row per entry
+    Csv,
+    /// A single JSON array of entries
+    Json,
+}
+
+impl ExportFormat {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Json => "application/json",
+        }
+    }
+
+    pub fn file_extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    /// Encode the `index`th entry of the export
+    fn encode(self, index: usize, entry: &DictionaryEntryResponse) -> Result<String, AppError> {
+        match self {
+            ExportFormat::Csv => {
+                let mut writer = csv::WriterBuilder::new()
+                    .has_headers(false)
+                    .from_writer(Vec::new());
+                writer
+                    .serialize(entry)
+                    .map_err(|e| AppError::Internal(format!("Failed to encode CSV row: {}", e)))?;
+                let row = writer
+                    .into_inner()
+                    .map_err(|e| AppError::Internal(format!("Failed to encode CSV row: {}", e)))?;
+                String::from_utf8(row)
+                    .map_err(|e| AppError::Internal(format!("Failed to encode CSV row: {}", e)))
+            }
+            ExportFormat::Json => {
+                let json = serde_json::to_string(entry)
+                    .map_err(|e| AppError::Internal(format!("Failed to encode entry: {}", e)))?;
+                Ok(if index == 0 {
+                    json
+                } else {
+                    format!(",{}", json)
+                })
+            }
+        }
+    }
+}
+
+/// Stream every dictionary entry, oldest first.
+///
+/// A background task reads the rows with a single streaming query and hands
+/// them over through a bounded channel, so memory stays bounded however large
+/// the dictionary is, and the query stops once the consumer goes away. The
+/// query runs under the permit's statement timeout, and the permit is held by
+/// the returned stream until it is finished or dropped.
+pub fn stream_entries(
+    pool: PgPool,
+    permit: ExportPermit,
+) -> impl Stream<Item = Result<DictionaryEntryResponse, AppError>> + 'static {
+    let (sender, receiver) = tokio::sync::mpsc::channel(EXPORT_CHANNEL_CAPACITY);
+    let statement_timeout = format!("{}ms", permit.statement_timeout.as_millis());
+
+    tokio::spawn(async move {
+        // SET LOCAL scoping keeps the timeout from leaking into the pool
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(e) => {
+                let _ = sender.send(Err(AppError::from(e))).await;
+                return;
+            }
+        };
+        if let Err(e) = sqlx::query("SELECT set_config('statement_timeout', $1, true)")
+            .bind(&statement_timeout)
+            .execute(&mut *tx)
+            .await
+        {
+            let _ = sender.send(Err(AppError::from(e))).await;
+            return;
+        }
+
+        let mut records = sqlx::query(
+            r#"
+            SELECT id, pnar_word, english_word, part_of_speech, definition,
+                   example_pnar, example_english, difficulty_level, usage_frequency,
+                   cultural_context, related_words, pronunciation, etymology,
+                   verified, created_at, updated_at, created_by, version
+            FROM pnar_dictionary
+            ORDER BY created_at, id
+            "#,
+        )
+        .fetch(&mut *tx);
+
+        while let Some(record) = records.next().await {
+            let entry = record
+                .map(|record| entry_from_row(&record))
+                .map_err(AppError::from);
+            let failed = entry.is_err();
+
+            if sender.send(entry).await.is_err() || failed {
+                break;
+            }
+        }
+    });
+
+    stream::unfold((receiver, permit), |(mut receiver, permit)| async move {
+        receiver
+            .recv()
+            .await
+            .map(|entry| (entry, (receiver, permit)))
+    })
+}
+
+/// The whole dictionary from [`stream_entries`], encoded as `format` a chunk
+/// per entry
+pub fn export_entries(
+    pool: PgPool,
+    format: ExportFormat,
+    permit: ExportPermit,
+) -> impl Stream<Item = Result<String, AppError>> + 'static {
+    let (head, tail) = match format {
+        ExportFormat::Csv => (format!("{}\n", EXPORT_CSV_COLUMNS.join(",")), None),
+        ExportFormat::Json => ("[".to_string(), Some("]".to_string())),
+    };
+
+    let body = stream_entries(pool, permit)
+        .enumerate()
+        .map(move |(index, entry)| format.encode(index, &entry?));
+
+    stream::once(ready(Ok(head)))
+        .chain(body)
+        .chain(stream::iter(tail.map(Ok)))
+}
+
+/// Entries with the given ids, in the order first requested; ids with no
+/// entry are left out
+pub async fn get_entries_by_ids(
+    pool: &PgPool,
+    ids: Vec<Uuid>,
+) -> Result<Vec<DictionaryEntryResponse>, AppError> {
+    let records = sqlx::query(
+        r#"
+        SELECT id, pnar_word, english_word, part_of_speech, definition,
+               example_pnar, example_english, difficulty_level, usage_frequency,
+               cultural_context, related_words, pronunciation, etymology,
+               verified, created_at, updated_at, created_by, version
+        FROM pnar_dictionary
+        WHERE id = ANY($1)
+        ORDER BY array_position($1, id)
+        "#,
+    )
+    .bind(&ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(records
+        .into_iter()
+        .map(|record| entry_from_row(&record))
+        .collect())
+}
+
+/// The `limit` most recently verified entries, newest verification first
+pub async fn recently_verified(
+    pool: &PgPool,
+    limit: i64,
+) -> Result<Vec<DictionaryEntryResponse>, AppError> {
+    let records = sqlx::query(
+        r#"
+        SELECT id, pnar_word, english_word, part_of_speech, definition,
+               example_pnar, example_english, difficulty_level, usage_frequency,
+               cultural_context, related_words, pronunciation, etymology,
+               verified, created_at, updated_at, created_by, version
+        FROM pnar_dictionary
+        WHERE verified = true AND verified_at IS NOT NULL
+        ORDER BY verified_at DESC, id
+        LIMIT $1
+        "#,
+    )
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(records
+        .into_iter()
+        .map(|record| entry_from_row(&record))
+        .collect())
+}
+
+/// Entries verified after `since` with their verification time, oldest first.
+///
+/// Used to replay events an SSE client missed while disconnected.
+pub async fn list_verified_since(
+    pool: &PgPool,
+    since: DateTime<Utc>,
+    limit: i64,
+) -> Result<Vec<(DateTime<Utc>, DictionaryEntryResponse)>, AppError> {
+    let records = sqlx::query(
+        r#"
+        SELECT id, pnar_word, english_word, part_of_speech, definition,
+               example_pnar, example_english, difficulty_level, usage_frequency,
+               cultural_context, related_words, pronunciation, etymology,
+               verified, created_at, updated_at, created_by, version, verified_at
+        FROM pnar_dictionary
+        WHERE verified = true AND verified_at > $1
+        ORDER BY verified_at ASC
+        LIMIT $2
+        "#,
+    )
+    .bind(since)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(records
+        .into_iter()
+        .map(|record| (record.get("verified_at"), entry_from_row(&record)))
+        .collect())
+}
+
+/// Optional fields whose population is reported in the stats completeness map
+const COMPLETENESS_FIELDS: &[&str] = &[
+    "definition",
+    "pronunciation",
+    "etymology",
+    "example_pnar",
+    "example_english",
+    "cultural_context",
+];
+
+pub async fn stats(pool: &PgPool) -> Result<DictionaryStatsResponse, AppError> {
+    let totals = sqlx::query(
+        r#"
+        SELECT COUNT(*) AS total,
+               COUNT(*) FILTER (WHERE verified) AS verified,
+               COUNT(*) FILTER (WHERE NULLIF(TRIM(definition), '') IS NOT NULL) AS definition,
+               COUNT(*) FILTER (WHERE NULLIF(TRIM(pronunciation), '') IS NOT NULL) AS pronunciation,
+               COUNT(*) FILTER (WHERE NULLIF(TRIM(etymology), '') IS NOT NULL) AS etymology,
+               COUNT(*) FILTER (WHERE NULLIF(TRIM(example_pnar), '') IS NOT NULL) AS example_pnar,
+               COUNT(*) FILTER (WHERE NULLIF(TRIM(example_english), '') IS NOT NULL) AS example_english,
+               COUNT(*) FILTER (WHERE NULLIF(TRIM(cultural_context), '') IS NOT NULL) AS cultural_context
+        FROM pnar_dictionary
+        "#,
+    )
+    .fetch_one(pool)
+    .await?;
+    let total_entries: i64 = totals.get("total");
+    let verified_entries: i64 = totals.get("verified");
+
+    let completeness = COMPLETENESS_FIELDS
+        .iter()
+        .map(|field| (field.to_string(), totals.get::<i64, _>(*field)))
+        .collect();
+
+    let by_part_of_speech = sqlx::query(
+        r#"
+        SELECT COALESCE(NULLIF(part_of_speech, ''), 'unspecified') AS part_of_speech,
+               COUNT(*) AS count
+        FROM pnar_dictionary
+        GROUP BY 1
+        "#,
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|record| (record.get("part_of_speech"), record.get("count")))
+    .collect();
+
+    Ok(DictionaryStatsResponse {
+        total_entries,
+        verified_entries,
+        unverified_entries: total_entries - verified_entries,
+        by_part_of_speech,
+        completeness,
+        generated_at: Utc::now(),
+    })
+}
+
+/// Count one lookup of an entry.
+///
+/// Only the counter changes, so `updated_at`, `version` and cache validators
+/// stay as they were.
+pub async fn record_lookup(pool: &PgPool, entry_id: Uuid) -> Result<(), AppError> {
+    sqlx::query("UPDATE pnar_dictionary SET lookup_count = lookup_count + 1 WHERE id = $1")
+        .bind(entry_id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Raise lookup counters that fell behind the lookup events recorded in the
+/// analytics table, returning how many entries were corrected.
+///
+/// Counters are never lowered, since lookups counted by [`record_lookup`] need
+/// not have a matching analytics event.
+pub async fn reconcile_lookup_counts(pool: &PgPool) -> Result<u64, AppError> {
+    let result = sqlx::query(
+        r#"
+        UPDATE pnar_dictionary d
+        SET lookup_count = a.lookups
+        FROM (
+            SELECT word_id, COUNT(*) AS lookups
+            FROM word_usage_analytics
+            WHERE event_type = $1
+            GROUP BY word_id
+        ) a
+        WHERE a.word_id = d.id AND d.lookup_count < a.lookups
+        "#,
+    )
+    .bind(LOOKUP_EVENT_TYPE)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// Report how long entries have gone without an update, plus the oldest
+/// unverified entries for curators to review first
+pub async fn freshness_report(pool: &PgPool) -> Result<DictionaryFreshnessResponse, AppError> {
+    let ages = sqlx::query(
+        r#"
+        SELECT COUNT(*) AS total,
+               COUNT(*) FILTER (WHERE updated_at >= NOW() - INTERVAL '30 days') AS under_30_days,
+               COUNT(*) FILTER (WHERE updated_at < NOW() - INTERVAL '30 days'
+                                  AND updated_at >= NOW() - INTERVAL '90 days') AS "30_to_90_days",
+               COUNT(*) FILTER (WHERE updated_at < NOW() - INTERVAL '90 days'
+                                  AND updated_at >= NOW() - INTERVAL '365 days') AS "90_to_365_days",
+               COUNT(*) FILTER (WHERE updated_at < NOW() - INTERVAL '365 days') AS over_365_days
+        FROM pnar_dictionary
+        "#,
+    )
+    .fetch_one(pool)
+    .await?;
+
+    let by_last_update_age = [
+        "under_30_days",
+        "30_to_90_days",
+        "90_to_365_days",
+        "over_365_days",
+    ]
+    .into_iter()
+    .map(|bucket| FreshnessBucket {
+        bucket: bucket.to_string(),
+        count: ages.get(bucket),
+    })
+    .collect();
+
+    let oldest_unverified = sqlx::query(
+        r#"
+        SELECT id, pnar_word, english_word, part_of_speech, definition,
+               example_pnar, example_english, difficulty_level, usage_frequency,
+               cultural_context, related_words, pronunciation, etymology,
+               verified, created_at, updated_at, created_by, version
+        FROM pnar_dictionary
+        WHERE verified = false
+        ORDER BY updated_at ASC, id ASC
+        LIMIT $1
+        "#,
+    )
+    .bind(OLDEST_UNVERIFIED_LIMIT)
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|record| entry_from_row(&record))
+    .collect();
+
+    Ok(DictionaryFreshnessResponse {
+        total_entries: ages.get("total"),
+        by_last_update_age,
+        oldest_unverified,
+        generated_at: Utc::now(),
+    })
+}
+
+/// Return [`stats`] from the cache, recomputing them when the TTL has elapsed
+pub async fn cached_stats(
+    pool: &PgPool,
+    cache: &DictionaryStatsCache,
+) -> Result<DictionaryStatsResponse, AppError> {
+    match cache.get() {
+        Some((computed_at, stats)) if computed_at.elapsed() < cache.ttl => return Ok(stats),
+        // Another request is already recomputing; serve the stale value
+        Some((_, stale)) if cache.refreshing.swap(true, Ordering::AcqRel) => return Ok(stale),
+        Some(_) => {}
+        None => cache.refreshing.store(true, Ordering::Release),
+    }
+
+    let generation = cache.generation.load(Ordering::Acquire);
+    let result = stats(pool).await;
+    cache.refreshing.store(false, Ordering::Release);
+
+    let fresh = result?;
+    cache.store(generation, fresh.clone());
+
+    Ok(fresh)
+}
+
+/// Store an anonymous suggestion as pending and notify moderators. Suggestions
+/// with `flagged_terms` are saved all the same, with the terms called out.
+pub async fn suggest_entry(
+    pool: &PgPool,
+    request: SuggestDictionaryEntryRequest,
+    flagged_terms: Vec<String>,
+) -> Result<DictionarySuggestionResponse, AppError> {
+    let record = with_transaction(pool, |tx| {
+        Box::pin(async move {
+            let record = sqlx::query(
+                r#"
+                INSERT INTO dictionary_suggestions (
+                    id, pnar_word, english_word, part_of_speech, definition,
+                    example_pnar, example_english, contact_name, contact_email,
+                    flagged_terms, status, created_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, 'pending', NOW())
+                RETURNING id, pnar_word, english_word, part_of_speech, definition,
+                          example_pnar, example_english, contact_name, contact_email,
+                          flagged_terms, status, entry_id, reviewed_by, reviewed_at,
+                          created_at
+                "#,
+            )
+            .bind(Uuid::new_v4())
+            .bind(&request.pnar_word)
+            .bind(&request.english_word)
+            .bind(&request.part_of_speech)
+            .bind(&request.definition)
+            .bind(&request.example_pnar)
+            .bind(&request.example_english)
+            .bind(&request.contact_name)
+            .bind(&request.contact_email)
+            .bind(&flagged_terms)
+            .fetch_one(&mut **tx)
+            .await?;
+
+            let suggestion_id: Uuid = record.get("id");
+
+            notification_service::notify_moderators(
+                tx,
+                "dictionary_suggestion",
+                "New dictionary suggestion",
+                &format!(
+                    "'{}' ({}) was suggested for the dictionary",
+                    request.pnar_word, request.english_word
+                ),
+                serde_json::json!({ "suggestion_id": suggestion_id }),
+            )
+            .await?;
+
+            if !flagged_terms.is_empty() {
+                notification_service::notify_flagged_submission(
+                    tx,
+                    "dictionary_suggestion",
+                    suggestion_id,
+                    &flagged_terms,
+                )
+                .await?;
+            }
+
+            Ok(record)
+        })
     })
+    .await?;
+
+    Ok(DictionarySuggestionResponse {
+        id: record.get("id"),
+        pnar_word: record.get("pnar_word"),
+        english_word: record.get("english_word"),
+        part_of_speech: record.get("part_of_speech"),
+        definition: record.get("definition"),
+        example_pnar: record.get("example_pnar"),
+        example_english: record.get("example_english"),
+        contact_name: record.get("contact_name"),
+        contact_email: record.get("contact_email"),
+        flagged_terms: record.get("flagged_terms"),
+        status: record.get("status"),
+        entry_id: record.get("entry_id"),
+        reviewed_by: record.get("reviewed_by"),
+        reviewed_at: record.get("reviewed_at"),
+        created_at: record.get("created_at"),
+    })
+}
+
+/// List suggestions with the given status, oldest first
+pub async fn list_suggestions(
+    pool: &PgPool,
+    status: &str,
+    page: i64,
+    per_page: i64,
+) -> Result<Vec<DictionarySuggestionResponse>, AppError> {
+    let offset = (page - 1) * per_page;
+
+    let records = sqlx::query(
+        r#"
+        SELECT id, pnar_word, english_word, part_of_speech, definition,
+               example_pnar, example_english, contact_name, contact_email,
+               flagged_terms, status, entry_id, reviewed_by, reviewed_at, created_at
+        FROM dictionary_suggestions
+        WHERE status = $1
+        ORDER BY created_at ASC
+        LIMIT $2 OFFSET $3
+        "#,
+    )
+    .bind(status)
+    .bind(per_page)
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(records
+        .into_iter()
+        .map(|record| DictionarySuggestionResponse {
+            id: record.get("id"),
+            pnar_word: record.get("pnar_word"),
+            english_word: record.get("english_word"),
+            part_of_speech: record.get("part_of_speech"),
+            definition: record.get("definition"),
+            example_pnar: record.get("example_pnar"),
+            example_english: record.get("example_english"),
+            contact_name: record.get("contact_name"),
+            contact_email: record.get("contact_email"),
+            flagged_terms: record.get("flagged_terms"),
+            status: record.get("status"),
+            entry_id: record.get("entry_id"),
+            reviewed_by: record.get("reviewed_by"),
+            reviewed_at: record.get("reviewed_at"),
+            created_at: record.get("created_at"),
+        })
+        .collect())
+}
+
+/// Promote a pending suggestion into a real dictionary entry
+pub async fn approve_suggestion(
+    pool: &PgPool,
+    suggestion_id: Uuid,
+    moderator_id: Uuid,
+) -> Result<DictionaryEntryResponse, AppError> {
+    let entry_record = with_transaction(pool, |tx| {
+        Box::pin(async move {
+            let suggestion = sqlx::query(
+                r#"
+                SELECT pnar_word, english_word, part_of_speech, definition,
+                       example_pnar, example_english, status
+                FROM dictionary_suggestions
+                WHERE id = $1
+                FOR UPDATE
+                "#,
+            )
+            .bind(suggestion_id)
+            .fetch_optional(&mut **tx)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Suggestion not found".to_string()))?;
+
+            let status: String = suggestion.get("status");
+            if status != "pending" {
+                return Err(AppError::Conflict(format!(
+                    "Suggestion has already been {}",
+                    status
+                )));
+            }
+
+            let pnar_word: String = suggestion.get("pnar_word");
+            if let Some(existing_id) = find_id_by_pnar_word(&mut **tx, &pnar_word).await? {
+                return Err(duplicate_pnar_word(&pnar_word, Some(existing_id)));
+            }
+
+            let entry_record = sqlx::query(
+                r#"
+                INSERT INTO pnar_dictionary (
+                    id, pnar_word, english_word, part_of_speech, definition,
+                    example_pnar, example_english, created_by, created_at, updated_at, verified
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, NOW(), NOW(), false)
+                RETURNING id, pnar_word, english_word, part_of_speech, definition,
+                          example_pnar, example_english, difficulty_level, usage_frequency,
+                          cultural_context, related_words, pronunciation, etymology,
+                          verified, created_at, updated_at, created_by, version
+                "#,
+            )
+            .bind(Uuid::new_v4())
+            .bind(&pnar_word)
+            .bind(suggestion.get::<String, _>("english_word"))
+            .bind(suggestion.get::<Option<String>, _>("part_of_speech"))
+            .bind(suggestion.get::<Option<String>, _>("definition"))
+            .bind(suggestion.get::<Option<String>, _>("example_pnar"))
+            .bind(suggestion.get::<Option<String>, _>("example_english"))
+            .bind(moderator_id)
+            .fetch_one(&mut **tx)
+            .await?;
+
+            sqlx::query(
+                r#"
+                UPDATE dictionary_suggestions
+                SET status = 'approved', entry_id = $2, reviewed_by = $3, reviewed_at = NOW()
+                WHERE id = $1
+                "#,
+            )
+            .bind(suggestion_id)
+            .bind(entry_record.get::<Uuid, _>("id"))
+            .bind(moderator_id)
+            .execute(&mut **tx)
+            .await?;
+
+            Ok(entry_record)
+        })
+    })
+    .await?;
+
+    Ok(entry_from_row(&entry_record))
 }
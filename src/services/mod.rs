@@ -1,6 +1,9 @@
+pub mod admin_service;
 pub mod analytics_service;
 pub mod auth_service;
+pub mod backup_service;
 pub mod contribution_service;
 pub mod dictionary_service;
+pub mod notification_service;
 pub mod translation_service;
 pub mod user_service;
@@ -0,0 +1,227 @@
+use crate::{
+    dto::responses::{NotificationDigestResponse, NotificationResponse},
+    error::AppResult,
+};
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool, Row};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// Notification type used for digests, which never count towards a digest
+pub const DIGEST_NOTIFICATION_TYPE: &str = "digest";
+
+/// Unread notifications listed individually in a digest
+const DIGEST_LATEST_LIMIT: i64 = 10;
+
+/// Notify every active moderator and admin
+pub async fn notify_moderators(
+    conn: &mut PgConnection,
+    notification_type: &str,
+    title: &str,
+    message: &str,
+    data: serde_json::Value,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO notifications (user_id, type, title, message, data)
+        SELECT id, $1, $2, $3, $4
+        FROM users
+        WHERE role IN ('moderator', 'admin') AND is_active = true
+        "#,
+    )
+    .bind(notification_type)
+    .bind(title)
+    .bind(message)
+    .bind(data)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Tell moderators that a submission contains blocklisted terms
+pub async fn notify_flagged_submission(
+    conn: &mut PgConnection,
+    entity_type: &str,
+    entity_id: Uuid,
+    flagged_terms: &[String],
+) -> AppResult<()> {
+    notify_moderators(
+        conn,
+        "flagged_submission",
+        "Submission flagged for review",
+        &format!(
+            "A {} submission contains blocked terms: {}",
+            entity_type.replace('_', " "),
+            flagged_terms.join(", ")
+        ),
+        serde_json::json!({
+            "entity_type": entity_type,
+            "entity_id": entity_id,
+            "flagged_terms": flagged_terms,
+        }),
+    )
+    .await
+}
+
+/// Record a notification for a user
+pub async fn create_notification(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    notification_type: &str,
+    title: &str,
+    message: &str,
+    data: serde_json::Value,
+) -> AppResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO notifications (user_id, type, title, message, data)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+    )
+    .bind(user_id)
+    .bind(notification_type)
+    .bind(title)
+    .bind(message)
+    .bind(data)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Summarize a user's unread notifications created after `since`
+pub async fn build_digest(
+    pool: &PgPool,
+    user_id: Uuid,
+    since: DateTime<Utc>,
+) -> AppResult<NotificationDigestResponse> {
+    let by_type: BTreeMap<String, i64> = sqlx::query(
+        r#"
+        SELECT type, COUNT(*) AS count
+        FROM notifications
+        WHERE user_id = $1 AND read = false AND created_at > $2 AND type <> $3
+          AND (expires_at IS NULL OR expires_at > NOW())
+        GROUP BY type
+        "#,
+    )
+    .bind(user_id)
+    .bind(since)
+    .bind(DIGEST_NOTIFICATION_TYPE)
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|record| (record.get("type"), record.get("count")))
+    .collect();
+
+    let latest = sqlx::query(
+        r#"
+        SELECT id, type, title, message, data, read, read_at, created_at, expires_at
+        FROM notifications
+        WHERE user_id = $1 AND read = false AND created_at > $2 AND type <> $3
+          AND (expires_at IS NULL OR expires_at > NOW())
+        ORDER BY created_at DESC
+        LIMIT $4
+        "#,
+    )
+    .bind(user_id)
+    .bind(since)
+    .bind(DIGEST_NOTIFICATION_TYPE)
+    .bind(DIGEST_LATEST_LIMIT)
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|record| NotificationResponse {
+        id: record.get("id"),
+        notification_type: record.get("type"),
+        title: record.get("title"),
+        message: record.get("message"),
+        data: record.get("data"),
+        read: record.get("read"),
+        read_at: record.get("read_at"),
+        created_at: record.get("created_at"),
+        expires_at: record.get("expires_at"),
+    })
+    .collect();
+
+    Ok(NotificationDigestResponse {
+        since,
+        unread_count: by_type.values().sum(),
+        by_type,
+        latest,
+        generated_at: Utc::now(),
+    })
+}
+
+/// Build a digest and record it as a single notification, unless there is
+/// nothing unread or the user already got a digest after `since`. Returns
+/// whether a digest notification was created.
+pub async fn send_digest(
+    pool: &PgPool,
+    user_id: Uuid,
+    since: DateTime<Utc>,
+    digest: &NotificationDigestResponse,
+) -> AppResult<bool> {
+    if digest.unread_count == 0 {
+        return Ok(false);
+    }
+
+    let inserted = sqlx::query(
+        r#"
+        INSERT INTO notifications (user_id, type, title, message, data)
+        SELECT $1, $2, $3, $4, $5
+        WHERE NOT EXISTS (
+            SELECT 1 FROM notifications
+            WHERE user_id = $1 AND type = $2 AND created_at > $6
+        )
+        "#,
+    )
+    .bind(user_id)
+    .bind(DIGEST_NOTIFICATION_TYPE)
+    .bind("Your notification digest")
+    .bind(format!(
+        "You have {} unread notification{}",
+        digest.unread_count,
+        if digest.unread_count == 1 { "" } else { "s" }
+    ))
+    .bind(serde_json::json!({
+        "since": digest.since,
+        "unread_count": digest.unread_count,
+        "by_type": digest.by_type,
+    }))
+    .bind(since)
+    .execute(pool)
+    .await?;
+
+    Ok(inserted.rows_affected() > 0)
+}
+
+/// Send a digest to every active user with unread notifications after
+/// `since`. Returns the number of digests sent.
+pub async fn send_digests(pool: &PgPool, since: DateTime<Utc>) -> AppResult<u64> {
+    let user_ids: Vec<Uuid> = sqlx::query(
+        r#"
+        SELECT DISTINCT n.user_id
+        FROM notifications n
+        JOIN users u ON u.id = n.user_id
+        WHERE n.read = false AND n.created_at > $1 AND n.type <> $2 AND u.is_active = true
+        "#,
+    )
+    .bind(since)
+    .bind(DIGEST_NOTIFICATION_TYPE)
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|record| record.get("user_id"))
+    .collect();
+
+    let mut sent = 0;
+    for user_id in user_ids {
+        let digest = build_digest(pool, user_id, since).await?;
+        if send_digest(pool, user_id, since, &digest).await? {
+            sent += 1;
+        }
+    }
+
+    Ok(sent)
+}
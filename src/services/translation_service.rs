@@ -1,5 +1,8 @@
 use crate::{
-    dto::{responses::TranslationResponse, CreateTranslationRequest, UpdateTranslationRequest},
+    dto::{
+        responses::{TranslationPaginatedResponse, TranslationResponse},
+        CreateTranslationRequest, UpdateTranslationRequest,
+    },
     error::AppError,
 };
 use sqlx::{PgPool, Row};
@@ -38,7 +41,7 @@ pub async fn create_translation_request(
             .translation_type
             .unwrap_or_else(|| "automatic".to_string()),
     )
-    .bind(&request.metadata.unwrap_or_else(|| serde_json::json!({})))
+    .bind(request.metadata.unwrap_or_else(|| serde_json::json!({})))
     .fetch_one(pool)
     .await?;
 
@@ -111,7 +114,7 @@ pub async fn list_translation_requests(
     user_id: Uuid,
     page: i64,
     per_page: i64,
-) -> Result<Vec<TranslationResponse>, AppError> {
+) -> Result<TranslationPaginatedResponse, AppError> {
     let offset = (page - 1) * per_page;
 
     let records = sqlx::query(
@@ -133,7 +136,7 @@ pub async fn list_translation_requests(
     .fetch_all(pool)
     .await?;
 
-    Ok(records
+    let translations = records
         .into_iter()
         .map(|record| TranslationResponse {
             id: record.get("id"),
@@ -153,7 +156,20 @@ pub async fn list_translation_requests(
             created_at: record.get("created_at"),
             updated_at: record.get("updated_at"),
         })
-        .collect())
+        .collect();
+
+    let total: i64 = sqlx::query("SELECT COUNT(*) FROM translation_requests WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_one(pool)
+        .await?
+        .get(0);
+
+    Ok(TranslationPaginatedResponse::new(
+        translations,
+        page,
+        per_page,
+        total,
+    ))
 }
 
 pub async fn update_translation_request(
@@ -182,7 +198,9 @@ pub async fn update_translation_request(
     };
 
     if !can_update {
-        return Err(AppError::NotFound("Translation request not found".to_string()));
+        return Err(AppError::NotFound(
+            "Translation request not found".to_string(),
+        ));
     }
 
     // Update the translation
@@ -194,7 +212,10 @@ pub async fn update_translation_request(
             status = COALESCE($3, status),
             confidence_score = COALESCE($4, confidence_score),
             reviewed = COALESCE($5, reviewed),
-            metadata = COALESCE($6, metadata),
+            metadata = CASE
+                WHEN $7 THEN COALESCE(metadata, '{}'::jsonb) || COALESCE($6, '{}'::jsonb)
+                ELSE COALESCE($6, metadata)
+            END,
             updated_at = NOW()
         WHERE id = $1
         "#,
@@ -205,6 +226,7 @@ pub async fn update_translation_request(
     .bind(request.confidence_score)
     .bind(request.reviewed)
     .bind(&request.metadata)
+    .bind(request.merge_metadata.unwrap_or(false))
     .execute(pool)
     .await?;
 
@@ -244,6 +266,67 @@ pub async fn update_translation_request(
     })
 }
 
+/// Minimum time between two writes of an unchanged draft
+const DRAFT_SAVE_MIN_INTERVAL_SECS: f64 = 2.0;
+
+/// Autosave a partial translation: stores the text as-is and moves the
+/// request to `in_progress`. Only the owner can save drafts, and only while
+/// the request is still pending or in progress. New text is always stored;
+/// re-saving the same text within `DRAFT_SAVE_MIN_INTERVAL_SECS` skips the
+/// write and returns the stored draft.
+pub async fn save_draft(
+    pool: &PgPool,
+    user_id: Uuid,
+    request_id: Uuid,
+    partial_text: &str,
+) -> Result<TranslationResponse, AppError> {
+    let result = sqlx::query(
+        r#"
+        UPDATE translation_requests
+        SET translated_text = $3, status = 'in_progress', updated_at = NOW()
+        WHERE id = $1 AND user_id = $2
+          AND (status = 'pending'
+               OR (status = 'in_progress'
+                   AND (translated_text IS DISTINCT FROM $3
+                        OR updated_at <= NOW() - make_interval(secs => $4))))
+        "#,
+    )
+    .bind(request_id)
+    .bind(user_id)
+    .bind(partial_text)
+    .bind(DRAFT_SAVE_MIN_INTERVAL_SECS)
+    .execute(pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        let status: Option<String> = sqlx::query_scalar(
+            "SELECT status FROM translation_requests WHERE id = $1 AND user_id = $2",
+        )
+        .bind(request_id)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?;
+
+        match status.as_deref() {
+            None => {
+                return Err(AppError::NotFound(
+                    "Translation request not found".to_string(),
+                ))
+            }
+            // The same text was stored moments ago
+            Some("in_progress") => {}
+            Some(status) => {
+                return Err(AppError::Conflict(format!(
+                    "Drafts cannot be saved for a translation request that is {}",
+                    status
+                )))
+            }
+        }
+    }
+
+    get_translation_request(pool, request_id, user_id).await
+}
+
 pub async fn delete_translation_request(
     pool: &PgPool,
     request_id: Uuid,
@@ -254,15 +337,18 @@ pub async fn delete_translation_request(
     let (query_str, bind_user_id) = if user_role == "admin" {
         ("DELETE FROM translation_requests WHERE id = $1", false)
     } else {
-        ("DELETE FROM translation_requests WHERE id = $1 AND user_id = $2", true)
+        (
+            "DELETE FROM translation_requests WHERE id = $1 AND user_id = $2",
+            true,
+        )
     };
 
     let mut query = sqlx::query(query_str).bind(request_id);
-    
+
     if bind_user_id {
         query = query.bind(user_id);
     }
-    
+
     let result = query.execute(pool).await?;
 
     if result.rows_affected() == 0 {
@@ -1,23 +1,71 @@
 use crate::{
+    config::SecuritySettings,
     dto::{
-        responses::{PaginatedResponse, UserResponse},
+        responses::{
+            ActivityItem, BatchError, BatchResult, ContributionResponse, NotificationResponse,
+            PaginatedResponse, PointsLedgerEntry, TranslationResponse, UserDataExport,
+            UserResponse, UserSubmission,
+        },
         user::{
-            AwardPointsRequest, CreateUserRequest, UpdatePasswordRequest, UpdateUserRequest,
-            UserQueryParams,
+            AwardPointsRequest, CreateUserRequest, RoleAssignment, UpdatePasswordRequest,
+            UpdateUserRequest, UserQueryParams,
         },
     },
     error::{AppError, AppResult},
-};
-use argon2::{
-    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
-    Argon2,
+    services::dictionary_service,
+    utils::{database::with_transaction, email::normalize_email, password},
 };
 use chrono::Utc;
-use sqlx::{PgPool, Row};
+use sqlx::{postgres::PgRow, Acquire, PgPool, Row};
 use uuid::Uuid;
+use validator::Validate;
+
+/// Contribution types whose `points_awarded` were credited to `translation_points`
+const POINTS_LEDGER_TYPES: &[&str] = &["impact", "points_adjustment"];
+
+/// Roles ordered from least to most privileged
+const ROLE_HIERARCHY: &[&str] = &["user", "contributor", "translator", "moderator", "admin"];
+
+fn role_rank(role: &str) -> Option<usize> {
+    ROLE_HIERARCHY.iter().position(|r| *r == role)
+}
+
+/// Check whether `actor_role` may grant `target_role`: only roles strictly
+/// below the actor's own rank can be assigned.
+pub fn can_assign_role(actor_role: &str, target_role: &str) -> bool {
+    match (role_rank(actor_role), role_rank(target_role)) {
+        (Some(actor), Some(target)) => target < actor,
+        _ => false,
+    }
+}
+
+/// Map a row selecting every [`UserResponse`] column
+pub fn user_from_row(user_row: &PgRow) -> UserResponse {
+    UserResponse {
+        id: user_row.get("id"),
+        email: user_row.get("email"),
+        full_name: user_row.get("full_name"),
+        avatar_url: user_row.get("avatar_url"),
+        role: user_row.get("role"),
+        translation_points: user_row.get("translation_points"),
+        bio: user_row.get("bio"),
+        preferred_language: user_row.get("preferred_language"),
+        settings: user_row.get("settings"),
+        is_active: user_row.get("is_active"),
+        is_email_verified: user_row.get("is_email_verified"),
+        created_at: user_row.get("created_at"),
+        updated_at: user_row.get("updated_at"),
+    }
+}
 
 /// Create a new user
-pub async fn create_user(pool: &PgPool, request: CreateUserRequest) -> AppResult<UserResponse> {
+pub async fn create_user(
+    pool: &PgPool,
+    security: &SecuritySettings,
+    mut request: CreateUserRequest,
+) -> AppResult<UserResponse> {
+    request.email = normalize_email(&request.email);
+
     // Check if user already exists
     let existing_user = sqlx::query("SELECT id FROM users WHERE email = $1")
         .bind(&request.email)
@@ -30,13 +78,7 @@ pub async fn create_user(pool: &PgPool, request: CreateUserRequest) -> AppResult
         ));
     }
 
-    // Hash password
-    let salt = SaltString::generate(&mut OsRng);
-    let argon2 = Argon2::default();
-    let password_hash = argon2
-        .hash_password(request.password.as_bytes(), &salt)
-        .map_err(|e| AppError::Internal(format!("Failed to hash password: {}", e)))?
-        .to_string();
+    let password_hash = password::hash_password(security, &request.password)?;
 
     // Insert user
     let user_id = Uuid::new_v4();
@@ -74,21 +116,189 @@ pub async fn create_user(pool: &PgPool, request: CreateUserRequest) -> AppResult
     .fetch_one(pool)
     .await?;
 
-    Ok(UserResponse {
-        id: user_row.get("id"),
-        email: user_row.get("email"),
-        full_name: user_row.get("full_name"),
-        avatar_url: user_row.get("avatar_url"),
-        role: user_row.get("role"),
-        translation_points: user_row.get("translation_points"),
-        bio: user_row.get("bio"),
-        preferred_language: user_row.get("preferred_language"),
-        settings: user_row.get("settings"),
-        is_active: user_row.get("is_active"),
-        is_email_verified: user_row.get("is_email_verified"),
-        created_at: user_row.get("created_at"),
-        updated_at: user_row.get("updated_at"),
-    })
+    Ok(user_from_row(&user_row))
+}
+
+/// Create several users in one transaction.
+///
+/// Each row runs in its own savepoint so an invalid or duplicate row is
+/// reported in the result without aborting the rest of the batch.
+pub async fn create_users_batch(
+    pool: &PgPool,
+    security: &SecuritySettings,
+    requests: Vec<CreateUserRequest>,
+) -> AppResult<BatchResult<UserResponse>> {
+    let mut created = Vec::new();
+    let mut failed = Vec::new();
+
+    let mut tx = pool.begin().await?;
+
+    for (index, mut request) in requests.into_iter().enumerate() {
+        request.email = normalize_email(&request.email);
+
+        if let Err(e) = request.validate() {
+            failed.push(BatchError {
+                index,
+                message: AppError::from(e).to_string(),
+            });
+            continue;
+        }
+
+        let password_hash = password::hash_password(security, &request.password)?;
+
+        let mut savepoint = tx.begin().await?;
+        let result = sqlx::query(
+            r#"
+            INSERT INTO users (
+                id, email, password, full_name, avatar_url, role, 
+                bio, preferred_language, settings, is_active, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, NOW(), NOW())
+            RETURNING 
+                id, email, password, full_name, avatar_url, role, 
+                translation_points, bio, preferred_language, settings,
+                is_active, is_email_verified, created_at, updated_at
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(&request.email)
+        .bind(&password_hash)
+        .bind(&request.full_name)
+        .bind(&request.avatar_url)
+        .bind(request.role.unwrap_or_else(|| "user".to_string()))
+        .bind(&request.bio)
+        .bind(
+            request
+                .preferred_language
+                .unwrap_or_else(|| "en".to_string()),
+        )
+        .bind(request.settings.unwrap_or_else(|| serde_json::json!({})))
+        .bind(request.is_active.unwrap_or(true))
+        .fetch_one(&mut *savepoint)
+        .await;
+
+        match result {
+            Ok(user_row) => {
+                savepoint.commit().await?;
+                created.push(user_from_row(&user_row));
+            }
+            Err(e) => {
+                savepoint.rollback().await?;
+                let message = match &e {
+                    sqlx::Error::Database(db_err) if db_err.code().as_deref() == Some("23505") => {
+                        "User with this email already exists".to_string()
+                    }
+                    sqlx::Error::Database(db_err) if db_err.code().as_deref() == Some("23503") => {
+                        "Unknown role".to_string()
+                    }
+                    _ => return Err(AppError::Database(e)),
+                };
+                failed.push(BatchError { index, message });
+            }
+        }
+    }
+
+    tx.commit().await?;
+
+    Ok(BatchResult::new(created, failed))
+}
+
+/// Assign roles to several users by email in one transaction.
+///
+/// Each row is checked against the role hierarchy with [`can_assign_role`],
+/// and only users currently ranked below the actor can be changed. Rows
+/// outside the hierarchy, naming an unknown user or role, or trying to change
+/// the actor's own role are reported as failures without aborting the batch.
+pub async fn bulk_update_roles(
+    pool: &PgPool,
+    actor_id: Uuid,
+    actor_role: &str,
+    assignments: Vec<RoleAssignment>,
+) -> AppResult<BatchResult<UserResponse>> {
+    let mut updated = Vec::new();
+    let mut failed = Vec::new();
+
+    let assignable_roles: Vec<&str> = ROLE_HIERARCHY
+        .iter()
+        .copied()
+        .filter(|role| can_assign_role(actor_role, role))
+        .collect();
+
+    let mut tx = pool.begin().await?;
+
+    for (index, mut assignment) in assignments.into_iter().enumerate() {
+        assignment.email = normalize_email(&assignment.email);
+
+        if let Err(e) = assignment.validate() {
+            failed.push(BatchError {
+                index,
+                message: AppError::from(e).to_string(),
+            });
+            continue;
+        }
+
+        if role_rank(&assignment.role).is_some() && !can_assign_role(actor_role, &assignment.role) {
+            failed.push(BatchError {
+                index,
+                message: format!("You cannot assign the '{}' role", assignment.role),
+            });
+            continue;
+        }
+
+        let mut savepoint = tx.begin().await?;
+        let result = sqlx::query(
+            r#"
+            UPDATE users
+            SET role = $2, updated_at = NOW()
+            WHERE email = $1 AND id <> $3 AND role = ANY($4)
+            RETURNING
+                id, email, full_name, avatar_url, role,
+                translation_points, bio, preferred_language, settings,
+                is_active, is_email_verified, created_at, updated_at
+            "#,
+        )
+        .bind(&assignment.email)
+        .bind(&assignment.role)
+        .bind(actor_id)
+        .bind(&assignable_roles)
+        .fetch_optional(&mut *savepoint)
+        .await;
+
+        match result {
+            Ok(Some(user_row)) => {
+                savepoint.commit().await?;
+                updated.push(user_from_row(&user_row));
+            }
+            Ok(None) => {
+                savepoint.rollback().await?;
+                let target_id: Option<Uuid> =
+                    sqlx::query_scalar("SELECT id FROM users WHERE email = $1")
+                        .bind(&assignment.email)
+                        .fetch_optional(&mut *tx)
+                        .await?;
+                let message = match target_id {
+                    Some(id) if id == actor_id => "You cannot change your own role".to_string(),
+                    Some(_) => {
+                        "You cannot change the role of a user at or above your own role".to_string()
+                    }
+                    None => "User not found".to_string(),
+                };
+                failed.push(BatchError { index, message });
+            }
+            Err(sqlx::Error::Database(db_err)) if db_err.code().as_deref() == Some("23503") => {
+                savepoint.rollback().await?;
+                failed.push(BatchError {
+                    index,
+                    message: format!("Unknown role '{}'", assignment.role),
+                });
+            }
+            Err(e) => return Err(AppError::Database(e)),
+        }
+    }
+
+    tx.commit().await?;
+
+    Ok(BatchResult::new(updated, failed))
 }
 
 /// Get user by ID
@@ -108,21 +318,7 @@ pub async fn get_user_by_id(pool: &PgPool, user_id: Uuid) -> AppResult<UserRespo
     .await?
     .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
-    Ok(UserResponse {
-        id: user_record.get("id"),
-        email: user_record.get("email"),
-        full_name: user_record.get("full_name"),
-        avatar_url: user_record.get("avatar_url"),
-        role: user_record.get("role"),
-        translation_points: user_record.get("translation_points"),
-        bio: user_record.get("bio"),
-        preferred_language: user_record.get("preferred_language"),
-        settings: user_record.get("settings"),
-        is_active: user_record.get("is_active"),
-        is_email_verified: user_record.get("is_email_verified"),
-        created_at: user_record.get("created_at"),
-        updated_at: user_record.get("updated_at"),
-    })
+    Ok(user_from_row(&user_record))
 }
 
 /// Get user by email
@@ -137,45 +333,29 @@ pub async fn get_user_by_email(pool: &PgPool, email: &str) -> AppResult<UserResp
         WHERE email = $1
         "#,
     )
-    .bind(email)
+    .bind(normalize_email(email))
     .fetch_optional(pool)
     .await?
     .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
-    Ok(UserResponse {
-        id: user_record.get("id"),
-        email: user_record.get("email"),
-        full_name: user_record.get("full_name"),
-        avatar_url: user_record.get("avatar_url"),
-        role: user_record.get("role"),
-        translation_points: user_record.get("translation_points"),
-        bio: user_record.get("bio"),
-        preferred_language: user_record.get("preferred_language"),
-        settings: user_record.get("settings"),
-        is_active: user_record.get("is_active"),
-        is_email_verified: user_record.get("is_email_verified"),
-        created_at: user_record.get("created_at"),
-        updated_at: user_record.get("updated_at"),
-    })
+    Ok(user_from_row(&user_record))
 }
 
 /// List users with pagination and filtering
 pub async fn list_users(
     pool: &PgPool,
     query: UserQueryParams,
+    page: i64,
+    per_page: i64,
 ) -> AppResult<PaginatedResponse<UserResponse>> {
-    let page = query.page.unwrap_or(1);
-    let per_page = query.per_page.unwrap_or(20);
     let offset = (page - 1) * per_page;
+    let count = query.count.unwrap_or(true);
+    // Without a COUNT, fetch one extra row to learn whether another page exists
+    let limit = if count { per_page } else { per_page + 1 };
 
     // For now, implement a simple version without complex filtering
     // This can be enhanced later with proper query building
-    let total_result = sqlx::query("SELECT COUNT(*) FROM users WHERE is_active = true")
-        .fetch_one(pool)
-        .await?;
-    let total: i64 = total_result.get(0);
-
-    let users_rows = sqlx::query(
+    let mut users_rows = sqlx::query(
         r#"
         SELECT 
             id, email, password, full_name, avatar_url, role, 
@@ -187,30 +367,33 @@ pub async fn list_users(
         LIMIT $1 OFFSET $2
         "#,
     )
-    .bind(per_page)
+    .bind(limit)
     .bind(offset)
     .fetch_all(pool)
     .await?;
 
+    let has_more = users_rows.len() as i64 > per_page;
+    users_rows.truncate(per_page as usize);
+
     let user_responses: Vec<UserResponse> = users_rows
         .into_iter()
-        .map(|row| UserResponse {
-            id: row.get("id"),
-            email: row.get("email"),
-            full_name: row.get("full_name"),
-            avatar_url: row.get("avatar_url"),
-            role: row.get("role"),
-            translation_points: row.get("translation_points"),
-            bio: row.get("bio"),
-            preferred_language: row.get("preferred_language"),
-            settings: row.get("settings"),
-            is_active: row.get("is_active"),
-            is_email_verified: row.get("is_email_verified"),
-            created_at: row.get("created_at"),
-            updated_at: row.get("updated_at"),
-        })
+        .map(|row| user_from_row(&row))
         .collect();
 
+    if !count {
+        return Ok(PaginatedResponse::without_total(
+            user_responses,
+            page,
+            per_page,
+            has_more,
+        ));
+    }
+
+    let total_result = sqlx::query("SELECT COUNT(*) FROM users WHERE is_active = true")
+        .fetch_one(pool)
+        .await?;
+    let total: i64 = total_result.get(0);
+
     Ok(PaginatedResponse::new(
         user_responses,
         page,
@@ -223,8 +406,10 @@ pub async fn list_users(
 pub async fn update_user(
     pool: &PgPool,
     user_id: Uuid,
-    request: UpdateUserRequest,
+    mut request: UpdateUserRequest,
 ) -> AppResult<UserResponse> {
+    request.email = request.email.as_deref().map(normalize_email);
+
     // Check if user exists
     let existing_user = get_user_by_id(pool, user_id).await?;
 
@@ -232,7 +417,7 @@ pub async fn update_user(
     if let Some(ref email) = request.email {
         if email != &existing_user.email {
             let email_exists = sqlx::query("SELECT id FROM users WHERE email = $1 AND id != $2")
-                .bind(&email)
+                .bind(email)
                 .bind(user_id)
                 .fetch_optional(pool)
                 .await?;
@@ -278,26 +463,13 @@ pub async fn update_user(
     .fetch_one(pool)
     .await?;
 
-    Ok(UserResponse {
-        id: user_record.get("id"),
-        email: user_record.get("email"),
-        full_name: user_record.get("full_name"),
-        avatar_url: user_record.get("avatar_url"),
-        role: user_record.get("role"),
-        translation_points: user_record.get("translation_points"),
-        bio: user_record.get("bio"),
-        preferred_language: user_record.get("preferred_language"),
-        settings: user_record.get("settings"),
-        is_active: user_record.get("is_active"),
-        is_email_verified: user_record.get("is_email_verified"),
-        created_at: user_record.get("created_at"),
-        updated_at: user_record.get("updated_at"),
-    })
+    Ok(user_from_row(&user_record))
 }
 
 /// Update user password
 pub async fn update_user_password(
     pool: &PgPool,
+    security: &SecuritySettings,
     user_id: Uuid,
     request: UpdatePasswordRequest,
 ) -> AppResult<()> {
@@ -318,21 +490,15 @@ pub async fn update_user_password(
     .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
     // Verify current password
-    let argon2 = Argon2::default();
-    let password: String = user_record.get("password");
-    let parsed_hash = PasswordHash::new(&password)
-        .map_err(|e| AppError::Internal(format!("Failed to parse password hash: {}", e)))?;
-
-    argon2
-        .verify_password(request.current_password.as_bytes(), &parsed_hash)
-        .map_err(|_| AppError::Unauthorized("Invalid current password".to_string()))?;
+    let current_hash: String = user_record.get("password");
+    if !password::verify_password(security, &request.current_password, &current_hash)? {
+        return Err(AppError::Unauthorized(
+            "Invalid current password".to_string(),
+        ));
+    }
 
     // Hash new password
-    let salt = SaltString::generate(&mut OsRng);
-    let new_password_hash = argon2
-        .hash_password(request.new_password.as_bytes(), &salt)
-        .map_err(|e| AppError::Internal(format!("Failed to hash new password: {}", e)))?
-        .to_string();
+    let new_password_hash = password::hash_password(security, &request.new_password)?;
 
     // Update password
     sqlx::query("UPDATE users SET password = $1, updated_at = NOW() WHERE id = $2")
@@ -373,46 +539,133 @@ pub async fn permanently_delete_user(pool: &PgPool, user_id: Uuid) -> AppResult<
     Ok(())
 }
 
-/// Award or deduct points from user
+/// Erase a user's personal data while keeping their contributions.
+///
+/// The account row is kept (so contribution counts and points still add up) but
+/// its email, name, bio, avatar and settings are wiped and the password replaced
+/// with a random one. Dictionary attribution is cleared, notifications are
+/// deleted and tracking data on analytics events is dropped.
+pub async fn purge_user(
+    pool: &PgPool,
+    security: &SecuritySettings,
+    user_id: Uuid,
+) -> AppResult<()> {
+    let password_hash = password::hash_password(security, &Uuid::new_v4().to_string())?;
+
+    with_transaction(pool, |tx| {
+        Box::pin(async move {
+            let result = sqlx::query(
+                r#"
+                UPDATE users
+                SET email = 'erased-' || id::text || '@erased.invalid',
+                    password = $2,
+                    full_name = NULL,
+                    avatar_url = NULL,
+                    bio = NULL,
+                    settings = '{}',
+                    is_active = false,
+                    is_email_verified = false,
+                    updated_at = NOW()
+                WHERE id = $1
+                "#,
+            )
+            .bind(user_id)
+            .bind(&password_hash)
+            .execute(&mut **tx)
+            .await?;
+
+            if result.rows_affected() == 0 {
+                return Err(AppError::NotFound("User not found".to_string()));
+            }
+
+            sqlx::query("UPDATE pnar_dictionary SET created_by = NULL, version = version + 1 WHERE created_by = $1")
+                .bind(user_id)
+                .execute(&mut **tx)
+                .await?;
+
+            sqlx::query("UPDATE pnar_dictionary SET verified_by = NULL WHERE verified_by = $1")
+                .bind(user_id)
+                .execute(&mut **tx)
+                .await?;
+
+            sqlx::query("DELETE FROM notifications WHERE user_id = $1")
+                .bind(user_id)
+                .execute(&mut **tx)
+                .await?;
+
+            sqlx::query(
+                r#"
+                UPDATE word_usage_analytics
+                SET ip_address = NULL, user_agent = NULL, session_id = NULL
+                WHERE user_id = $1
+                "#,
+            )
+            .bind(user_id)
+            .execute(&mut **tx)
+            .await?;
+
+            Ok(())
+        })
+    })
+    .await
+}
+
+/// Award or deduct points from user, recording the adjustment in the
+/// user's points history
 pub async fn award_points(
     pool: &PgPool,
     user_id: Uuid,
+    awarded_by: Uuid,
     request: AwardPointsRequest,
 ) -> AppResult<UserResponse> {
-    let user_row = sqlx::query(
-        r#"
-        UPDATE users 
-        SET 
-            translation_points = translation_points + $1,
-            updated_at = NOW()
-        WHERE id = $2
-        RETURNING 
-            id, email, password, full_name, avatar_url, role, 
-            translation_points, bio, preferred_language, settings,
-            is_active, is_email_verified, created_at, updated_at
-        "#,
-    )
-    .bind(request.points)
-    .bind(user_id)
-    .fetch_optional(pool)
-    .await?
-    .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+    let user_row = with_transaction(pool, |tx| {
+        Box::pin(async move {
+            let user_row = sqlx::query(
+                r#"
+                UPDATE users 
+                SET 
+                    translation_points = translation_points + $1,
+                    updated_at = NOW()
+                WHERE id = $2
+                RETURNING 
+                    id, email, password, full_name, avatar_url, role, 
+                    translation_points, bio, preferred_language, settings,
+                    is_active, is_email_verified, created_at, updated_at
+                "#,
+            )
+            .bind(request.points)
+            .bind(user_id)
+            .fetch_optional(&mut **tx)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+            sqlx::query(
+                r#"
+                INSERT INTO user_contributions (
+                    user_id, contribution_type, entity_type, entity_id, action,
+                    new_value, points_awarded, status, reviewed_by, reviewed_at
+                )
+                VALUES ($1, 'points_adjustment', 'user', $1, $2, $3, $4, 'approved', $5, NOW())
+                "#,
+            )
+            .bind(user_id)
+            .bind(if request.points < 0 {
+                "deduct"
+            } else {
+                "award"
+            })
+            .bind(serde_json::json!({ "reason": request.reason }))
+            .bind(request.points)
+            .bind(awarded_by)
+            .execute(&mut **tx)
+            .await?;
 
-    Ok(UserResponse {
-        id: user_row.get("id"),
-        email: user_row.get("email"),
-        full_name: user_row.get("full_name"),
-        avatar_url: user_row.get("avatar_url"),
-        role: user_row.get("role"),
-        translation_points: user_row.get("translation_points"),
-        bio: user_row.get("bio"),
-        preferred_language: user_row.get("preferred_language"),
-        settings: user_row.get("settings"),
-        is_active: user_row.get("is_active"),
-        is_email_verified: user_row.get("is_email_verified"),
-        created_at: user_row.get("created_at"),
-        updated_at: user_row.get("updated_at"),
+            Ok(user_row)
+        })
     })
+    .await?;
+
+    Ok(user_from_row(&user_row))
 }
 
 /// Verify user email
@@ -435,19 +688,307 @@ pub async fn verify_email(pool: &PgPool, user_id: Uuid) -> AppResult<UserRespons
     .await?
     .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
-    Ok(UserResponse {
-        id: user_row.get("id"),
-        email: user_row.get("email"),
-        full_name: user_row.get("full_name"),
-        avatar_url: user_row.get("avatar_url"),
-        role: user_row.get("role"),
-        translation_points: user_row.get("translation_points"),
-        bio: user_row.get("bio"),
-        preferred_language: user_row.get("preferred_language"),
-        settings: user_row.get("settings"),
-        is_active: user_row.get("is_active"),
-        is_email_verified: user_row.get("is_email_verified"),
-        created_at: user_row.get("created_at"),
-        updated_at: user_row.get("updated_at"),
+    Ok(user_from_row(&user_row))
+}
+
+/// Recent activity of a user across contributions, translations and notifications
+pub async fn activity_feed(
+    pool: &PgPool,
+    user_id: Uuid,
+    limit: i64,
+) -> AppResult<Vec<ActivityItem>> {
+    let rows = sqlx::query(
+        r#"
+        SELECT kind, ref_id, summary, at FROM (
+            SELECT 'contribution' AS kind, id AS ref_id,
+                   contribution_type || ' ' || action || ' on ' || entity_type AS summary,
+                   created_at AS at
+            FROM user_contributions
+            WHERE user_id = $1
+            UNION ALL
+            SELECT 'translation' AS kind, id AS ref_id,
+                   'Translation (' || status || '): ' || LEFT(source_text, 80) AS summary,
+                   created_at AS at
+            FROM translation_requests
+            WHERE user_id = $1
+            UNION ALL
+            SELECT 'notification' AS kind, id AS ref_id, title AS summary, created_at AS at
+            FROM notifications
+            WHERE user_id = $1
+        ) activity
+        ORDER BY at DESC
+        LIMIT $2
+        "#,
+    )
+    .bind(user_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| ActivityItem {
+            kind: row.get("kind"),
+            ref_id: row.get("ref_id"),
+            summary: row.get("summary"),
+            at: row.get("at"),
+        })
+        .collect())
+}
+
+/// A user's dictionary entries, translations and contributions, newest first
+pub async fn list_submissions(
+    pool: &PgPool,
+    user_id: Uuid,
+    page: i64,
+    per_page: i64,
+) -> AppResult<PaginatedResponse<UserSubmission>> {
+    // 404 for unknown users rather than an empty page
+    get_user_by_id(pool, user_id).await?;
+
+    let offset = (page - 1) * per_page;
+
+    let rows = sqlx::query(
+        r#"
+        SELECT kind, ref_id, summary, status, created_at FROM (
+            SELECT 'dictionary_entry' AS kind, id AS ref_id,
+                   pnar_word || ': ' || english_word AS summary,
+                   CASE WHEN verified THEN 'verified' ELSE 'unverified' END AS status,
+                   created_at
+            FROM pnar_dictionary
+            WHERE created_by = $1
+            UNION ALL
+            SELECT 'translation' AS kind, id AS ref_id,
+                   LEFT(source_text, 80) AS summary, status, created_at
+            FROM translation_requests
+            WHERE user_id = $1
+            UNION ALL
+            SELECT 'contribution' AS kind, id AS ref_id,
+                   contribution_type || ' ' || action || ' on ' || entity_type AS summary,
+                   status, created_at
+            FROM user_contributions
+            WHERE user_id = $1
+        ) submissions
+        ORDER BY created_at DESC, ref_id
+        LIMIT $2 OFFSET $3
+        "#,
+    )
+    .bind(user_id)
+    .bind(per_page)
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
+
+    let items = rows
+        .into_iter()
+        .map(|row| UserSubmission {
+            kind: row.get("kind"),
+            ref_id: row.get("ref_id"),
+            summary: row.get("summary"),
+            status: row.get("status"),
+            created_at: row.get("created_at"),
+        })
+        .collect();
+
+    let total: i64 = sqlx::query(
+        r#"
+        SELECT (SELECT COUNT(*) FROM pnar_dictionary WHERE created_by = $1)
+             + (SELECT COUNT(*) FROM translation_requests WHERE user_id = $1)
+             + (SELECT COUNT(*) FROM user_contributions WHERE user_id = $1)
+        "#,
+    )
+    .bind(user_id)
+    .fetch_one(pool)
+    .await?
+    .get(0);
+
+    Ok(PaginatedResponse::new(items, page, per_page, total))
+}
+
+/// How a user's `translation_points` were earned, newest first.
+///
+/// Built from the contributions that credited points (impact awards and
+/// admin adjustments); adjustments made before they were recorded are not
+/// listed.
+pub async fn points_history(
+    pool: &PgPool,
+    user_id: Uuid,
+    page: i64,
+    per_page: i64,
+) -> AppResult<PaginatedResponse<PointsLedgerEntry>> {
+    let offset = (page - 1) * per_page;
+
+    let rows = sqlx::query(
+        r#"
+        SELECT id, contribution_type, action, new_value,
+               COALESCE(points_awarded, 0) AS points, created_at
+        FROM user_contributions
+        WHERE user_id = $1
+          AND contribution_type = ANY($2)
+          AND status = 'approved'
+        ORDER BY created_at DESC, id
+        LIMIT $3 OFFSET $4
+        "#,
+    )
+    .bind(user_id)
+    .bind(POINTS_LEDGER_TYPES)
+    .bind(per_page)
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
+
+    let items = rows
+        .into_iter()
+        .map(|row| PointsLedgerEntry {
+            contribution_id: row.get("id"),
+            source: row.get("contribution_type"),
+            action: row.get("action"),
+            points: row.get("points"),
+            details: row.get("new_value"),
+            created_at: row.get("created_at"),
+        })
+        .collect();
+
+    let total: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*)
+        FROM user_contributions
+        WHERE user_id = $1
+          AND contribution_type = ANY($2)
+          AND status = 'approved'
+        "#,
+    )
+    .bind(user_id)
+    .bind(POINTS_LEDGER_TYPES)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(PaginatedResponse::new(items, page, per_page, total))
+}
+
+/// Collect everything stored about a user into a single export.
+///
+/// Only the user's own records are included; emails of other users (reviewers,
+/// moderators) are left out.
+pub async fn export_user_data(pool: &PgPool, user_id: Uuid) -> AppResult<UserDataExport> {
+    let user = get_user_by_id(pool, user_id).await?;
+
+    let contributions = sqlx::query(
+        r#"
+        SELECT id, user_id, contribution_type, entity_type, entity_id, action,
+               previous_value, new_value, points_awarded, status, reviewed_by, reviewed_at,
+               created_at
+        FROM user_contributions
+        WHERE user_id = $1
+        ORDER BY created_at DESC
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|record| ContributionResponse {
+        id: record.get("id"),
+        user_id: record.get("user_id"),
+        contribution_type: record.get("contribution_type"),
+        entity_type: record.get("entity_type"),
+        entity_id: record.get("entity_id"),
+        action: record.get("action"),
+        previous_value: record.get("previous_value"),
+        new_value: record.get("new_value"),
+        points_awarded: record.get::<Option<i32>, _>("points_awarded").unwrap_or(0),
+        status: record.get("status"),
+        reviewed_by: record.get("reviewed_by"),
+        reviewed_by_email: None,
+        reviewed_at: record.get("reviewed_at"),
+        created_at: record.get("created_at"),
+    })
+    .collect();
+
+    let translations = sqlx::query(
+        r#"
+        SELECT id, user_id, source_text, source_language, target_language, translated_text,
+               status, translation_type, confidence_score::float8 AS confidence_score,
+               reviewed, reviewed_by, reviewed_at, COALESCE(metadata, '{}') AS metadata,
+               created_at, updated_at
+        FROM translation_requests
+        WHERE user_id = $1
+        ORDER BY created_at DESC
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|record| TranslationResponse {
+        id: record.get("id"),
+        user_id: record.get("user_id"),
+        created_by_email: Some(user.email.clone()),
+        source_text: record.get("source_text"),
+        source_language: record.get("source_language"),
+        target_language: record.get("target_language"),
+        translated_text: record.get("translated_text"),
+        status: record.get("status"),
+        translation_type: record.get("translation_type"),
+        confidence_score: record.get("confidence_score"),
+        reviewed: record.get("reviewed"),
+        reviewed_by: record.get("reviewed_by"),
+        reviewed_at: record.get("reviewed_at"),
+        metadata: record.get("metadata"),
+        created_at: record.get("created_at"),
+        updated_at: record.get("updated_at"),
+    })
+    .collect();
+
+    let dictionary_entries = sqlx::query(
+        r#"
+        SELECT id, pnar_word, english_word, part_of_speech, definition,
+               example_pnar, example_english, difficulty_level, usage_frequency,
+               cultural_context, related_words, pronunciation, etymology,
+               verified, created_at, updated_at, created_by, version
+        FROM pnar_dictionary
+        WHERE created_by = $1
+        ORDER BY created_at DESC
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|record| dictionary_service::entry_from_row(&record))
+    .collect();
+
+    let notifications = sqlx::query(
+        r#"
+        SELECT id, type, title, message, data, read, read_at, created_at, expires_at
+        FROM notifications
+        WHERE user_id = $1
+        ORDER BY created_at DESC
+        "#,
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|record| NotificationResponse {
+        id: record.get("id"),
+        notification_type: record.get("type"),
+        title: record.get("title"),
+        message: record.get("message"),
+        data: record.get("data"),
+        read: record.get("read"),
+        read_at: record.get("read_at"),
+        created_at: record.get("created_at"),
+        expires_at: record.get("expires_at"),
+    })
+    .collect();
+
+    Ok(UserDataExport {
+        user,
+        contributions,
+        translations,
+        dictionary_entries,
+        notifications,
+        exported_at: Utc::now(),
     })
 }
@@ -1,15 +1,37 @@
 use crate::{
-    config::Settings, database::create_connection_pool, error::AppResult, handlers,
-    middleware::auth::AuthMiddleware, openapi::ApiDoc,
+    config::Settings,
+    database::{self, create_connection_pool, Readiness},
+    error::{AppError, AppResult},
+    handlers, metrics,
+    middleware::{
+        auth::AuthMiddleware,
+        concurrency::UserConcurrencyLimiter,
+        content_negotiation::ErrorContentNegotiation,
+        json_guard::JsonBodyGuard,
+        maintenance::{MaintenanceMiddleware, MaintenanceMode},
+        pretty_json::PrettyJson,
+        rate_limit::{RateLimiter, SoftRateLimit},
+        trace_context::TraceContextMiddleware,
+        transaction::TransactionMiddleware,
+    },
+    openapi::ApiDoc,
+    services::{
+        analytics_service,
+        dictionary_service::{
+            self, DictionaryExportLimiter, DictionaryStatsCache, VerifiedEntryFeed,
+        },
+        notification_service,
+    },
 };
 use actix_cors::Cors;
 use actix_web::{
     middleware::{Logger, NormalizePath},
     web, App, HttpServer,
 };
+use serde::Serialize;
 use sqlx::PgPool;
-use std::net::TcpListener;
-use tracing::info;
+use std::{net::TcpListener, time::Duration};
+use tracing::{error, info, warn};
 use tracing_actix_web::TracingLogger;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
@@ -21,7 +43,18 @@ pub struct Application {
 
 impl Application {
     pub async fn build(settings: Settings) -> AppResult<Self> {
-        let connection_pool = create_connection_pool(&settings.database).await?;
+        metrics::mark_started();
+
+        let connection_pool =
+            create_connection_pool(&settings.database, &settings.monitoring).await?;
+
+        startup_self_check(&connection_pool, &settings).await?;
+
+        // create_connection_pool has warmed the pool by now
+        let readiness = Readiness::new(Duration::from_secs(
+            settings.application.readiness_grace_seconds,
+        ));
+        readiness.mark_warmed_up();
 
         let address = format!(
             "{}:{}",
@@ -30,7 +63,19 @@ impl Application {
         let listener = TcpListener::bind(&address)?;
         let port = listener.local_addr().unwrap().port();
 
-        let server = run(listener, connection_pool, settings)?;
+        if settings.analytics.impact.enabled {
+            spawn_impact_points_job(connection_pool.clone(), &settings);
+        }
+
+        if settings.dictionary.lookup_reconcile_interval_seconds > 0 {
+            spawn_lookup_reconcile_job(connection_pool.clone(), &settings);
+        }
+
+        if settings.notifications.digest_interval_seconds > 0 {
+            spawn_notification_digest_job(connection_pool.clone(), &settings);
+        }
+
+        let server = run(listener, connection_pool, readiness, settings)?;
 
         Ok(Self { port, server })
     }
@@ -44,28 +89,243 @@ impl Application {
     }
 }
 
+/// Periodically settle impact points for the last completed analytics window
+fn spawn_impact_points_job(pool: PgPool, settings: &Settings) {
+    let impact = settings.analytics.impact.clone();
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(Duration::from_secs(impact.interval_seconds.max(1)));
+
+        loop {
+            ticker.tick().await;
+            match analytics_service::award_impact_points(&pool, &impact).await {
+                Ok(0) => {}
+                Ok(awarded) => info!("Awarded impact points to {} creators", awarded),
+                Err(e) => warn!("Impact points job failed: {}", e),
+            }
+        }
+    });
+}
+
+/// Periodically correct lookup counters that drifted from the analytics table
+fn spawn_lookup_reconcile_job(pool: PgPool, settings: &Settings) {
+    let interval = Duration::from_secs(settings.dictionary.lookup_reconcile_interval_seconds);
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+            match dictionary_service::reconcile_lookup_counts(&pool).await {
+                Ok(0) => {}
+                Ok(corrected) => info!("Reconciled lookup counts for {} entries", corrected),
+                Err(e) => warn!("Lookup count reconciliation failed: {}", e),
+            }
+        }
+    });
+}
+
+/// Periodically send each user a digest of notifications left unread since
+/// the previous run. The first run waits a full interval so restarts don't
+/// trigger an extra round of digests.
+fn spawn_notification_digest_job(pool: PgPool, settings: &Settings) {
+    let interval = Duration::from_secs(settings.notifications.digest_interval_seconds);
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+
+        loop {
+            ticker.tick().await;
+            let since = chrono::Utc::now()
+                - chrono::Duration::from_std(interval).unwrap_or(chrono::Duration::days(1));
+            match notification_service::send_digests(&pool, since).await {
+                Ok(0) => {}
+                Ok(sent) => info!("Sent {} notification digests", sent),
+                Err(e) => warn!("Notification digest run failed: {}", e),
+            }
+        }
+    });
+}
+
+/// Outcome of a single startup self-check
+#[derive(Debug, Serialize)]
+struct SelfCheck {
+    name: &'static str,
+    passed: bool,
+    critical: bool,
+    detail: String,
+}
+
+impl SelfCheck {
+    fn new(name: &'static str, critical: bool, outcome: Result<String, String>) -> Self {
+        let (passed, detail) = match outcome {
+            Ok(detail) => (true, detail),
+            Err(detail) => (false, detail),
+        };
+
+        Self {
+            name,
+            passed,
+            critical,
+            detail,
+        }
+    }
+}
+
+/// Verify the database and configuration are fit to serve traffic.
+///
+/// Every check is logged as one structured JSON report; startup is aborted when
+/// any critical check fails.
+async fn startup_self_check(pool: &PgPool, settings: &Settings) -> AppResult<()> {
+    let tables = match database::check_database_readiness(pool).await {
+        Ok(missing) if missing.is_empty() => Ok("all required tables present".to_string()),
+        Ok(missing) => Err(format!("missing tables: {}", missing.join(", "))),
+        Err(e) => Err(format!("could not inspect schema: {}", e)),
+    };
+
+    let migrations = match database::pending_migrations(pool).await {
+        Ok(pending) if pending.is_empty() => Ok("all migrations applied".to_string()),
+        Ok(pending) => Err(format!(
+            "pending migrations: {}",
+            pending
+                .iter()
+                .map(|version| version.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+        Err(e) => Err(format!("could not read migration history: {}", e)),
+    };
+
+    let open_connections = pool.size();
+    let pool_warm = if open_connections >= settings.database.min_connections {
+        Ok(format!("{} connections open", open_connections))
+    } else {
+        Err(format!(
+            "{} of {} min_connections open",
+            open_connections, settings.database.min_connections
+        ))
+    };
+
+    let pool_bounds = if settings.database.max_connections == 0 {
+        Err("max_connections must be greater than 0".to_string())
+    } else if settings.database.min_connections > settings.database.max_connections {
+        Err("min_connections exceeds max_connections".to_string())
+    } else {
+        Ok("pool bounds are consistent".to_string())
+    };
+
+    let jwt_secret = match std::env::var("JWT_SECRET") {
+        Ok(secret) if !secret.is_empty() => Ok("JWT_SECRET is set".to_string()),
+        _ => Err(
+            "JWT_SECRET is not set; falling back to the built-in development secret".to_string(),
+        ),
+    };
+
+    let password_hashing = match crate::utils::password::hasher(&settings.security) {
+        Ok(_) => Ok("Argon2 parameters are valid".to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+
+    let checks = [
+        SelfCheck::new("tables", true, tables),
+        SelfCheck::new("migrations", true, migrations),
+        SelfCheck::new("pool_warm", false, pool_warm),
+        SelfCheck::new("pool_config", true, pool_bounds),
+        SelfCheck::new("jwt_secret", false, jwt_secret),
+        SelfCheck::new("password_hashing", true, password_hashing),
+    ];
+
+    let report = serde_json::to_string(&checks)
+        .map_err(|e| AppError::Internal(format!("Failed to serialize self-check: {}", e)))?;
+    info!(self_check = %report, "Startup self-check");
+
+    let failures: Vec<String> = checks
+        .iter()
+        .filter(|check| check.critical && !check.passed)
+        .map(|check| format!("{} ({})", check.name, check.detail))
+        .collect();
+
+    if !failures.is_empty() {
+        error!("Startup self-check failed: {}", failures.join("; "));
+        return Err(AppError::Internal(format!(
+            "Startup self-check failed: {}",
+            failures.join("; ")
+        )));
+    }
+
+    for check in checks.iter().filter(|check| !check.passed) {
+        warn!(
+            "Startup self-check warning: {} ({})",
+            check.name, check.detail
+        );
+    }
+
+    Ok(())
+}
+
 fn run(
     listener: TcpListener,
     db_pool: PgPool,
+    readiness: Readiness,
     settings: Settings,
 ) -> AppResult<actix_web::dev::Server> {
     let db_pool = web::Data::new(db_pool);
+    let readiness = web::Data::new(readiness);
     let settings_data = web::Data::new(settings.clone());
+    let stats_cache = web::Data::new(DictionaryStatsCache::new(Duration::from_secs(
+        settings.dictionary.stats_cache_ttl_seconds,
+    )));
+    let verified_feed = web::Data::new(VerifiedEntryFeed::new());
+    let export_limiter = web::Data::new(DictionaryExportLimiter::new(
+        settings.dictionary.max_concurrent_exports,
+        Duration::from_secs(settings.dictionary.export_statement_timeout_seconds),
+    ));
+    let cors_settings = validate_cors_settings(&settings.application.cors);
+    let maintenance = web::Data::new(MaintenanceMode::new(&settings.maintenance));
+    let rate_limiter = settings
+        .rate_limit
+        .enabled
+        .then(|| web::Data::new(RateLimiter::new(&settings.rate_limit)));
+    let concurrency_limiter = (settings.rate_limit.max_concurrent_per_user > 0).then(|| {
+        web::Data::new(UserConcurrencyLimiter::new(
+            settings.rate_limit.max_concurrent_per_user,
+        ))
+    });
 
     let server = HttpServer::new(move || {
-        let _cors = configure_cors(&settings.application.cors);
+        let cors = configure_cors(&cors_settings);
         let openapi = ApiDoc::openapi();
 
-        App::new()
-            .app_data(db_pool.clone())
+        let mut app = App::new();
+        if let Some(rate_limiter) = &rate_limiter {
+            app = app.app_data(rate_limiter.clone());
+        }
+        if let Some(concurrency_limiter) = &concurrency_limiter {
+            app = app.app_data(concurrency_limiter.clone());
+        }
+
+        app.app_data(db_pool.clone())
             .app_data(settings_data.clone())
-            .wrap(Cors::permissive())
+            .app_data(stats_cache.clone())
+            .app_data(verified_feed.clone())
+            .app_data(export_limiter.clone())
+            .app_data(maintenance.clone())
+            .app_data(readiness.clone())
+            .wrap(TransactionMiddleware)
+            .wrap(JsonBodyGuard::new(settings.application.json_limits.clone()))
+            .wrap(MaintenanceMiddleware)
+            .wrap(ErrorContentNegotiation)
+            .wrap(TraceContextMiddleware)
+            .wrap(PrettyJson::new(settings.application.pretty_json))
+            .wrap(SoftRateLimit)
+            .wrap(cors)
             .wrap(TracingLogger::default())
             .wrap(Logger::default())
             .wrap(NormalizePath::trim())
             .service(
                 SwaggerUi::new("/swagger-ui/{_:.*}").url("/api-doc/openapi.json", openapi.clone()),
             )
+            .service(handlers::metrics::get_metrics)
             .route(
                 "/docs",
                 web::get().to(|| async {
@@ -77,6 +337,25 @@ fn run(
             .service(
                 web::scope("/api/v1")
                     .service(handlers::health::health_check)
+                    .service(handlers::health::ping)
+                    .service(handlers::health::ready)
+                    .service(
+                        web::scope("/notifications")
+                            .wrap(AuthMiddleware)
+                            .service(handlers::notification::send_digest),
+                    )
+                    .service(
+                        web::scope("/admin")
+                            .wrap(AuthMiddleware)
+                            .service(handlers::admin::dashboard)
+                            .service(handlers::admin::db_activity)
+                            .service(handlers::admin::cancel_db_query)
+                            .service(handlers::admin::get_maintenance_mode)
+                            .service(handlers::admin::set_maintenance_mode)
+                            .service(handlers::admin::reindex)
+                            .service(handlers::admin::export)
+                            .service(handlers::admin::import),
+                    )
                     .service(
                         web::scope("/auth")
                             .service(handlers::auth::register)
@@ -93,15 +372,23 @@ fn run(
                             web::scope("")
                                 .wrap(AuthMiddleware)
                                 .service(handlers::user::create_user)
+                                .service(handlers::user::create_users_batch)
+                                .service(handlers::user::bulk_update_roles)
                                 .service(handlers::user::list_users)
                                 .service(handlers::user::get_user_by_email)
                                 .service(handlers::user::get_current_user)
+                                .service(handlers::user::get_current_user_activity)
+                                .service(handlers::user::get_current_user_points)
+                                .service(handlers::user::export_current_user_data)
                                 .service(handlers::user::update_current_user)
                                 .service(handlers::user::update_current_user_password)
                                 .service(handlers::user::delete_current_user)
+                                .service(handlers::user::export_user_data)
+                                .service(handlers::user::list_user_submissions)
                                 .service(handlers::user::get_user)
                                 .service(handlers::user::update_user)
                                 .service(handlers::user::update_user_password)
+                                .service(handlers::user::purge_user)
                                 .service(handlers::user::delete_user)
                                 .service(handlers::user::award_points)
                                 .service(handlers::user::verify_email),
@@ -109,14 +396,34 @@ fn run(
                     )
                     .service(
                         web::scope("/dictionary")
-                            .wrap(AuthMiddleware)
-                            .service(handlers::dictionary::create_entry)
-                            .service(handlers::dictionary::get_entry)
-                            .service(handlers::dictionary::list_entries)
-                            .service(handlers::dictionary::search_entries)
-                            .service(handlers::dictionary::update_entry)
-                            .service(handlers::dictionary::delete_entry)
-                            .service(handlers::dictionary::verify_entry),
+                            .service(handlers::dictionary::suggest_entry)
+                            .service(handlers::dictionary::recently_verified)
+                            .service(handlers::dictionary::export_entries)
+                            .service(
+                                web::scope("")
+                                    .wrap(AuthMiddleware)
+                                    .service(handlers::dictionary::create_entry)
+                                    .service(handlers::dictionary::validate_entry)
+                                    .service(handlers::dictionary::import_csv)
+                                    .service(handlers::dictionary::get_stats)
+                                    .service(handlers::dictionary::get_freshness)
+                                    .service(handlers::dictionary::list_missing_field)
+                                    .service(handlers::dictionary::stream_verified_entries)
+                                    .service(handlers::dictionary::list_suggestions)
+                                    .service(handlers::dictionary::approve_suggestion)
+                                    .service(handlers::dictionary::get_entry_by_word)
+                                    .service(handlers::dictionary::batch_get_entries)
+                                    .service(handlers::dictionary::get_entry)
+                                    .service(handlers::dictionary::list_entries)
+                                    .service(handlers::dictionary::search_entries)
+                                    .service(handlers::dictionary::search_count)
+                                    .service(handlers::dictionary::update_entry)
+                                    .service(handlers::dictionary::patch_entry)
+                                    .service(handlers::dictionary::delete_entry)
+                                    .service(handlers::dictionary::verify_entry)
+                                    .service(handlers::dictionary::reassign_entry)
+                                    .service(handlers::dictionary::merge_entries),
+                            ),
                     )
                     .service(
                         web::scope("/translations")
@@ -134,6 +441,10 @@ fn run(
                                 "/{id}",
                                 web::put().to(handlers::translation::update_translation),
                             )
+                            .route(
+                                "/{id}/draft",
+                                web::patch().to(handlers::translation::save_translation_draft),
+                            )
                             .route(
                                 "/{id}",
                                 web::delete().to(handlers::translation::delete_translation),
@@ -150,6 +461,11 @@ fn run(
                                 "",
                                 web::get().to(handlers::contribution::list_contributions),
                             )
+                            .route(
+                                "/all",
+                                web::get()
+                                    .to(handlers::contribution::list_contributions_for_review),
+                            )
                             .route(
                                 "/{id}",
                                 web::get().to(handlers::contribution::get_contribution),
@@ -203,10 +519,32 @@ fn run(
     Ok(server)
 }
 
+/// Check the CORS settings once before workers start, so misconfiguration is
+/// reported a single time rather than once per worker.
+///
+/// Browsers reject credentialed responses when the allowed origin is a
+/// wildcard, so credentials are disabled whenever the two are combined.
+fn validate_cors_settings(
+    cors_settings: &crate::config::CorsSettings,
+) -> crate::config::CorsSettings {
+    let mut cors_settings = cors_settings.clone();
+
+    if cors_settings.allow_credentials && cors_settings.allowed_origins.iter().any(|o| o == "*") {
+        warn!(
+            "CORS allow_credentials is ignored because allowed_origins contains \"*\"; \
+             list explicit origins to enable credentialed requests"
+        );
+        cors_settings.allow_credentials = false;
+    }
+
+    cors_settings
+}
+
 fn configure_cors(cors_settings: &crate::config::CorsSettings) -> Cors {
     let mut cors = Cors::default();
+    let any_origin = cors_settings.allowed_origins.contains(&"*".to_string());
 
-    if cors_settings.allowed_origins.contains(&"*".to_string()) {
+    if any_origin {
         cors = cors.allow_any_origin();
     } else {
         for origin in &cors_settings.allowed_origins {
@@ -221,11 +559,21 @@ fn configure_cors(cors_settings: &crate::config::CorsSettings) -> Cors {
         .collect();
     let mut cors = cors.allowed_methods(method_strs);
 
-    for header in &cors_settings.allowed_headers {
-        cors = cors.allowed_header(header.as_str());
+    if cors_settings.allowed_headers.contains(&"*".to_string()) {
+        cors = cors.allow_any_header();
+    } else {
+        for header in &cors_settings.allowed_headers {
+            cors = cors.allowed_header(header.as_str());
+        }
+    }
+
+    if !cors_settings.exposed_headers.is_empty() {
+        cors = cors.expose_headers(cors_settings.exposed_headers.iter().map(String::as_str));
     }
 
-    if cors_settings.allow_credentials {
+    // validate_cors_settings already disabled credentials for wildcard
+    // origins; keep the guard so the two are never combined.
+    if cors_settings.allow_credentials && !any_origin {
         cors = cors.supports_credentials();
     }
 
@@ -260,3 +608,90 @@ pub fn init_tracing(settings: &crate::config::LoggingSettings) -> AppResult<()>
     info!("Tracing initialized with level: {}", settings.level);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CorsSettings;
+    use actix_web::{
+        http::header,
+        test::{call_service, init_service, TestRequest},
+        HttpResponse,
+    };
+
+    fn cors_settings(allowed_origins: &[&str], allow_credentials: bool) -> CorsSettings {
+        CorsSettings {
+            allowed_origins: allowed_origins.iter().map(|o| o.to_string()).collect(),
+            allowed_methods: vec!["GET".to_string()],
+            allowed_headers: vec!["*".to_string()],
+            exposed_headers: Vec::new(),
+            allow_credentials,
+        }
+    }
+
+    async fn allow_credentials_header(settings: &CorsSettings) -> Option<String> {
+        let app = init_service(
+            App::new()
+                .wrap(configure_cors(&validate_cors_settings(settings)))
+                .route("/", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+        let req = TestRequest::get()
+            .uri("/")
+            .insert_header((header::ORIGIN, "https://app.example.com"))
+            .to_request();
+        let res = call_service(&app, req).await;
+
+        res.headers()
+            .get(header::ACCESS_CONTROL_ALLOW_CREDENTIALS)
+            .map(|value| value.to_str().unwrap().to_string())
+    }
+
+    #[test]
+    fn wildcard_origin_disables_credentials() {
+        let settings = validate_cors_settings(&cors_settings(&["*"], true));
+
+        assert!(!settings.allow_credentials);
+    }
+
+    #[actix_web::test]
+    async fn wildcard_origin_never_sends_credentials_header() {
+        let header = allow_credentials_header(&cors_settings(&["*"], true)).await;
+
+        assert_eq!(header, None);
+    }
+
+    #[actix_web::test]
+    async fn explicit_origin_keeps_credentials() {
+        let settings = cors_settings(&["https://app.example.com"], true);
+
+        assert_eq!(
+            allow_credentials_header(&settings).await.as_deref(),
+            Some("true")
+        );
+    }
+
+    #[actix_web::test]
+    async fn default_cors_exposes_the_request_id_header() {
+        let settings = crate::config::get_configuration().unwrap().application.cors;
+        let app = init_service(
+            App::new()
+                .wrap(configure_cors(&validate_cors_settings(&settings)))
+                .route("/", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+        let req = TestRequest::get()
+            .uri("/")
+            .insert_header((header::ORIGIN, "http://localhost:3000"))
+            .to_request();
+
+        let res = call_service(&app, req).await;
+
+        let exposed = res
+            .headers()
+            .get(header::ACCESS_CONTROL_EXPOSE_HEADERS)
+            .map(|value| value.to_str().unwrap().to_lowercase())
+            .unwrap_or_default();
+        assert!(exposed.contains("x-request-id"), "{:?}", exposed);
+    }
+}
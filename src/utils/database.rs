@@ -0,0 +1,28 @@
+use crate::error::AppResult;
+use futures_util::future::BoxFuture;
+use sqlx::{PgPool, Postgres, Transaction};
+use tracing::warn;
+
+/// Runs `f` inside a database transaction.
+///
+/// The transaction is committed when `f` returns `Ok` and rolled back when it
+/// returns `Err`, so multi-statement service operations never leave partial state.
+pub async fn with_transaction<T, F>(pool: &PgPool, f: F) -> AppResult<T>
+where
+    F: for<'c> FnOnce(&'c mut Transaction<'static, Postgres>) -> BoxFuture<'c, AppResult<T>>,
+{
+    let mut tx = pool.begin().await?;
+
+    match f(&mut tx).await {
+        Ok(value) => {
+            tx.commit().await?;
+            Ok(value)
+        }
+        Err(e) => {
+            if let Err(rollback_err) = tx.rollback().await {
+                warn!("Failed to roll back transaction: {}", rollback_err);
+            }
+            Err(e)
+        }
+    }
+}
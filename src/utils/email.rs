@@ -0,0 +1,8 @@
+/// Canonical form used to store and look up account emails.
+///
+/// Surrounding whitespace is dropped and the whole address, local part
+/// included, is lowercased, so "User@Example.com" and "user@example.com" are
+/// the same account.
+pub fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
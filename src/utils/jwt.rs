@@ -1,6 +1,12 @@
-use crate::error::AppError;
+use crate::{
+    config::JwtSettings,
+    error::AppError,
+    metrics::{self, AuthFailureReason},
+};
 use chrono::{Duration, Utc};
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use jsonwebtoken::{
+    decode, encode, errors::ErrorKind, DecodingKey, EncodingKey, Header, Validation,
+};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -9,10 +15,12 @@ pub struct Claims {
     pub sub: String, // Subject (user ID)
     pub exp: i64,    // Expiry time
     pub iat: i64,    // Issued at
+    pub iss: String, // Issuer
+    pub aud: String, // Audience
 }
 
 impl Claims {
-    pub fn new(user_id: Uuid) -> Self {
+    pub fn new(user_id: Uuid, settings: &JwtSettings) -> Self {
         let now = Utc::now();
         let expiry = now + Duration::hours(24);
 
@@ -20,6 +28,8 @@ impl Claims {
             sub: user_id.to_string(),
             exp: expiry.timestamp(),
             iat: now.timestamp(),
+            iss: settings.issuer.clone(),
+            aud: settings.audience.clone(),
         }
     }
 
@@ -34,8 +44,8 @@ fn get_jwt_secret() -> Result<String, AppError> {
         .unwrap_or_else(|_| "your-secret-key-here-change-me-in-production".to_string()))
 }
 
-pub fn generate_token(user_id: Uuid) -> Result<String, AppError> {
-    let claims = Claims::new(user_id);
+pub fn generate_token(user_id: Uuid, settings: &JwtSettings) -> Result<String, AppError> {
+    let claims = Claims::new(user_id, settings);
     let secret = get_jwt_secret()?;
 
     encode(
@@ -46,7 +56,7 @@ pub fn generate_token(user_id: Uuid) -> Result<String, AppError> {
     .map_err(|e| AppError::Internal(format!("Failed to generate token: {}", e)))
 }
 
-pub fn generate_refresh_token(user_id: Uuid) -> Result<String, AppError> {
+pub fn generate_refresh_token(user_id: Uuid, settings: &JwtSettings) -> Result<String, AppError> {
     let now = Utc::now();
     let expiry = now + Duration::days(30); // 30 days for refresh token
     let secret = get_jwt_secret()?;
@@ -55,6 +65,8 @@ pub fn generate_refresh_token(user_id: Uuid) -> Result<String, AppError> {
         sub: user_id.to_string(),
         exp: expiry.timestamp(),
         iat: now.timestamp(),
+        iss: settings.issuer.clone(),
+        aud: settings.audience.clone(),
     };
 
     encode(
@@ -65,14 +77,126 @@ pub fn generate_refresh_token(user_id: Uuid) -> Result<String, AppError> {
     .map_err(|e| AppError::Internal(format!("Failed to generate refresh token: {}", e)))
 }
 
-pub fn verify_token(token: &str) -> Result<Claims, AppError> {
+pub fn verify_token(token: &str, settings: &JwtSettings) -> Result<Claims, AppError> {
     let secret = get_jwt_secret()?;
-    
+
+    let mut validation = Validation::default();
+    validation.set_issuer(&[&settings.issuer]);
+    validation.set_audience(&[&settings.audience]);
+    validation.leeway = settings.leeway_seconds;
+
     decode::<Claims>(
         token,
         &DecodingKey::from_secret(secret.as_ref()),
-        &Validation::default(),
+        &validation,
     )
     .map(|data| data.claims)
-    .map_err(|e| AppError::Unauthorized(format!("Invalid token: {}", e)))
+    .map_err(|e| {
+        metrics::record_auth_failure(match e.kind() {
+            ErrorKind::ExpiredSignature => AuthFailureReason::Expired,
+            ErrorKind::InvalidSignature => AuthFailureReason::InvalidSignature,
+            _ => AuthFailureReason::InvalidToken,
+        });
+        match e.kind() {
+            ErrorKind::InvalidIssuer => AppError::Unauthorized(format!(
+                "Invalid token: issuer does not match '{}'",
+                settings.issuer
+            )),
+            ErrorKind::InvalidAudience => AppError::Unauthorized(format!(
+                "Invalid token: audience does not match '{}'",
+                settings.audience
+            )),
+            _ => AppError::Unauthorized(format!("Invalid token: {}", e)),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secrecy::Secret;
+
+    fn settings(audience: &str) -> JwtSettings {
+        JwtSettings {
+            secret: Secret::new("unused".to_string()),
+            expires_in_minutes: 60,
+            refresh_expires_in_days: 7,
+            cookie_name: "auth_token".to_string(),
+            cookie_domain: None,
+            cookie_secure: false,
+            cookie_auth: false,
+            issuer: "pnar-world-api".to_string(),
+            audience: audience.to_string(),
+            leeway_seconds: 30,
+        }
+    }
+
+    #[test]
+    fn token_round_trips_with_matching_audience() {
+        let settings = settings("pnar-world");
+        let user_id = Uuid::new_v4();
+        let token = generate_token(user_id, &settings).unwrap();
+
+        let claims = verify_token(&token, &settings).unwrap();
+
+        assert_eq!(claims.user_id().unwrap(), user_id);
+    }
+
+    #[test]
+    fn token_for_another_audience_is_rejected() {
+        let token = generate_token(Uuid::new_v4(), &settings("pnar-world-staging")).unwrap();
+
+        let err = verify_token(&token, &settings("pnar-world")).unwrap_err();
+
+        assert!(
+            matches!(&err, AppError::Unauthorized(message) if message.contains("audience does not match 'pnar-world'")),
+            "unexpected error: {:?}",
+            err
+        );
+    }
+
+    fn token_expiring_at(exp: i64, settings: &JwtSettings) -> String {
+        let claims = Claims {
+            exp,
+            ..Claims::new(Uuid::new_v4(), settings)
+        };
+
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(get_jwt_secret().unwrap().as_ref()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn token_expired_within_leeway_still_verifies() {
+        let settings = settings("pnar-world");
+        let token = token_expiring_at(Utc::now().timestamp() - 10, &settings);
+
+        assert!(verify_token(&token, &settings).is_ok());
+    }
+
+    #[test]
+    fn token_expired_beyond_leeway_is_rejected() {
+        let settings = settings("pnar-world");
+        let token = token_expiring_at(Utc::now().timestamp() - 60, &settings);
+
+        assert!(matches!(
+            verify_token(&token, &settings),
+            Err(AppError::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn expired_token_is_counted_as_an_expired_auth_failure() {
+        let settings = settings("pnar-world");
+        let token = token_expiring_at(Utc::now().timestamp() - 60, &settings);
+        let before = metrics::auth_failures(AuthFailureReason::Expired);
+
+        let _ = verify_token(&token, &settings);
+
+        // Other tests may bump the shared counter concurrently
+        assert!(metrics::auth_failures(AuthFailureReason::Expired) > before);
+    }
 }
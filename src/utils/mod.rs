@@ -1 +1,5 @@
+pub mod database;
+pub mod email;
 pub mod jwt;
+pub mod moderation;
+pub mod password;
@@ -0,0 +1,63 @@
+//! Blocklist screening for user-submitted text.
+//!
+//! Matches only flag a submission for moderator attention; nothing is
+//! rejected automatically.
+
+use serde_json::Value;
+
+/// Case-insensitive list of blocked words and phrases.
+///
+/// Terms match whole words only, so a blocked "ass" does not flag "class".
+#[derive(Debug, Clone)]
+pub struct Blocklist {
+    terms: Vec<String>,
+}
+
+impl Blocklist {
+    pub fn new(terms: &[String]) -> Self {
+        Self {
+            terms: terms
+                .iter()
+                .map(|term| normalize(term))
+                .filter(|term| !term.trim().is_empty())
+                .collect(),
+        }
+    }
+
+    /// Blocked terms found in any of `texts`, in blocklist order
+    pub fn matches<'a>(&self, texts: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+        if self.terms.is_empty() {
+            return Vec::new();
+        }
+
+        let texts: Vec<String> = texts.into_iter().map(normalize).collect();
+
+        self.terms
+            .iter()
+            .filter(|term| texts.iter().any(|text| text.contains(term.as_str())))
+            .map(|term| term.trim().to_string())
+            .collect()
+    }
+}
+
+/// Lowercase and reduce to space-separated words with a leading and trailing
+/// space, so substring checks only match on word boundaries
+fn normalize(text: &str) -> String {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+
+    format!(" {} ", words.join(" "))
+}
+
+/// Every string inside a JSON value, e.g. a contribution's `new_value`
+pub fn json_strings(value: &Value) -> Vec<&str> {
+    match value {
+        Value::String(text) => vec![text.as_str()],
+        Value::Array(items) => items.iter().flat_map(json_strings).collect(),
+        Value::Object(fields) => fields.values().flat_map(json_strings).collect(),
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    config::SecuritySettings,
+    error::{AppError, AppResult},
+};
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Algorithm, Argon2, Params, Version,
+};
+
+/// Build an Argon2id hasher with the configured cost parameters
+pub fn hasher(settings: &SecuritySettings) -> AppResult<Argon2<'static>> {
+    let params = Params::new(
+        settings.argon2_memory_kib,
+        settings.argon2_iterations,
+        settings.argon2_parallelism,
+        None,
+    )
+    .map_err(|e| AppError::Internal(format!("Invalid Argon2 parameters: {}", e)))?;
+
+    Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+}
+
+/// Hash a plain-text password with the configured Argon2 parameters
+pub fn hash_password(settings: &SecuritySettings, password: &str) -> AppResult<String> {
+    let salt = SaltString::generate(&mut OsRng);
+
+    Ok(hasher(settings)?
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|e| AppError::Internal(format!("Failed to hash password: {}", e)))?
+        .to_string())
+}
+
+/// Check a password against a stored hash.
+///
+/// The cost parameters are read from the hash itself, so hashes created under
+/// earlier settings keep verifying after the configuration changes.
+pub fn verify_password(
+    settings: &SecuritySettings,
+    password: &str,
+    password_hash: &str,
+) -> AppResult<bool> {
+    let parsed_hash = PasswordHash::new(password_hash)
+        .map_err(|e| AppError::Internal(format!("Failed to parse password hash: {}", e)))?;
+
+    Ok(hasher(settings)?
+        .verify_password(password.as_bytes(), &parsed_hash)
+        .is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn security(memory_kib: u32, iterations: u32, parallelism: u32) -> SecuritySettings {
+        SecuritySettings {
+            argon2_memory_kib: memory_kib,
+            argon2_iterations: iterations,
+            argon2_parallelism: parallelism,
+        }
+    }
+
+    #[test]
+    fn hash_under_custom_params_verifies() {
+        let settings = security(8, 1, 1);
+
+        let hash = hash_password(&settings, "correct-horse").unwrap();
+
+        assert!(hash.contains("m=8,t=1,p=1"));
+        assert!(verify_password(&settings, "correct-horse", &hash).unwrap());
+        assert!(!verify_password(&settings, "wrong-horse", &hash).unwrap());
+    }
+
+    #[test]
+    fn hash_verifies_after_params_change() {
+        let hash = hash_password(&security(8, 1, 1), "correct-horse").unwrap();
+
+        assert!(verify_password(&security(16, 2, 1), "correct-horse", &hash).unwrap());
+    }
+
+    #[test]
+    fn invalid_params_are_rejected() {
+        assert!(hasher(&security(8, 0, 1)).is_err());
+    }
+}
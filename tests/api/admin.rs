@@ -0,0 +1,248 @@
+use crate::helpers::{insert_entry, insert_user, spawn_db};
+use actix_web::{
+    http::{header, StatusCode},
+    test::{call_service, init_service, read_body, TestRequest},
+    web, App,
+};
+use futures_util::TryStreamExt;
+use pnar_world_api::{
+    handlers,
+    middleware::auth::AuthMiddleware,
+    services::backup_service::{self, ArchiveImporter, BACKUP_TABLES},
+    utils::jwt,
+};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn reindex_requires_an_admin() {
+    let db = spawn_db().await;
+    let moderator_id = insert_user(&db.pool, "mod@example.com", "moderator").await;
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/admin")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::admin::reindex),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(moderator_id, &db.settings.jwt).unwrap();
+
+    let res = call_service(
+        &app,
+        TestRequest::post()
+            .uri("/api/v1/admin/reindex")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn export_manifest_counts_match_the_seeded_rows() {
+    let db = spawn_db().await;
+    let admin_id = insert_user(&db.pool, "backup@example.com", "admin").await;
+    insert_user(&db.pool, "reader@example.com", "user").await;
+    insert_entry(&db.pool, "kyntiewbackup", Some(admin_id)).await;
+    insert_entry(&db.pool, "kyntiewarchive", None).await;
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/admin")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::admin::export),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(admin_id, &db.settings.jwt).unwrap();
+
+    let res = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/api/v1/admin/export")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    let body = read_body(res).await;
+    let mut lines = std::str::from_utf8(&body)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap());
+    let manifest = lines.next().unwrap();
+    assert_eq!(manifest["type"], "manifest");
+    let mut exported: HashMap<String, i64> = HashMap::new();
+    for row in lines {
+        assert!(row["data"].get("password").is_none());
+        *exported
+            .entry(row["table"].as_str().unwrap().to_string())
+            .or_default() += 1;
+    }
+    for table in BACKUP_TABLES {
+        let seeded: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {}", table.name))
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(manifest["counts"][table.name], seeded, "{}", table.name);
+        assert_eq!(exported.get(table.name).copied().unwrap_or(0), seeded);
+    }
+}
+
+#[tokio::test]
+async fn import_into_an_empty_database_restores_every_entry() {
+    let source = spawn_db().await;
+    let author_id = insert_user(&source.pool, "author@example.com", "user").await;
+    insert_entry(&source.pool, "kyntiewrestored", Some(author_id)).await;
+    insert_entry(&source.pool, "kyntiewrecovered", None).await;
+    let exported_entries: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM pnar_dictionary")
+        .fetch_one(&source.pool)
+        .await
+        .unwrap();
+    let archive: Vec<String> = backup_service::export_archive(&source.pool)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+
+    let target = spawn_db().await;
+    sqlx::query("TRUNCATE pnar_dictionary, users CASCADE")
+        .execute(&target.pool)
+        .await
+        .unwrap();
+    let mut importer = ArchiveImporter::begin(&target.pool, "unusable".to_string())
+        .await
+        .unwrap();
+    for line in archive.concat().lines() {
+        importer.import_line(line.as_bytes()).await.unwrap();
+    }
+    let report = importer.finish().await.unwrap();
+
+    let restored_entries: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM pnar_dictionary")
+        .fetch_one(&target.pool)
+        .await
+        .unwrap();
+    assert_eq!(restored_entries, exported_entries);
+    let dictionary = report
+        .tables
+        .iter()
+        .find(|counts| counts.table == "pnar_dictionary")
+        .unwrap();
+    assert_eq!(dictionary.inserted, exported_entries);
+}
+
+#[tokio::test]
+async fn dashboard_counts_pending_translations() {
+    let db = spawn_db().await;
+    let admin_id = insert_user(&db.pool, "dashboard@example.com", "admin").await;
+    for status in ["pending", "pending", "completed"] {
+        sqlx::query(
+            "INSERT INTO translation_requests (user_id, source_text, status) VALUES ($1, 'hello', $2)",
+        )
+        .bind(admin_id)
+        .bind(status)
+        .execute(&db.pool)
+        .await
+        .unwrap();
+    }
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/admin")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::admin::dashboard),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(admin_id, &db.settings.jwt).unwrap();
+
+    let res = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/api/v1/admin/dashboard")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    assert_eq!(body["database"], "connected");
+    assert_eq!(body["review_queues"]["pending_translations"], 2);
+}
+
+#[tokio::test]
+async fn activity_lists_a_running_query_that_can_then_be_cancelled() {
+    let db = spawn_db().await;
+    let admin_id = insert_user(&db.pool, "dba@example.com", "admin").await;
+    let mut settings = db.settings.clone();
+    settings.monitoring.redact_query_text = false;
+    let sleeper_pool = db.pool.clone();
+    let sleeper = tokio::spawn(async move {
+        sqlx::query("SELECT pg_sleep(30)")
+            .execute(&sleeper_pool)
+            .await
+    });
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(settings))
+            .service(
+                web::scope("/api/v1/admin")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::admin::db_activity)
+                    .service(handlers::admin::cancel_db_query),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(admin_id, &db.settings.jwt).unwrap();
+    let authorized = |req: TestRequest| {
+        req.insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .to_request()
+    };
+
+    let mut sleeping_pid = None;
+    for _ in 0..50 {
+        let res = call_service(
+            &app,
+            authorized(TestRequest::get().uri("/api/v1/admin/db/activity?min_duration_seconds=0")),
+        )
+        .await;
+        assert_eq!(res.status(), StatusCode::OK);
+        let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+        sleeping_pid = body["queries"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|query| query["query"] == "SELECT pg_sleep(30)")
+            .map(|query| query["pid"].as_i64().unwrap());
+        if sleeping_pid.is_some() {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    let pid = sleeping_pid.expect("the sleeping query should be listed");
+
+    let res = call_service(
+        &app,
+        authorized(TestRequest::post().uri(&format!("/api/v1/admin/db/cancel/{}", pid))),
+    )
+    .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    let cancelled = tokio::time::timeout(std::time::Duration::from_secs(10), sleeper)
+        .await
+        .expect("the cancelled query should stop sleeping")
+        .unwrap();
+    assert!(cancelled.is_err());
+}
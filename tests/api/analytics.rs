@@ -0,0 +1,203 @@
+use crate::helpers::{insert_entry, insert_user, spawn_db};
+use actix_web::{
+    http::{header, StatusCode},
+    test::{call_service, init_service, read_body, TestRequest},
+    web, App,
+};
+use chrono::{DateTime, TimeZone, Utc};
+use pnar_world_api::{
+    config::ImpactPointsSettings, dto::CreateAnalyticsRequest, handlers,
+    middleware::auth::AuthMiddleware, services::analytics_service, utils::jwt,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+async fn record_event(
+    pool: &PgPool,
+    word_id: Uuid,
+    user_id: Option<Uuid>,
+    event_type: &str,
+    at: DateTime<Utc>,
+) {
+    analytics_service::create_analytics_record(
+        pool,
+        user_id,
+        CreateAnalyticsRequest {
+            word_id,
+            event_type: event_type.to_string(),
+            timestamp: at,
+            session_id: None,
+            metadata: None,
+        },
+    )
+    .await
+    .expect("Failed to record analytics event");
+}
+
+fn day(d: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, d, 12, 0, 0).unwrap()
+}
+
+#[tokio::test]
+async fn word_stats_only_include_buckets_within_range() {
+    let db = spawn_db().await;
+    let word_id = insert_entry(&db.pool, "stats", None).await;
+    for d in [1, 5, 5, 9, 14] {
+        record_event(&db.pool, word_id, None, "lookup", day(d)).await;
+    }
+
+    let stats =
+        analytics_service::get_word_usage_stats(&db.pool, word_id, None, day(4), day(10), 100)
+            .await
+            .unwrap();
+
+    let buckets = stats["statistics"].as_array().unwrap();
+    let counts: Vec<(String, i64)> = buckets
+        .iter()
+        .map(|b| {
+            (
+                b["date"].as_str().unwrap()[..10].to_string(),
+                b["count"].as_i64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        counts,
+        [("2026-03-09".to_string(), 1), ("2026-03-05".to_string(), 2)]
+    );
+
+    let limited =
+        analytics_service::get_word_usage_stats(&db.pool, word_id, None, day(1), day(20), 1)
+            .await
+            .unwrap();
+    assert_eq!(limited["statistics"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn word_stats_limit_counts_days_not_event_types() {
+    let db = spawn_db().await;
+    let word_id = insert_entry(&db.pool, "statsdays", None).await;
+    for (event_type, d) in [("view", 7), ("lookup", 7), ("search", 7), ("lookup", 3)] {
+        record_event(&db.pool, word_id, None, event_type, day(d)).await;
+    }
+
+    let stats =
+        analytics_service::get_word_usage_stats(&db.pool, word_id, None, day(1), day(20), 1)
+            .await
+            .unwrap();
+
+    let buckets: Vec<(String, String)> = stats["statistics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|b| {
+            (
+                b["date"].as_str().unwrap()[..10].to_string(),
+                b["event_type"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        buckets,
+        [
+            ("2026-03-07".to_string(), "lookup".to_string()),
+            ("2026-03-07".to_string(), "search".to_string()),
+            ("2026-03-07".to_string(), "view".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn lookups_in_a_window_award_one_impact_contribution_per_creator() {
+    let db = spawn_db().await;
+    let first = insert_user(&db.pool, "first@example.com", "user").await;
+    let second = insert_user(&db.pool, "second@example.com", "user").await;
+    let first_words = [
+        insert_entry(&db.pool, "impactone", Some(first)).await,
+        insert_entry(&db.pool, "impacttwo", Some(first)).await,
+    ];
+    let second_word = insert_entry(&db.pool, "impactthree", Some(second)).await;
+    for word_id in [
+        first_words[0],
+        first_words[0],
+        first_words[1],
+        second_word,
+        second_word,
+    ] {
+        record_event(&db.pool, word_id, None, "lookup", day(5)).await;
+    }
+    // Outside the window, and a creator looking up their own word
+    record_event(&db.pool, first_words[0], None, "lookup", day(9)).await;
+    record_event(&db.pool, first_words[1], Some(first), "lookup", day(5)).await;
+    let settings = ImpactPointsSettings {
+        enabled: true,
+        interval_seconds: 3600,
+        lookups_per_point: 1,
+        max_points_per_window: 10,
+    };
+
+    let awarded =
+        analytics_service::award_impact_points_for_window(&db.pool, &settings, day(4), day(6))
+            .await
+            .unwrap();
+    let rerun =
+        analytics_service::award_impact_points_for_window(&db.pool, &settings, day(4), day(6))
+            .await
+            .unwrap();
+
+    assert_eq!((awarded, rerun), (2, 0));
+    for (user_id, points) in [(first, 3), (second, 2)] {
+        let awards: Vec<i32> = sqlx::query_scalar(
+            "SELECT points_awarded FROM user_contributions WHERE user_id = $1 AND contribution_type = 'impact'",
+        )
+        .bind(user_id)
+        .fetch_all(&db.pool)
+        .await
+        .unwrap();
+        assert_eq!(awards, vec![points]);
+    }
+}
+
+#[tokio::test]
+async fn listing_filters_by_usage_type_within_a_date_range() {
+    let db = spawn_db().await;
+    let moderator_id = insert_user(&db.pool, "mod@example.com", "moderator").await;
+    let word_id = insert_entry(&db.pool, "filtered", None).await;
+    record_event(&db.pool, word_id, None, "search", day(5)).await;
+    record_event(&db.pool, word_id, None, "search", day(9)).await;
+    record_event(&db.pool, word_id, None, "lookup", day(5)).await;
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/analytics")
+                    .wrap(AuthMiddleware)
+                    .route("", web::get().to(handlers::analytics::list_analytics)),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(moderator_id, &db.settings.jwt).unwrap();
+
+    let res = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/api/v1/analytics?usage_type=search&from=2026-03-04T00:00:00Z&to=2026-03-08T00:00:00Z")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    let records: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    let found: Vec<_> = records
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|record| {
+            let timestamp: DateTime<Utc> = record["timestamp"].as_str().unwrap().parse().unwrap();
+            (record["event_type"].as_str().unwrap(), timestamp)
+        })
+        .collect();
+    assert_eq!(found, vec![("search", day(5))]);
+}
@@ -0,0 +1,142 @@
+use crate::helpers::spawn_db;
+use actix_web::{
+    http::StatusCode,
+    test::{call_service, init_service, TestRequest},
+    web, App,
+};
+use pnar_world_api::{
+    dto::{LoginRequest, RegisterRequest},
+    error::AppError,
+    handlers,
+    middleware::auth::AuthMiddleware,
+    services::auth_service,
+};
+
+const PASSWORD: &str = "correct-horse-battery";
+
+fn register_request(email: &str) -> RegisterRequest {
+    RegisterRequest {
+        email: email.to_string(),
+        password: PASSWORD.to_string(),
+        full_name: None,
+    }
+}
+
+#[tokio::test]
+async fn emails_differing_only_in_case_collide_on_registration() {
+    let db = spawn_db().await;
+    let settings = &db.settings;
+
+    let registered = auth_service::register_user(
+        &db.pool,
+        &settings.jwt,
+        &settings.security,
+        register_request("Mixed.Case@Example.com"),
+    )
+    .await
+    .unwrap();
+    assert_eq!(registered.user.email, "mixed.case@example.com");
+
+    let duplicate = auth_service::register_user(
+        &db.pool,
+        &settings.jwt,
+        &settings.security,
+        register_request("mixed.case@EXAMPLE.com"),
+    )
+    .await;
+    assert!(matches!(duplicate, Err(AppError::Conflict(_))));
+
+    // The index backs this up for rows written outside the service
+    let raw_insert =
+        sqlx::query("INSERT INTO users (email, password) VALUES ('MIXED.case@example.com', 'x')")
+            .execute(&db.pool)
+            .await;
+    assert!(raw_insert.is_err());
+}
+
+#[tokio::test]
+async fn login_ignores_email_case() {
+    let db = spawn_db().await;
+    let settings = &db.settings;
+
+    let registered = auth_service::register_user(
+        &db.pool,
+        &settings.jwt,
+        &settings.security,
+        register_request("login@example.com"),
+    )
+    .await
+    .unwrap();
+
+    let logged_in = auth_service::login_user(
+        &db.pool,
+        &settings.jwt,
+        &settings.security,
+        LoginRequest {
+            email: "LOGIN@Example.COM".to_string(),
+            password: PASSWORD.to_string(),
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(logged_in.user.id, registered.user.id);
+}
+
+#[tokio::test]
+async fn login_cookie_alone_authenticates_later_requests() {
+    let db = spawn_db().await;
+    let mut settings = db.settings.clone();
+    settings.jwt.cookie_auth = true;
+    auth_service::register_user(
+        &db.pool,
+        &settings.jwt,
+        &settings.security,
+        register_request("cookie@example.com"),
+    )
+    .await
+    .unwrap();
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(settings.clone()))
+            .service(web::scope("/api/v1/auth").service(handlers::auth::login))
+            .service(
+                web::scope("/api/v1/users")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::user::get_current_user),
+            ),
+    )
+    .await;
+
+    let login = call_service(
+        &app,
+        TestRequest::post()
+            .uri("/api/v1/auth/login")
+            .set_json(serde_json::json!({
+                "email": "cookie@example.com",
+                "password": PASSWORD,
+            }))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(login.status(), StatusCode::OK);
+    let cookie = login
+        .response()
+        .cookies()
+        .find(|cookie| cookie.name() == settings.jwt.cookie_name)
+        .expect("login should set the session cookie")
+        .into_owned();
+    assert_eq!(cookie.http_only(), Some(true));
+
+    let me = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/api/v1/users/me")
+            .cookie(cookie)
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!(me.status(), StatusCode::OK);
+}
@@ -0,0 +1,142 @@
+use crate::helpers::{insert_user, spawn_db, TestDb};
+use actix_web::{
+    http::{header, StatusCode},
+    test::{call_service, init_service, read_body, TestRequest},
+    web, App,
+};
+use pnar_world_api::{
+    handlers, middleware::auth::AuthMiddleware, services::contribution_service, utils::jwt,
+};
+use uuid::Uuid;
+
+async fn insert_contribution(pool: &sqlx::PgPool, user_id: Uuid) -> Uuid {
+    sqlx::query_scalar(
+        "INSERT INTO user_contributions (user_id, contribution_type, entity_type, entity_id, action) VALUES ($1, 'dictionary', 'entry', gen_random_uuid(), 'create') RETURNING id",
+    )
+    .bind(user_id)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+async fn get_contributions(
+    db: &TestDb,
+    viewer_id: Uuid,
+    uri: &str,
+) -> (StatusCode, serde_json::Value) {
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/contributions")
+                    .wrap(AuthMiddleware)
+                    .route(
+                        "",
+                        web::get().to(handlers::contribution::list_contributions),
+                    )
+                    .route(
+                        "/all",
+                        web::get().to(handlers::contribution::list_contributions_for_review),
+                    ),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(viewer_id, &db.settings.jwt).unwrap();
+    let req = TestRequest::get()
+        .uri(uri)
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+        .to_request();
+
+    let res = call_service(&app, req).await;
+    let status = res.status();
+    let body = read_body(res).await;
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+fn ids(contributions: &serde_json::Value) -> Vec<String> {
+    let mut ids: Vec<String> = contributions
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["id"].as_str().unwrap().to_string())
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[tokio::test]
+async fn moderator_reviews_pending_contributions_from_all_users() {
+    let db = spawn_db().await;
+    let moderator_id = insert_user(&db.pool, "mod@example.com", "moderator").await;
+    let first_id = insert_user(&db.pool, "first@example.com", "user").await;
+    let second_id = insert_user(&db.pool, "second@example.com", "user").await;
+    let mut expected = vec![
+        insert_contribution(&db.pool, first_id).await.to_string(),
+        insert_contribution(&db.pool, second_id).await.to_string(),
+    ];
+    expected.sort();
+
+    let (status, body) = get_contributions(
+        &db,
+        moderator_id,
+        "/api/v1/contributions/all?status=pending",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(ids(&body["data"]), expected);
+    assert_eq!(body["pagination"]["total"], 2);
+}
+
+#[tokio::test]
+async fn regular_user_is_scoped_to_own_contributions() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "user@example.com", "user").await;
+    let other_id = insert_user(&db.pool, "other@example.com", "user").await;
+    let own_id = insert_contribution(&db.pool, user_id).await;
+    insert_contribution(&db.pool, other_id).await;
+
+    let (status, _) =
+        get_contributions(&db, user_id, "/api/v1/contributions/all?status=pending").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, body) = get_contributions(&db, user_id, "/api/v1/contributions?all=true").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(ids(&body), vec![own_id.to_string()]);
+}
+
+#[tokio::test]
+async fn retried_create_with_same_idempotency_key_records_once() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "user@example.com", "user").await;
+    let request = serde_json::json!({
+        "contribution_type": "dictionary",
+        "entity_type": "entry",
+        "entity_id": Uuid::new_v4(),
+        "action": "create",
+        "points_awarded": 5,
+    });
+    let create = || {
+        contribution_service::create_contribution(
+            &db.pool,
+            user_id,
+            serde_json::from_value(request.clone()).unwrap(),
+            Some("retry-1".to_string()),
+            Vec::new(),
+        )
+    };
+
+    let first = create().await.unwrap();
+    let second = create().await.unwrap();
+
+    assert_eq!(first.id, second.id);
+    let (rows, points): (i64, Option<i64>) = sqlx::query_as(
+        "SELECT COUNT(*), SUM(points_awarded) FROM user_contributions WHERE user_id = $1",
+    )
+    .bind(user_id)
+    .fetch_one(&db.pool)
+    .await
+    .unwrap();
+    assert_eq!((rows, points), (1, Some(5)));
+}
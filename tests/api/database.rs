@@ -0,0 +1,217 @@
+use crate::helpers::{insert_entry, insert_user, spawn_db};
+use actix_web::{
+    http::{header, StatusCode},
+    test::{call_service, init_service, TestRequest},
+    web, App, HttpResponse, ResponseError,
+};
+use pnar_world_api::{
+    database,
+    dto::dictionary::CreateDictionaryEntryRequest,
+    error::AppError,
+    middleware::transaction::{TransactionMiddleware, Tx},
+    services::dictionary_service,
+};
+use sqlx::postgres::PgPoolOptions;
+use std::{
+    cell::Cell,
+    rc::Rc,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::{layer::Context, prelude::*, Layer};
+
+/// Advisory lock key `run_migrations` holds while migrating ("pnarmig")
+const MIGRATION_LOCK_KEY: i64 = 0x0070_6e61_726d_6967;
+
+/// Counts the warnings sqlx emits for slow statements
+#[derive(Clone, Default)]
+struct SlowStatementCounter(Arc<Mutex<usize>>);
+
+impl<S: Subscriber> Layer<S> for SlowStatementCounter {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        if metadata.target() == "sqlx::query" && *metadata.level() == Level::WARN {
+            *self.0.lock().unwrap() += 1;
+        }
+    }
+}
+
+#[tokio::test]
+async fn pool_is_warmed_up_to_min_connections() {
+    let db = spawn_db().await;
+    let mut settings = db.settings.database.clone();
+    settings.min_connections = 3;
+    settings.max_connections = 5;
+
+    let pool = database::create_connection_pool(&settings, &db.settings.monitoring)
+        .await
+        .unwrap();
+
+    // Warmup connections are handed back to the pool in the background
+    for _ in 0..50 {
+        if pool.num_idle() >= 3 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(pool.num_idle() >= 3, "only {} idle", pool.num_idle());
+}
+
+#[tokio::test]
+async fn slow_query_is_logged_when_monitoring_is_on() {
+    let db = spawn_db().await;
+    let mut monitoring = db.settings.monitoring.clone();
+    monitoring.performance_monitoring = true;
+    monitoring.slow_query_threshold_ms = 100;
+    let counter = SlowStatementCounter::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(counter.clone()));
+
+    let pool = database::create_connection_pool(&db.settings.database, &monitoring)
+        .await
+        .unwrap();
+    sqlx::query("SELECT 1").execute(&pool).await.unwrap();
+    assert_eq!(*counter.0.lock().unwrap(), 0);
+
+    sqlx::query("SELECT pg_sleep(0.3)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    assert_eq!(*counter.0.lock().unwrap(), 1);
+}
+
+#[tokio::test]
+async fn failed_transaction_leaves_no_partial_row() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "author@example.com", "user").await;
+    // Deferred to commit, so create_entry's INSERT succeeds and the failure
+    // comes after it, inside the same transaction
+    for statement in [
+        r#"
+        CREATE FUNCTION reject_half_entry() RETURNS TRIGGER AS $$
+        BEGIN
+            RAISE EXCEPTION 'injected failure';
+        END;
+        $$ LANGUAGE plpgsql
+        "#,
+        r#"
+        CREATE CONSTRAINT TRIGGER reject_half_entry
+            AFTER INSERT ON pnar_dictionary
+            DEFERRABLE INITIALLY DEFERRED
+            FOR EACH ROW
+            WHEN (NEW.pnar_word = 'kyntiewhalf')
+            EXECUTE FUNCTION reject_half_entry()
+        "#,
+    ] {
+        sqlx::query(statement).execute(&db.pool).await.unwrap();
+    }
+    let request: CreateDictionaryEntryRequest = serde_json::from_value(serde_json::json!({
+        "pnar_word": "kyntiewhalf",
+        "english_word": "half",
+    }))
+    .unwrap();
+
+    let result = dictionary_service::create_entry(&db.pool, user_id, request).await;
+
+    assert!(matches!(result, Err(AppError::Database(_))));
+    let rows: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM pnar_dictionary WHERE pnar_word = 'kyntiewhalf'")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+    assert_eq!(rows, 0);
+}
+
+#[tokio::test]
+async fn handler_error_rolls_back_the_request_transaction() {
+    let db = spawn_db().await;
+    let moderator_id = insert_user(&db.pool, "mod@example.com", "moderator").await;
+    let entry_id = insert_entry(&db.pool, "kyntiewrolledback", None).await;
+    let committed = Rc::new(Cell::new(false));
+    let hook_flag = committed.clone();
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .wrap(TransactionMiddleware)
+            .route(
+                "/verify",
+                web::post().to(move |tx: Tx| {
+                    let hook_flag = hook_flag.clone();
+                    async move {
+                        let mut conn = tx.lock().await?;
+                        dictionary_service::verify_entry(&mut conn, entry_id, moderator_id).await?;
+                        drop(conn);
+                        tx.after_commit(move || hook_flag.set(true));
+
+                        Err::<HttpResponse, _>(AppError::Internal("injected failure".to_string()))
+                    }
+                }),
+            ),
+    )
+    .await;
+
+    let res = call_service(&app, TestRequest::post().uri("/verify").to_request()).await;
+
+    assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    let verified: bool = sqlx::query_scalar("SELECT verified FROM pnar_dictionary WHERE id = $1")
+        .bind(entry_id)
+        .fetch_one(&db.pool)
+        .await
+        .unwrap();
+    assert!(!verified);
+    assert!(!committed.get());
+}
+
+#[tokio::test]
+async fn exhausted_pool_is_reported_as_unavailable() {
+    let db = spawn_db().await;
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .acquire_timeout(Duration::from_millis(100))
+        .connect_with(db.settings.database.connection_options())
+        .await
+        .unwrap();
+    let _busy = pool.acquire().await.unwrap();
+
+    let error =
+        dictionary_service::search_count(&pool, "kyntiew", None, false, &db.settings.dictionary)
+            .await
+            .unwrap_err();
+
+    assert!(matches!(
+        error,
+        AppError::Database(sqlx::Error::PoolTimedOut)
+    ));
+    let response = error.error_response();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert!(response.headers().contains_key(header::RETRY_AFTER));
+}
+
+#[tokio::test]
+async fn second_migration_run_waits_for_the_lock() {
+    let db = spawn_db().await;
+    let mut holder = db.pool.acquire().await.unwrap();
+    sqlx::query("SELECT pg_advisory_lock($1)")
+        .bind(MIGRATION_LOCK_KEY)
+        .execute(&mut *holder)
+        .await
+        .unwrap();
+
+    let migration = database::run_migrations(&db.pool, Duration::from_secs(30));
+    tokio::pin!(migration);
+    let waited = tokio::time::timeout(Duration::from_millis(300), &mut migration).await;
+    assert!(waited.is_err(), "migrations ran while the lock was held");
+
+    sqlx::query("SELECT pg_advisory_unlock($1)")
+        .bind(MIGRATION_LOCK_KEY)
+        .execute(&mut *holder)
+        .await
+        .unwrap();
+
+    tokio::time::timeout(Duration::from_secs(10), migration)
+        .await
+        .expect("migrations should finish once the lock is released")
+        .unwrap();
+}
@@ -0,0 +1,1497 @@
+use crate::helpers::{insert_entry, insert_user, spawn_db};
+use actix_web::{
+    body::{to_bytes, MessageBody},
+    http::{header, StatusCode},
+    test::{call_service, init_service, read_body, TestRequest},
+    web, App, HttpServer, ResponseError,
+};
+use pnar_world_api::{
+    dto::dictionary::{
+        CreateDictionaryEntryRequest, SearchDictionaryRequest, SearchType,
+        SuggestDictionaryEntryRequest, UpdateDictionaryEntryRequest,
+    },
+    error::AppError,
+    handlers,
+    middleware::{auth::AuthMiddleware, transaction::TransactionMiddleware},
+    services::dictionary_service::{
+        self, DictionaryExportLimiter, DictionaryStatsCache, EntryFilters, EntryOrder,
+        VerifiedEntryFeed,
+    },
+    utils::jwt,
+};
+use std::{
+    future::poll_fn,
+    io::{Read, Write},
+    net::{TcpListener, TcpStream},
+    time::Duration,
+};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn list_is_not_modified_since_its_last_modified_time() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "reader@example.com", "user").await;
+    insert_entry(&db.pool, "kyntien", None).await;
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::list_entries),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(user_id, &db.settings.jwt).unwrap();
+    let authorization = (header::AUTHORIZATION, format!("Bearer {}", token));
+
+    let first = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/api/v1/dictionary")
+            .insert_header(authorization.clone())
+            .to_request(),
+    )
+    .await;
+    assert_eq!(first.status(), StatusCode::OK);
+    let last_modified = first
+        .headers()
+        .get(header::LAST_MODIFIED)
+        .expect("Last-Modified header")
+        .clone();
+
+    let second = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/api/v1/dictionary")
+            .insert_header(authorization)
+            .insert_header((header::IF_MODIFIED_SINCE, last_modified))
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!(second.status(), StatusCode::NOT_MODIFIED);
+    assert!(read_body(second).await.is_empty());
+}
+
+#[tokio::test]
+async fn deleting_a_listed_entry_defeats_the_not_modified_response() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "reader@example.com", "user").await;
+    let entry_id = insert_entry(&db.pool, "kyntiewgone", Some(user_id)).await;
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::list_entries),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(user_id, &db.settings.jwt).unwrap();
+    let authorization = (header::AUTHORIZATION, format!("Bearer {}", token));
+
+    let first = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/api/v1/dictionary?per_page=1")
+            .insert_header(authorization.clone())
+            .to_request(),
+    )
+    .await;
+    let last_modified = first
+        .headers()
+        .get(header::LAST_MODIFIED)
+        .expect("Last-Modified header")
+        .clone();
+    let body: serde_json::Value = serde_json::from_slice(&read_body(first).await).unwrap();
+    assert_eq!(body["data"][0]["id"], entry_id.to_string());
+
+    // HTTP dates only resolve whole seconds
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    dictionary_service::delete_entry(&db.pool, entry_id, user_id)
+        .await
+        .unwrap();
+
+    let second = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/api/v1/dictionary?per_page=1")
+            .insert_header(authorization)
+            .insert_header((header::IF_MODIFIED_SINCE, last_modified))
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!(second.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn verified_only_search_excludes_unverified_matches() {
+    let db = spawn_db().await;
+    let verified_id = insert_entry(&db.pool, "kyntiewverified", None).await;
+    insert_entry(&db.pool, "kyntiewpending", None).await;
+    sqlx::query("UPDATE pnar_dictionary SET verified = true WHERE id = $1")
+        .bind(verified_id)
+        .execute(&db.pool)
+        .await
+        .unwrap();
+    let request: SearchDictionaryRequest = serde_json::from_value(serde_json::json!({
+        "query": "kyntiew",
+        "search_type": "pnar",
+        "verified_only": true,
+    }))
+    .unwrap();
+
+    let result = dictionary_service::search_entries(&db.pool, request, &db.settings.dictionary)
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = result.data.iter().map(|entry| entry.id).collect();
+    assert_eq!(ids, vec![verified_id]);
+}
+
+#[tokio::test]
+async fn stats_are_cached_until_an_entry_is_created() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "editor@example.com", "user").await;
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .app_data(web::Data::new(DictionaryStatsCache::new(
+                Duration::from_secs(60),
+            )))
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::create_entry)
+                    .service(handlers::dictionary::get_stats),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(user_id, &db.settings.jwt).unwrap();
+    let authorization = (header::AUTHORIZATION, format!("Bearer {}", token));
+    let total_entries = |body: web::Bytes| {
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        body["data"]["total_entries"].as_i64().unwrap()
+    };
+    let get_stats = || {
+        TestRequest::get()
+            .uri("/api/v1/dictionary/stats")
+            .insert_header(authorization.clone())
+            .to_request()
+    };
+
+    let before = total_entries(read_body(call_service(&app, get_stats()).await).await);
+    // Written behind the cache's back, so a cache hit does not see it
+    insert_entry(&db.pool, "kyntiewsneak", None).await;
+    let cached = total_entries(read_body(call_service(&app, get_stats()).await).await);
+    assert_eq!(cached, before);
+
+    let created = call_service(
+        &app,
+        TestRequest::post()
+            .uri("/api/v1/dictionary")
+            .insert_header(authorization.clone())
+            .set_json(serde_json::json!({"pnar_word": "kyntiewnew", "english_word": "new"}))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(created.status(), StatusCode::CREATED);
+
+    let after = total_entries(read_body(call_service(&app, get_stats()).await).await);
+    assert_eq!(after, before + 2);
+}
+
+#[tokio::test]
+async fn duplicate_word_conflict_links_to_the_existing_entry() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "editor@example.com", "user").await;
+    let existing_id = insert_entry(&db.pool, "kyntiewtaken", None).await;
+    let request: CreateDictionaryEntryRequest = serde_json::from_value(serde_json::json!({
+        "pnar_word": "kyntiewtaken",
+        "english_word": "taken",
+    }))
+    .unwrap();
+
+    let err = dictionary_service::create_entry(&db.pool, user_id, request)
+        .await
+        .unwrap_err();
+
+    let res = err.error_response();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+    let body = to_bytes(res.into_body()).await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(
+        body["error"]["details"],
+        serde_json::json!({
+            "conflict_field": "pnar_word",
+            "conflicting_value": "kyntiewtaken",
+            "existing_id": existing_id,
+        })
+    );
+}
+
+#[tokio::test]
+async fn uncounted_list_reports_has_more_without_a_total() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "reader@example.com", "user").await;
+    insert_entry(&db.pool, "kyntiewone", None).await;
+    insert_entry(&db.pool, "kyntiewtwo", None).await;
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::list_entries),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(user_id, &db.settings.jwt).unwrap();
+
+    let res = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/api/v1/dictionary?per_page=1&count=false")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+    assert_eq!(body["pagination"]["has_more"], true);
+    assert!(body["pagination"].get("total").is_none());
+}
+
+#[tokio::test]
+async fn created_range_keeps_only_entries_inside_it() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "researcher@example.com", "user").await;
+    let early = insert_entry(&db.pool, "kyntiewearly", None).await;
+    let inside = insert_entry(&db.pool, "kyntiewinside", None).await;
+    let late = insert_entry(&db.pool, "kyntiewlate", None).await;
+    for (id, created_at) in [
+        (early, "2020-01-01T00:00:00Z"),
+        (inside, "2020-06-15T00:00:00Z"),
+        (late, "2021-01-01T00:00:00Z"),
+    ] {
+        sqlx::query("UPDATE pnar_dictionary SET created_at = $2::timestamptz WHERE id = $1")
+            .bind(id)
+            .bind(created_at)
+            .execute(&db.pool)
+            .await
+            .unwrap();
+    }
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::list_entries),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(user_id, &db.settings.jwt).unwrap();
+
+    let res = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/api/v1/dictionary?created_after=2020-03-01T00:00:00Z&created_before=2020-12-31T00:00:00Z")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    let ids: Vec<_> = body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["id"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(ids, vec![inside.to_string()]);
+}
+
+#[tokio::test]
+async fn approved_anonymous_suggestion_becomes_an_entry() {
+    let db = spawn_db().await;
+    let moderator_id = insert_user(&db.pool, "mod@example.com", "moderator").await;
+    let request: SuggestDictionaryEntryRequest = serde_json::from_value(serde_json::json!({
+        "pnar_word": "kyntiewsuggested",
+        "english_word": "suggested",
+    }))
+    .unwrap();
+
+    let suggestion = dictionary_service::suggest_entry(&db.pool, request, Vec::new())
+        .await
+        .unwrap();
+
+    assert_eq!(suggestion.status, "pending");
+    let notifications: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM notifications WHERE user_id = $1")
+            .bind(moderator_id)
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+    assert_eq!(notifications, 1);
+
+    let entry = dictionary_service::approve_suggestion(&db.pool, suggestion.id, moderator_id)
+        .await
+        .unwrap();
+
+    assert_eq!(entry.pnar_word, "kyntiewsuggested");
+    assert!(!entry.verified);
+    let status: String =
+        sqlx::query_scalar("SELECT status FROM dictionary_suggestions WHERE id = $1")
+            .bind(suggestion.id)
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+    assert_eq!(status, "approved");
+}
+
+#[tokio::test]
+async fn json_patch_replaces_definition_but_not_created_by() {
+    let db = spawn_db().await;
+    let author_id = insert_user(&db.pool, "author@example.com", "user").await;
+    let entry_id = insert_entry(&db.pool, "kyntiewpatched", Some(author_id)).await;
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .app_data(web::Data::new(DictionaryStatsCache::new(
+                Duration::from_secs(60),
+            )))
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::patch_entry),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(author_id, &db.settings.jwt).unwrap();
+    let patch = |operations: serde_json::Value| {
+        TestRequest::patch()
+            .uri(&format!("/api/v1/dictionary/{}", entry_id))
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .insert_header((header::CONTENT_TYPE, "application/json-patch+json"))
+            .set_payload(operations.to_string())
+            .to_request()
+    };
+
+    let res = call_service(
+        &app,
+        patch(serde_json::json!([
+            {"op": "replace", "path": "/definition", "value": "patched meaning"}
+        ])),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    assert_eq!(body["data"]["definition"], "patched meaning");
+
+    let res = call_service(
+        &app,
+        patch(serde_json::json!([
+            {"op": "replace", "path": "/created_by", "value": Uuid::new_v4()}
+        ])),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    let created_by: Option<Uuid> =
+        sqlx::query_scalar("SELECT created_by FROM pnar_dictionary WHERE id = $1")
+            .bind(entry_id)
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+    assert_eq!(created_by, Some(author_id));
+}
+
+#[tokio::test]
+async fn stats_completeness_counts_filled_fields() {
+    let db = spawn_db().await;
+    sqlx::query("DELETE FROM pnar_dictionary")
+        .execute(&db.pool)
+        .await
+        .unwrap();
+    let full = insert_entry(&db.pool, "kyntiewfull", None).await;
+    let blank = insert_entry(&db.pool, "kyntiewblank", None).await;
+    insert_entry(&db.pool, "kyntiewbare", None).await;
+    sqlx::query(
+        "UPDATE pnar_dictionary SET pronunciation = 'kyn-tiew', etymology = 'old', example_pnar = 'ka kyntiew' WHERE id = $1",
+    )
+    .bind(full)
+    .execute(&db.pool)
+    .await
+    .unwrap();
+    // Whitespace-only values do not count as filled in
+    sqlx::query("UPDATE pnar_dictionary SET pronunciation = '  ', etymology = 'new' WHERE id = $1")
+        .bind(blank)
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+    let stats = dictionary_service::stats(&db.pool).await.unwrap();
+
+    assert_eq!(stats.total_entries, 3);
+    assert_eq!(stats.completeness["pronunciation"], 1);
+    assert_eq!(stats.completeness["etymology"], 2);
+    assert_eq!(stats.completeness["example_pnar"], 1);
+    assert_eq!(stats.completeness["cultural_context"], 0);
+}
+
+#[tokio::test]
+async fn head_on_an_entry_sends_headers_without_a_body() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "reader@example.com", "user").await;
+    let entry_id = insert_entry(&db.pool, "kyntiewhead", None).await;
+    let (pool, settings) = (db.pool.clone(), db.settings.clone());
+    // The body is dropped by the HTTP/1 dispatcher, so this needs a real server
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let server = HttpServer::new(move || {
+        App::new()
+            .app_data(web::Data::new(pool.clone()))
+            .app_data(web::Data::new(settings.clone()))
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::get_entry),
+            )
+    })
+    .workers(1)
+    .listen(listener)
+    .unwrap()
+    .run();
+    tokio::spawn(server);
+    let token = jwt::generate_token(user_id, &db.settings.jwt).unwrap();
+
+    let response = tokio::task::spawn_blocking(move || {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "HEAD /api/v1/dictionary/{} HTTP/1.1\r\nHost: localhost\r\nAuthorization: Bearer {}\r\nConnection: close\r\n\r\n",
+            entry_id, token
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    })
+    .await
+    .unwrap();
+
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let head = head.to_ascii_lowercase();
+    assert!(head.starts_with("http/1.1 200 ok"), "{}", head);
+    assert!(head.contains("\r\netag: "), "{}", head);
+    assert!(head.contains("\r\nlast-modified: "), "{}", head);
+    assert!(body.is_empty(), "{}", body);
+}
+
+#[tokio::test]
+async fn related_entries_are_only_included_when_expanded() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "reader@example.com", "user").await;
+    let entry_id = insert_entry(&db.pool, "kyntiewroot", None).await;
+    insert_entry(&db.pool, "kyntiewleft", None).await;
+    insert_entry(&db.pool, "kyntiewright", None).await;
+    sqlx::query(
+        "UPDATE pnar_dictionary SET related_words = 'kyntiewleft; kyntiewright, kyntiewmissing' WHERE id = $1",
+    )
+    .bind(entry_id)
+    .execute(&db.pool)
+    .await
+    .unwrap();
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::get_entry),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(user_id, &db.settings.jwt).unwrap();
+    let get = |query: &str| {
+        TestRequest::get()
+            .uri(&format!("/api/v1/dictionary/{}{}", entry_id, query))
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .to_request()
+    };
+
+    let res = call_service(&app, get("?expand=related")).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    let related: Vec<_> = body["data"]["related_entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["pnar_word"].as_str().unwrap())
+        .collect();
+    assert_eq!(related, vec!["kyntiewleft", "kyntiewright"]);
+
+    let res = call_service(&app, get("")).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    assert_eq!(body["data"]["pnar_word"], "kyntiewroot");
+    assert!(body["data"].get("related_entries").is_none());
+}
+
+#[tokio::test]
+async fn verifying_an_entry_is_pushed_to_stream_subscribers() {
+    let db = spawn_db().await;
+    let moderator_id = insert_user(&db.pool, "mod@example.com", "moderator").await;
+    let entry_id = insert_entry(&db.pool, "kyntiewstreamed", None).await;
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .app_data(web::Data::new(DictionaryStatsCache::new(
+                Duration::from_secs(60),
+            )))
+            .app_data(web::Data::new(VerifiedEntryFeed::new()))
+            .wrap(TransactionMiddleware)
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::stream_verified_entries)
+                    .service(handlers::dictionary::verify_entry),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(moderator_id, &db.settings.jwt).unwrap();
+    let authorization = (header::AUTHORIZATION, format!("Bearer {}", token));
+
+    let stream = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/api/v1/dictionary/stream")
+            .insert_header(authorization.clone())
+            .to_request(),
+    )
+    .await;
+    assert_eq!(stream.status(), StatusCode::OK);
+    let verified = call_service(
+        &app,
+        TestRequest::put()
+            .uri(&format!("/api/v1/dictionary/{}/verify", entry_id))
+            .insert_header(authorization)
+            .to_request(),
+    )
+    .await;
+    assert_eq!(verified.status(), StatusCode::OK);
+
+    let mut body = Box::pin(stream.into_body());
+    let event = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let chunk = poll_fn(|cx| body.as_mut().poll_next(cx))
+                .await
+                .expect("stream ended")
+                .unwrap();
+            let chunk = String::from_utf8(chunk.to_vec()).unwrap();
+            // Skip keep-alive comments
+            if chunk.contains("event: entry_verified") {
+                return chunk;
+            }
+        }
+    })
+    .await
+    .expect("no entry_verified event");
+
+    assert!(event.contains("kyntiewstreamed"), "{}", event);
+}
+
+#[tokio::test]
+async fn csv_import_reports_a_malformed_row_and_keeps_the_rest() {
+    let db = spawn_db().await;
+    let moderator_id = insert_user(&db.pool, "mod@example.com", "moderator").await;
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .app_data(web::Data::new(DictionaryStatsCache::new(
+                Duration::from_secs(60),
+            )))
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::import_csv),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(moderator_id, &db.settings.jwt).unwrap();
+    let csv = "\u{feff}pnar_word,english_word,difficulty_level,definition\n\
+               kyntiewcsvone,one,1,\"first, with a comma\"\n\
+               kyntiewcsvbad,bad,not-a-number,\n\
+               kyntiewcsvtwo,two,2,\n";
+    let body = format!(
+        "--boundary\r\nContent-Disposition: form-data; name=\"file\"; filename=\"words.csv\"\r\nContent-Type: text/csv\r\n\r\n{}\r\n--boundary--\r\n",
+        csv
+    );
+
+    let res = call_service(
+        &app,
+        TestRequest::post()
+            .uri("/api/v1/dictionary/import/csv")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .insert_header((
+                header::CONTENT_TYPE,
+                "multipart/form-data; boundary=boundary",
+            ))
+            .set_payload(body)
+            .to_request(),
+    )
+    .await;
+
+    let status = res.status();
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    assert!(status.is_success(), "{}: {}", status, body);
+    let created: Vec<_> = body["created"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["pnar_word"].as_str().unwrap())
+        .collect();
+    assert_eq!(created, vec!["kyntiewcsvone", "kyntiewcsvtwo"]);
+    assert_eq!(body["created"][0]["definition"], "first, with a comma");
+    let failed = body["failed"].as_array().unwrap();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0]["index"], 1);
+    assert!(failed[0]["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Malformed row"));
+}
+
+#[tokio::test]
+async fn reassigned_entry_can_only_be_updated_by_its_new_owner() {
+    let db = spawn_db().await;
+    let moderator_id = insert_user(&db.pool, "mod@example.com", "moderator").await;
+    let original_id = insert_user(&db.pool, "original@example.com", "user").await;
+    let new_owner_id = insert_user(&db.pool, "new@example.com", "user").await;
+    let entry_id = insert_entry(&db.pool, "kyntiewowned", Some(original_id)).await;
+
+    let entry = dictionary_service::reassign_owner(&db.pool, entry_id, new_owner_id, moderator_id)
+        .await
+        .unwrap();
+    assert_eq!(entry.created_by, Some(new_owner_id));
+
+    let update = || -> UpdateDictionaryEntryRequest {
+        serde_json::from_value(serde_json::json!({
+            "definition": "updated after reassignment",
+            "version": entry.version,
+        }))
+        .unwrap()
+    };
+    let err = dictionary_service::update_entry(&db.pool, entry_id, original_id, update())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AppError::Forbidden(_)), "{:?}", err);
+
+    let updated = dictionary_service::update_entry(&db.pool, entry_id, new_owner_id, update())
+        .await
+        .unwrap();
+    assert_eq!(
+        updated.definition.as_deref(),
+        Some("updated after reassignment")
+    );
+}
+
+#[tokio::test]
+async fn fields_projection_returns_only_the_requested_keys() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "reader@example.com", "user").await;
+    let entry_id = insert_entry(&db.pool, "kyntiewprojected", None).await;
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::get_entry),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(user_id, &db.settings.jwt).unwrap();
+
+    let res = call_service(
+        &app,
+        TestRequest::get()
+            .uri(&format!(
+                "/api/v1/dictionary/{}?fields=id,pnar_word",
+                entry_id
+            ))
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    assert_eq!(
+        body["data"],
+        serde_json::json!({"id": entry_id, "pnar_word": "kyntiewprojected"})
+    );
+}
+
+#[tokio::test]
+async fn stale_version_update_is_rejected() {
+    let db = spawn_db().await;
+    let author_id = insert_user(&db.pool, "author@example.com", "user").await;
+    let entry_id = insert_entry(&db.pool, "kyntiewversioned", Some(author_id)).await;
+    let update = |definition: &str, version: i32| -> UpdateDictionaryEntryRequest {
+        serde_json::from_value(serde_json::json!({
+            "definition": definition,
+            "version": version,
+        }))
+        .unwrap()
+    };
+    let current = dictionary_service::get_entry(&db.pool, entry_id)
+        .await
+        .unwrap()
+        .version;
+
+    let updated =
+        dictionary_service::update_entry(&db.pool, entry_id, author_id, update("first", current))
+            .await
+            .unwrap();
+    assert_eq!(updated.version, current + 1);
+
+    // A second editor still holding the original version
+    let err =
+        dictionary_service::update_entry(&db.pool, entry_id, author_id, update("second", current))
+            .await
+            .unwrap_err();
+    assert_eq!(err.error_response().status(), StatusCode::CONFLICT);
+    let entry = dictionary_service::get_entry(&db.pool, entry_id)
+        .await
+        .unwrap();
+    assert_eq!(entry.definition.as_deref(), Some("first"));
+}
+
+#[tokio::test]
+async fn old_unverified_entry_leads_the_freshness_report() {
+    let db = spawn_db().await;
+    let stale_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO pnar_dictionary (pnar_word, english_word, created_at, updated_at) VALUES ('kyntiewstale', 'stale', NOW() - INTERVAL '2 years', NOW() - INTERVAL '2 years') RETURNING id",
+    )
+    .fetch_one(&db.pool)
+    .await
+    .unwrap();
+    // Older still, but verified entries are not curation gaps
+    sqlx::query(
+        "INSERT INTO pnar_dictionary (pnar_word, english_word, verified, created_at, updated_at) VALUES ('kyntiewsettled', 'settled', true, NOW() - INTERVAL '3 years', NOW() - INTERVAL '3 years')",
+    )
+    .execute(&db.pool)
+    .await
+    .unwrap();
+
+    let report = dictionary_service::freshness_report(&db.pool)
+        .await
+        .unwrap();
+
+    assert_eq!(report.oldest_unverified[0].id, stale_id);
+    assert!(report
+        .oldest_unverified
+        .iter()
+        .all(|entry| entry.pnar_word != "kyntiewsettled"));
+    let over_a_year = report
+        .by_last_update_age
+        .iter()
+        .find(|bucket| bucket.bucket == "over_365_days")
+        .unwrap();
+    assert_eq!(over_a_year.count, 2);
+}
+
+#[tokio::test]
+async fn search_count_matches_the_rows_a_search_returns() {
+    let db = spawn_db().await;
+    insert_entry(&db.pool, "kyntiewcounted", None).await;
+    insert_entry(&db.pool, "kyntiewtallied", None).await;
+    let by_definition = insert_entry(&db.pool, "rangbah", None).await;
+    sqlx::query("UPDATE pnar_dictionary SET definition = 'not a kyntiew at all' WHERE id = $1")
+        .bind(by_definition)
+        .execute(&db.pool)
+        .await
+        .unwrap();
+    let request: SearchDictionaryRequest = serde_json::from_value(serde_json::json!({
+        "query": "kyntiew",
+        "per_page": 100,
+    }))
+    .unwrap();
+
+    let count =
+        dictionary_service::search_count(&db.pool, "kyntiew", None, false, &db.settings.dictionary)
+            .await
+            .unwrap();
+    let results = dictionary_service::search_entries(&db.pool, request, &db.settings.dictionary)
+        .await
+        .unwrap();
+
+    assert_eq!(count.count, 3);
+    assert_eq!(count.count, results.data.len() as i64);
+}
+
+#[tokio::test]
+async fn fuzzy_search_count_matches_the_fuzzy_total() {
+    let db = spawn_db().await;
+    insert_entry(&db.pool, "kyntiewmisspelt", None).await;
+    let request: SearchDictionaryRequest = serde_json::from_value(serde_json::json!({
+        "query": "kyntiewmispelt",
+        "search_type": "fuzzy",
+    }))
+    .unwrap();
+
+    let substring = dictionary_service::search_count(
+        &db.pool,
+        "kyntiewmispelt",
+        None,
+        false,
+        &db.settings.dictionary,
+    )
+    .await
+    .unwrap();
+    let fuzzy = dictionary_service::search_count(
+        &db.pool,
+        "kyntiewmispelt",
+        Some(&SearchType::Fuzzy),
+        false,
+        &db.settings.dictionary,
+    )
+    .await
+    .unwrap();
+    let results = dictionary_service::search_entries(&db.pool, request, &db.settings.dictionary)
+        .await
+        .unwrap();
+
+    assert_eq!(substring.count, 0);
+    assert!(fuzzy.count >= 1);
+    assert_eq!(Some(fuzzy.count), results.pagination.total);
+}
+
+#[tokio::test]
+async fn empty_search_echoes_the_query_with_a_zero_count() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "searcher@example.com", "user").await;
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::search_entries),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(user_id, &db.settings.jwt).unwrap();
+
+    let res = call_service(
+        &app,
+        TestRequest::post()
+            .uri("/api/v1/dictionary/search")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .set_json(serde_json::json!({ "query": "  kyntiewnothing " }))
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    assert_eq!(body["query"], "kyntiewnothing");
+    assert_eq!(body["count"], 0);
+    assert_eq!(body["data"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn search_page_past_the_maximum_is_rejected_instead_of_overflowing() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "searcher@example.com", "user").await;
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::search_entries),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(user_id, &db.settings.jwt).unwrap();
+
+    let res = call_service(
+        &app,
+        TestRequest::post()
+            .uri("/api/v1/dictionary/search")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .set_json(serde_json::json!({ "query": "kyntiew", "page": i64::MAX }))
+            .to_request(),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    // Callers that skip validation get the last servable page, not a panic
+    let request: SearchDictionaryRequest = serde_json::from_value(serde_json::json!({
+        "query": "kyntiew",
+        "page": i64::MAX,
+        "per_page": 100,
+    }))
+    .unwrap();
+    let results = dictionary_service::search_entries(&db.pool, request, &db.settings.dictionary)
+        .await
+        .unwrap();
+    assert!(results.data.is_empty());
+}
+
+#[tokio::test]
+async fn merged_entry_hands_its_analytics_to_the_kept_entry() {
+    let db = spawn_db().await;
+    let moderator_id = insert_user(&db.pool, "merger@example.com", "moderator").await;
+    let keep_id = insert_entry(&db.pool, "kyntiewkeep", None).await;
+    let merge_id = insert_entry(&db.pool, "kyntiewdupe", None).await;
+    for word_id in [keep_id, merge_id, merge_id] {
+        sqlx::query(
+            "INSERT INTO word_usage_analytics (id, word_id, event_type) VALUES ($1, $2, 'view')",
+        )
+        .bind(Uuid::new_v4())
+        .bind(word_id)
+        .execute(&db.pool)
+        .await
+        .unwrap();
+    }
+
+    dictionary_service::merge_entries(&db.pool, keep_id, merge_id, moderator_id)
+        .await
+        .unwrap();
+
+    let kept_events: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM word_usage_analytics WHERE word_id = $1")
+            .bind(keep_id)
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+    assert_eq!(kept_events, 3);
+    let merged = dictionary_service::get_entry(&db.pool, merge_id).await;
+    assert!(matches!(merged, Err(AppError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn lookups_raise_an_entry_in_the_popularity_listing() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "reader@example.com", "user").await;
+    let popular = insert_entry(&db.pool, "kyntiewpopular", None).await;
+    let runner_up = insert_entry(&db.pool, "kyntiewrunnerup", None).await;
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::list_entries)
+                    .service(handlers::dictionary::get_entry),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(user_id, &db.settings.jwt).unwrap();
+    let get = |uri: String| {
+        TestRequest::get()
+            .uri(&uri)
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .to_request()
+    };
+
+    for entry_id in [popular, runner_up, popular] {
+        let res = call_service(&app, get(format!("/api/v1/dictionary/{}", entry_id))).await;
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    let lookup_count: i64 =
+        sqlx::query_scalar("SELECT lookup_count::bigint FROM pnar_dictionary WHERE id = $1")
+            .bind(popular)
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+    assert_eq!(lookup_count, 2);
+    let res = call_service(
+        &app,
+        get("/api/v1/dictionary?order_by=lookup_count&per_page=2".to_string()),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    let words: Vec<_> = body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["pnar_word"].as_str().unwrap())
+        .collect();
+    assert_eq!(words, vec!["kyntiewpopular", "kyntiewrunnerup"]);
+}
+
+#[tokio::test]
+async fn search_limit_is_capped_at_the_configured_maximum() {
+    let db = spawn_db().await;
+    for word in ["kyntiewa", "kyntiewb", "kyntiewc", "kyntiewd", "kyntiewe"] {
+        insert_entry(&db.pool, word, None).await;
+    }
+    let mut settings = db.settings.dictionary.clone();
+    settings.max_search_limit = 3;
+    let request: SearchDictionaryRequest = serde_json::from_value(serde_json::json!({
+        "query": "kyntiew",
+        "limit": 10000,
+    }))
+    .unwrap();
+
+    let results = dictionary_service::search_entries(&db.pool, request, &settings)
+        .await
+        .unwrap();
+
+    assert_eq!(results.data.len(), 3);
+    assert_eq!(results.pagination.per_page, 3);
+    assert_eq!(results.pagination.total, Some(5));
+}
+
+#[tokio::test]
+async fn recently_verified_lists_the_latest_verification_first() {
+    let db = spawn_db().await;
+    let earlier = insert_entry(&db.pool, "kyntiewearlier", None).await;
+    let later = insert_entry(&db.pool, "kyntiewlater", None).await;
+    insert_entry(&db.pool, "kyntiewpending", None).await;
+    for (entry_id, hours_ahead) in [(later, 2), (earlier, 1)] {
+        sqlx::query(
+            "UPDATE pnar_dictionary SET verified = true, verified_at = NOW() + make_interval(hours => $2) WHERE id = $1",
+        )
+        .bind(entry_id)
+        .bind(hours_ahead)
+        .execute(&db.pool)
+        .await
+        .unwrap();
+    }
+
+    let entries = dictionary_service::recently_verified(&db.pool, 1000)
+        .await
+        .unwrap();
+
+    let words: Vec<_> = entries
+        .iter()
+        .map(|entry| entry.pnar_word.as_str())
+        .collect();
+    assert_eq!(words[..2], ["kyntiewlater", "kyntiewearlier"]);
+    assert!(entries.iter().all(|entry| entry.verified));
+    assert!(!words.contains(&"kyntiewpending"));
+}
+
+#[tokio::test]
+async fn validating_a_duplicate_word_reports_a_conflict_without_saving() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "drafter@example.com", "user").await;
+    let existing_id = insert_entry(&db.pool, "kyntiewtaken", None).await;
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::validate_entry),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(user_id, &db.settings.jwt).unwrap();
+
+    let res = call_service(
+        &app,
+        TestRequest::post()
+            .uri("/api/v1/dictionary/validate")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .set_json(serde_json::json!({
+                "pnar_word": "kyntiewtaken",
+                "english_word": "taken again",
+            }))
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    assert_eq!(body["data"]["valid"], false);
+    assert_eq!(body["data"]["errors"], serde_json::json!([]));
+    assert_eq!(body["data"]["conflicts"][0]["field"], "pnar_word");
+    assert_eq!(
+        body["data"]["conflicts"][0]["existing_id"],
+        existing_id.to_string()
+    );
+    let rows: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM pnar_dictionary WHERE pnar_word = 'kyntiewtaken'")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+    assert_eq!(rows, 1);
+}
+
+#[tokio::test]
+async fn word_lookup_ignores_case_and_padding_and_404s_when_absent() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "linker@example.com", "user").await;
+    let entry_id = insert_entry(&db.pool, "Kyntiewlink", None).await;
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::get_entry_by_word),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(user_id, &db.settings.jwt).unwrap();
+    let get = |uri: &str| {
+        TestRequest::get()
+            .uri(uri)
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .to_request()
+    };
+
+    let res = call_service(&app, get("/api/v1/dictionary/word/%20kyntiewLINK%20")).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    assert_eq!(body["data"]["id"], entry_id.to_string());
+
+    let res = call_service(&app, get("/api/v1/dictionary/word/kyntiewmissing")).await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn suggestion_with_a_blocked_term_is_flagged_but_kept_pending() {
+    let db = spawn_db().await;
+    let moderator_id = insert_user(&db.pool, "screener@example.com", "moderator").await;
+    let mut settings = db.settings.clone();
+    settings.moderation.blocked_terms = vec!["Kyntiew Curse".to_string()];
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(settings))
+            .service(web::scope("/api/v1/dictionary").service(handlers::dictionary::suggest_entry)),
+    )
+    .await;
+
+    let res = call_service(
+        &app,
+        TestRequest::post()
+            .uri("/api/v1/dictionary/suggest")
+            .set_json(serde_json::json!({
+                "pnar_word": "kyntiewflagged",
+                "english_word": "flagged",
+                "definition": "Said as a kyntiew curse, loudly.",
+            }))
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!(res.status(), StatusCode::CREATED);
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    assert_eq!(body["data"]["status"], "pending");
+    assert_eq!(
+        body["data"]["flagged_terms"],
+        serde_json::json!(["kyntiew curse"])
+    );
+    let flagged_notices: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM notifications WHERE user_id = $1 AND type = 'flagged_submission'",
+    )
+    .bind(moderator_id)
+    .fetch_one(&db.pool)
+    .await
+    .unwrap();
+    assert_eq!(flagged_notices, 1);
+}
+
+#[tokio::test]
+async fn missing_field_worklist_skips_entries_that_have_it() {
+    let db = spawn_db().await;
+    let spoken = insert_entry(&db.pool, "kyntiewspoken", None).await;
+    let blank = insert_entry(&db.pool, "kyntiewblank", None).await;
+    insert_entry(&db.pool, "kyntiewsilent", None).await;
+    for (entry_id, pronunciation) in [(spoken, "kyn-tiew"), (blank, "  ")] {
+        sqlx::query("UPDATE pnar_dictionary SET pronunciation = $2 WHERE id = $1")
+            .bind(entry_id)
+            .bind(pronunciation)
+            .execute(&db.pool)
+            .await
+            .unwrap();
+    }
+
+    let missing =
+        dictionary_service::entries_missing_field(&db.pool, "pronunciation", None, 1, 1000)
+            .await
+            .unwrap();
+
+    let words: Vec<_> = missing
+        .data
+        .iter()
+        .map(|entry| entry.pnar_word.as_str())
+        .collect();
+    assert!(words.contains(&"kyntiewblank"));
+    assert!(words.contains(&"kyntiewsilent"));
+    assert!(!words.contains(&"kyntiewspoken"));
+    assert!(missing.data.iter().all(|entry| entry
+        .pronunciation
+        .as_deref()
+        .is_none_or(|value| value.trim().is_empty())));
+}
+
+#[tokio::test]
+async fn batch_get_returns_found_entries_in_request_order() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "batcher@example.com", "user").await;
+    let mut ids = Vec::new();
+    for word in [
+        "kyntiewfirst",
+        "kyntiewsecond",
+        "kyntiewthird",
+        "kyntiewfourth",
+    ] {
+        ids.push(insert_entry(&db.pool, word, None).await);
+    }
+    let requested = vec![ids[2], ids[0], Uuid::new_v4(), ids[3], ids[1]];
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/dictionary")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::dictionary::batch_get_entries),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(user_id, &db.settings.jwt).unwrap();
+
+    let res = call_service(
+        &app,
+        TestRequest::post()
+            .uri("/api/v1/dictionary/batch-get")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .set_json(serde_json::json!({ "ids": requested }))
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    let words: Vec<_> = body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["pnar_word"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        words,
+        vec![
+            "kyntiewthird",
+            "kyntiewfirst",
+            "kyntiewfourth",
+            "kyntiewsecond"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn search_pages_past_the_first_batch() {
+    let db = spawn_db().await;
+    for word in ["kyntiewpagea", "kyntiewpageb", "kyntiewpagec"] {
+        insert_entry(&db.pool, word, None).await;
+    }
+    let request: SearchDictionaryRequest = serde_json::from_value(serde_json::json!({
+        "query": "kyntiewpage",
+        "page": 2,
+        "per_page": 2,
+    }))
+    .unwrap();
+
+    let results = dictionary_service::search_entries(&db.pool, request, &db.settings.dictionary)
+        .await
+        .unwrap();
+
+    assert_eq!(results.data.len(), 1);
+    assert_eq!(results.pagination.page, 2);
+    assert_eq!(results.pagination.total, Some(3));
+    assert_eq!(results.pagination.pages, Some(2));
+    assert!(!results.pagination.has_more);
+}
+
+#[tokio::test]
+async fn fuzzy_search_finds_a_misspelled_word() {
+    let db = spawn_db().await;
+    insert_entry(&db.pool, "kyntiewmisspelt", None).await;
+    let search = |search_type: &str| -> SearchDictionaryRequest {
+        serde_json::from_value(serde_json::json!({
+            "query": "kyntiewmispelt",
+            "search_type": search_type,
+        }))
+        .unwrap()
+    };
+
+    let substring =
+        dictionary_service::search_entries(&db.pool, search("all"), &db.settings.dictionary)
+            .await
+            .unwrap();
+    let fuzzy =
+        dictionary_service::search_entries(&db.pool, search("fuzzy"), &db.settings.dictionary)
+            .await
+            .unwrap();
+
+    assert!(substring.data.is_empty());
+    assert_eq!(fuzzy.data[0].pnar_word, "kyntiewmisspelt");
+}
+
+#[tokio::test]
+async fn list_filters_and_count_agree_on_verification_and_part_of_speech() {
+    let db = spawn_db().await;
+    for (word, verified, part_of_speech) in [
+        ("kyntiewnoun", true, "noun"),
+        ("kyntiewnountoo", true, "Noun"),
+        ("kyntiewdraftnoun", false, "noun"),
+        ("kyntiewverb", true, "verb"),
+    ] {
+        let entry_id = insert_entry(&db.pool, word, None).await;
+        sqlx::query("UPDATE pnar_dictionary SET verified = $2, part_of_speech = $3 WHERE id = $1")
+            .bind(entry_id)
+            .bind(verified)
+            .bind(part_of_speech)
+            .execute(&db.pool)
+            .await
+            .unwrap();
+    }
+    let expected: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM pnar_dictionary WHERE verified AND LOWER(part_of_speech) = 'noun'",
+    )
+    .fetch_one(&db.pool)
+    .await
+    .unwrap();
+    let filters = EntryFilters {
+        verified: Some(true),
+        part_of_speech: Some("NOUN".to_string()),
+        ..EntryFilters::default()
+    };
+
+    let (page, _) =
+        dictionary_service::list_entries(&db.pool, 1, 1000, true, &filters, EntryOrder::CreatedAt)
+            .await
+            .unwrap();
+
+    assert_eq!(page.pagination.total, Some(expected));
+    assert_eq!(page.data.len() as i64, expected);
+    let words: Vec<_> = page
+        .data
+        .iter()
+        .map(|entry| entry.pnar_word.as_str())
+        .collect();
+    assert!(words.contains(&"kyntiewnoun") && words.contains(&"kyntiewnountoo"));
+    assert!(!words.contains(&"kyntiewdraftnoun") && !words.contains(&"kyntiewverb"));
+}
+
+#[tokio::test]
+async fn export_streams_every_entry_as_json_or_csv() {
+    let db = spawn_db().await;
+    insert_entry(&db.pool, "kyntiewexported", None).await;
+    let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM pnar_dictionary")
+        .fetch_one(&db.pool)
+        .await
+        .unwrap();
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(DictionaryExportLimiter::new(
+                1,
+                Duration::from_secs(30),
+            )))
+            .service(
+                web::scope("/api/v1/dictionary").service(handlers::dictionary::export_entries),
+            ),
+    )
+    .await;
+
+    let res = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/api/v1/dictionary/export?format=json")
+            .to_request(),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let entries: Vec<serde_json::Value> = serde_json::from_slice(&read_body(res).await).unwrap();
+    assert_eq!(entries.len() as i64, total);
+    assert!(entries
+        .iter()
+        .any(|entry| entry["pnar_word"] == "kyntiewexported"));
+
+    let res = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/api/v1/dictionary/export?format=csv")
+            .to_request(),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let csv = String::from_utf8(read_body(res).await.to_vec()).unwrap();
+    assert!(
+        csv.starts_with("id,pnar_word,english_word,"),
+        "{}",
+        &csv[..80]
+    );
+    assert!(csv.contains("kyntiewexported"));
+}
+
+#[tokio::test]
+async fn reconcile_only_counts_lookup_events() {
+    let db = spawn_db().await;
+    let searched = insert_entry(&db.pool, "kyntiewsearched", None).await;
+    let looked_up = insert_entry(&db.pool, "kyntiewlookedup", None).await;
+    for (word_id, event_type) in [
+        (searched, "search"),
+        (searched, "view"),
+        (looked_up, "lookup"),
+    ] {
+        sqlx::query(
+            "INSERT INTO word_usage_analytics (id, word_id, event_type) VALUES ($1, $2, $3)",
+        )
+        .bind(Uuid::new_v4())
+        .bind(word_id)
+        .bind(event_type)
+        .execute(&db.pool)
+        .await
+        .unwrap();
+    }
+
+    dictionary_service::reconcile_lookup_counts(&db.pool)
+        .await
+        .unwrap();
+
+    let lookup_count = |entry_id: Uuid| {
+        sqlx::query_scalar::<_, i64>(
+            "SELECT lookup_count::bigint FROM pnar_dictionary WHERE id = $1",
+        )
+        .bind(entry_id)
+        .fetch_one(&db.pool)
+    };
+    assert_eq!(lookup_count(searched).await.unwrap(), 0);
+    assert_eq!(lookup_count(looked_up).await.unwrap(), 1);
+}
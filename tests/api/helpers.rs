@@ -0,0 +1,59 @@
+use pnar_world_api::config::{get_configuration, Settings};
+use sqlx::{Connection, Executor, PgConnection, PgPool};
+use uuid::Uuid;
+
+/// A freshly migrated database of its own, so tests can run in parallel
+pub struct TestDb {
+    pub pool: PgPool,
+    pub settings: Settings,
+}
+
+pub async fn spawn_db() -> TestDb {
+    let mut settings = get_configuration().expect("Failed to read configuration");
+    settings.database.database_name = format!("test_{}", Uuid::new_v4().simple());
+
+    let mut connection =
+        PgConnection::connect_with(&settings.database.connection_options().database("postgres"))
+            .await
+            .expect("Failed to connect to Postgres");
+    connection
+        .execute(format!(r#"CREATE DATABASE "{}";"#, settings.database.database_name).as_str())
+        .await
+        .expect("Failed to create test database");
+
+    let pool = PgPool::connect_with(settings.database.connection_options())
+        .await
+        .expect("Failed to connect to test database");
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("Failed to migrate test database");
+
+    TestDb { pool, settings }
+}
+
+/// Insert a user directly, bypassing password hashing
+pub async fn insert_user(pool: &PgPool, email: &str, role: &str) -> Uuid {
+    sqlx::query_scalar(
+        "INSERT INTO users (email, password, full_name, role) VALUES ($1, 'x', $2, $3) RETURNING id",
+    )
+    .bind(email)
+    .bind(format!("Name of {}", email))
+    .bind(role)
+    .fetch_one(pool)
+    .await
+    .expect("Failed to insert user")
+}
+
+/// Insert an unverified dictionary entry directly
+pub async fn insert_entry(pool: &PgPool, pnar_word: &str, created_by: Option<Uuid>) -> Uuid {
+    sqlx::query_scalar(
+        "INSERT INTO pnar_dictionary (pnar_word, english_word, created_by) VALUES ($1, $2, $3) RETURNING id",
+    )
+    .bind(pnar_word)
+    .bind(format!("{} in English", pnar_word))
+    .bind(created_by)
+    .fetch_one(pool)
+    .await
+    .expect("Failed to insert dictionary entry")
+}
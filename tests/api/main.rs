@@ -0,0 +1,12 @@
+mod admin;
+mod analytics;
+mod auth;
+mod contributions;
+mod database;
+mod dictionary;
+mod helpers;
+mod notifications;
+mod startup;
+mod submissions;
+mod translations;
+mod users;
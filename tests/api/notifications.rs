@@ -0,0 +1,42 @@
+use crate::helpers::{insert_user, spawn_db};
+use chrono::{Duration, Utc};
+use pnar_world_api::services::notification_service;
+
+#[tokio::test]
+async fn digest_counts_unread_notifications_since_the_cutoff() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "digest@example.com", "user").await;
+    let other_id = insert_user(&db.pool, "elsewhere@example.com", "user").await;
+    let cutoff = Utc::now() - Duration::hours(24);
+    for (owner, notification_type, read, hours_ago) in [
+        (user_id, "entry_verified", false, 1),
+        (user_id, "entry_verified", false, 2),
+        (user_id, "contribution_reviewed", false, 3),
+        (user_id, "entry_verified", true, 1),
+        (user_id, "entry_verified", false, 48),
+        (other_id, "entry_verified", false, 1),
+    ] {
+        sqlx::query(
+            r#"
+            INSERT INTO notifications (user_id, type, title, message, read, created_at)
+            VALUES ($1, $2, 'Title', 'Message', $3, $4)
+            "#,
+        )
+        .bind(owner)
+        .bind(notification_type)
+        .bind(read)
+        .bind(Utc::now() - Duration::hours(hours_ago))
+        .execute(&db.pool)
+        .await
+        .unwrap();
+    }
+
+    let digest = notification_service::build_digest(&db.pool, user_id, cutoff)
+        .await
+        .unwrap();
+
+    assert_eq!(digest.unread_count, 3);
+    assert_eq!(digest.by_type.get("entry_verified"), Some(&2));
+    assert_eq!(digest.by_type.get("contribution_reviewed"), Some(&1));
+    assert_eq!(digest.latest.len(), 3);
+}
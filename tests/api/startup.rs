@@ -0,0 +1,58 @@
+use crate::helpers::spawn_db;
+use actix_web::{
+    http::StatusCode,
+    test::{call_service, init_service, read_body, TestRequest},
+    web, App,
+};
+use pnar_world_api::{database::Readiness, handlers, startup::Application};
+use std::time::Duration;
+
+#[tokio::test]
+async fn self_check_names_a_missing_critical_table() {
+    let db = spawn_db().await;
+    sqlx::query("DROP TABLE notifications CASCADE")
+        .execute(&db.pool)
+        .await
+        .unwrap();
+    let mut settings = db.settings.clone();
+    settings.application.port = 0;
+
+    let err = match Application::build(settings).await {
+        Ok(_) => panic!("startup should fail without the notifications table"),
+        Err(err) => err,
+    };
+
+    let message = err.to_string();
+    assert!(message.contains("Startup self-check failed"), "{}", message);
+    assert!(
+        message.contains("missing tables: notifications"),
+        "{}",
+        message
+    );
+}
+
+#[tokio::test]
+async fn ready_probe_turns_ready_once_warmup_completes() {
+    let db = spawn_db().await;
+    let readiness = web::Data::new(Readiness::new(Duration::ZERO));
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(readiness.clone())
+            .service(web::scope("/api/v1").service(handlers::health::ready)),
+    )
+    .await;
+    let probe = || TestRequest::get().uri("/api/v1/ready").to_request();
+
+    let res = call_service(&app, probe()).await;
+    assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    assert_eq!(body["status"], "not_ready");
+
+    readiness.mark_warmed_up();
+
+    let res = call_service(&app, probe()).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    assert_eq!(body["status"], "ready");
+}
@@ -0,0 +1,70 @@
+use crate::helpers::{insert_user, spawn_db, TestDb};
+use actix_web::{
+    http::{header, StatusCode},
+    test::{call_service, init_service, read_body, TestRequest},
+    web, App,
+};
+use pnar_world_api::{handlers, middleware::auth::AuthMiddleware, utils::jwt};
+use uuid::Uuid;
+
+async fn get_submissions(
+    db: &TestDb,
+    viewer_id: Uuid,
+    target_id: Uuid,
+) -> (StatusCode, serde_json::Value) {
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/users")
+                    .wrap(AuthMiddleware)
+                    .service(handlers::user::list_user_submissions),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(viewer_id, &db.settings.jwt).unwrap();
+    let req = TestRequest::get()
+        .uri(&format!("/api/v1/users/{}/submissions", target_id))
+        .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+        .to_request();
+
+    let res = call_service(&app, req).await;
+    let status = res.status();
+    let body = read_body(res).await;
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn moderator_sees_a_users_recent_entries() {
+    let db = spawn_db().await;
+    let moderator_id = insert_user(&db.pool, "mod@example.com", "moderator").await;
+    let author_id = insert_user(&db.pool, "author@example.com", "user").await;
+    let entry_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO pnar_dictionary (pnar_word, english_word, created_by) VALUES ('vetted', 'checked', $1) RETURNING id",
+    )
+    .bind(author_id)
+    .fetch_one(&db.pool)
+    .await
+    .unwrap();
+
+    let (status, body) = get_submissions(&db, moderator_id, author_id).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let submissions = body["data"].as_array().unwrap();
+    assert_eq!(submissions.len(), 1);
+    assert_eq!(submissions[0]["kind"], "dictionary_entry");
+    assert_eq!(submissions[0]["ref_id"], entry_id.to_string());
+    assert_eq!(submissions[0]["status"], "unverified");
+}
+
+#[tokio::test]
+async fn regular_user_cannot_see_another_users_submissions() {
+    let db = spawn_db().await;
+    let viewer_id = insert_user(&db.pool, "viewer@example.com", "user").await;
+    let author_id = insert_user(&db.pool, "author@example.com", "user").await;
+
+    let (status, _) = get_submissions(&db, viewer_id, author_id).await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
@@ -0,0 +1,135 @@
+use crate::helpers::{insert_user, spawn_db};
+use actix_web::{
+    http::{header, StatusCode},
+    test::{call_service, init_service, read_body, TestRequest},
+    web, App,
+};
+use pnar_world_api::{
+    dto::translation::UpdateTranslationRequest, handlers, middleware::auth::AuthMiddleware,
+    services::translation_service, utils::jwt,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn merging_metadata_keeps_other_keys() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "translator@example.com", "user").await;
+    let request_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO translation_requests (user_id, source_text, metadata) VALUES ($1, 'hello', $2) RETURNING id",
+    )
+    .bind(user_id)
+    .bind(json!({"domain": "greetings", "source": "app"}))
+    .fetch_one(&db.pool)
+    .await
+    .unwrap();
+    let update: UpdateTranslationRequest = serde_json::from_value(json!({
+        "metadata": {"source": "web"},
+        "merge_metadata": true,
+    }))
+    .unwrap();
+
+    let updated = translation_service::update_translation_request(
+        &db.pool, request_id, user_id, "user", update,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        updated.metadata,
+        json!({"domain": "greetings", "source": "web"})
+    );
+}
+
+#[tokio::test]
+async fn listing_reports_the_total_of_the_users_translations() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "lister@example.com", "user").await;
+    let other_id = insert_user(&db.pool, "other@example.com", "user").await;
+    for (owner, text) in [
+        (user_id, "one"),
+        (user_id, "two"),
+        (user_id, "three"),
+        (other_id, "four"),
+    ] {
+        sqlx::query("INSERT INTO translation_requests (user_id, source_text) VALUES ($1, $2)")
+            .bind(owner)
+            .bind(text)
+            .execute(&db.pool)
+            .await
+            .unwrap();
+    }
+    let app = init_service(
+        App::new()
+            .app_data(web::Data::new(db.pool.clone()))
+            .app_data(web::Data::new(db.settings.clone()))
+            .service(
+                web::scope("/api/v1/translations")
+                    .wrap(AuthMiddleware)
+                    .route("", web::get().to(handlers::translation::list_translations)),
+            ),
+    )
+    .await;
+    let token = jwt::generate_token(user_id, &db.settings.jwt).unwrap();
+
+    let res = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/api/v1/translations?per_page=2")
+            .insert_header((header::AUTHORIZATION, format!("Bearer {}", token)))
+            .to_request(),
+    )
+    .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(&read_body(res).await).unwrap();
+    assert_eq!(body["data"].as_array().unwrap().len(), 2);
+    assert_eq!(body["pagination"]["total"], 3);
+}
+
+#[tokio::test]
+async fn saving_a_draft_stores_the_text_and_marks_it_in_progress() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "drafter@example.com", "user").await;
+    let request_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO translation_requests (user_id, source_text, status) VALUES ($1, 'a long passage', 'pending') RETURNING id",
+    )
+    .bind(user_id)
+    .fetch_one(&db.pool)
+    .await
+    .unwrap();
+
+    let draft = translation_service::save_draft(&db.pool, user_id, request_id, "half of it")
+        .await
+        .unwrap();
+
+    assert_eq!(draft.status, "in_progress");
+    assert_eq!(draft.translated_text.as_deref(), Some("half of it"));
+}
+
+#[tokio::test]
+async fn quick_successive_drafts_keep_the_latest_text() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "autosaver@example.com", "user").await;
+    let request_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO translation_requests (user_id, source_text, status) VALUES ($1, 'a long passage', 'pending') RETURNING id",
+    )
+    .bind(user_id)
+    .fetch_one(&db.pool)
+    .await
+    .unwrap();
+
+    translation_service::save_draft(&db.pool, user_id, request_id, "half of it")
+        .await
+        .unwrap();
+    let second = translation_service::save_draft(&db.pool, user_id, request_id, "most of it")
+        .await
+        .unwrap();
+    let repeated = translation_service::save_draft(&db.pool, user_id, request_id, "most of it")
+        .await
+        .unwrap();
+
+    assert_eq!(second.translated_text.as_deref(), Some("most of it"));
+    assert_eq!(repeated.translated_text.as_deref(), Some("most of it"));
+    assert_eq!(repeated.updated_at, second.updated_at);
+}
@@ -0,0 +1,319 @@
+use crate::helpers::{insert_user, spawn_db};
+use pnar_world_api::{
+    dto::{AwardPointsRequest, CreateUserRequest, RoleAssignment},
+    services::user_service,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+fn create_user_request(email: &str) -> CreateUserRequest {
+    CreateUserRequest {
+        email: email.to_string(),
+        password: "correct-horse-battery".to_string(),
+        full_name: None,
+        avatar_url: None,
+        role: None,
+        bio: None,
+        preferred_language: None,
+        settings: None,
+        is_active: None,
+    }
+}
+
+#[tokio::test]
+async fn activity_feed_lists_contributions_and_translations_newest_first() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "feed@example.com", "user").await;
+
+    sqlx::query(
+        r#"
+        INSERT INTO user_contributions
+            (user_id, contribution_type, entity_type, entity_id, action, created_at)
+        VALUES ($1, 'dictionary', 'entry', gen_random_uuid(), 'create', NOW() - INTERVAL '1 hour')
+        "#,
+    )
+    .bind(user_id)
+    .execute(&db.pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO translation_requests (user_id, source_text, created_at) VALUES ($1, 'hello', NOW())",
+    )
+    .bind(user_id)
+    .execute(&db.pool)
+    .await
+    .unwrap();
+
+    let feed = user_service::activity_feed(&db.pool, user_id, 10)
+        .await
+        .unwrap();
+
+    let kinds: Vec<&str> = feed.iter().map(|item| item.kind.as_str()).collect();
+    assert_eq!(kinds, ["translation", "contribution"]);
+    assert!(feed[0].at > feed[1].at);
+}
+
+#[tokio::test]
+async fn user_batch_reports_duplicate_emails_and_creates_the_rest() {
+    let db = spawn_db().await;
+    insert_user(&db.pool, "taken@example.com", "user").await;
+
+    let result = user_service::create_users_batch(
+        &db.pool,
+        &db.settings.security,
+        vec![
+            create_user_request("first@example.com"),
+            create_user_request("Taken@example.com"),
+            create_user_request("second@example.com"),
+            create_user_request("FIRST@example.com"),
+        ],
+    )
+    .await
+    .unwrap();
+
+    let created: Vec<&str> = result.created.iter().map(|u| u.email.as_str()).collect();
+    assert_eq!(created, ["first@example.com", "second@example.com"]);
+
+    let failed: Vec<(usize, &str)> = result
+        .failed
+        .iter()
+        .map(|e| (e.index, e.message.as_str()))
+        .collect();
+    assert_eq!(
+        failed,
+        [
+            (1, "User with this email already exists"),
+            (3, "User with this email already exists"),
+        ]
+    );
+}
+
+async fn insert_contribution(pool: &PgPool, user_id: Uuid, reviewed_by: Option<Uuid>) -> Uuid {
+    sqlx::query_scalar(
+        r#"
+        INSERT INTO user_contributions
+            (user_id, contribution_type, entity_type, entity_id, action, reviewed_by)
+        VALUES ($1, 'dictionary', 'entry', gen_random_uuid(), 'create', $2)
+        RETURNING id
+        "#,
+    )
+    .bind(user_id)
+    .bind(reviewed_by)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn data_export_contains_own_data_and_excludes_other_users() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "exporter@example.com", "user").await;
+    let other_id = insert_user(&db.pool, "bystander@example.com", "moderator").await;
+
+    let own_contribution = insert_contribution(&db.pool, user_id, Some(other_id)).await;
+    let other_contribution = insert_contribution(&db.pool, other_id, None).await;
+    sqlx::query("INSERT INTO translation_requests (user_id, source_text) VALUES ($1, 'mine')")
+        .bind(user_id)
+        .execute(&db.pool)
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO translation_requests (user_id, source_text) VALUES ($1, 'theirs')")
+        .bind(other_id)
+        .execute(&db.pool)
+        .await
+        .unwrap();
+
+    let export = user_service::export_user_data(&db.pool, user_id)
+        .await
+        .unwrap();
+
+    let contributions: Vec<Uuid> = export.contributions.iter().map(|c| c.id).collect();
+    assert_eq!(contributions, [own_contribution]);
+    assert!(!contributions.contains(&other_contribution));
+    assert_eq!(export.translations.len(), 1);
+    assert_eq!(export.translations[0].source_text, "mine");
+
+    let bundle = serde_json::to_string(&export).unwrap();
+    assert!(!bundle.contains("bystander@example.com"));
+}
+
+#[tokio::test]
+async fn purge_anonymizes_user_but_keeps_their_entries() {
+    let db = spawn_db().await;
+    let user_id = insert_user(&db.pool, "erase.me@example.com", "contributor").await;
+
+    let entry_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO pnar_dictionary (pnar_word, english_word, created_by) VALUES ('purgeword', 'erase', $1) RETURNING id",
+    )
+    .bind(user_id)
+    .fetch_one(&db.pool)
+    .await
+    .unwrap();
+    insert_contribution(&db.pool, user_id, None).await;
+    sqlx::query(
+        "INSERT INTO notifications (user_id, type, title, message) VALUES ($1, 'info', 'Hi', 'Hello')",
+    )
+    .bind(user_id)
+    .execute(&db.pool)
+    .await
+    .unwrap();
+
+    user_service::purge_user(&db.pool, &db.settings.security, user_id)
+        .await
+        .unwrap();
+
+    let user = user_service::get_user_by_id(&db.pool, user_id)
+        .await
+        .unwrap();
+    assert_eq!(user.email, format!("erased-{}@erased.invalid", user_id));
+    assert_eq!(user.full_name, None);
+    assert!(!user.is_active);
+
+    let created_by: Option<Uuid> =
+        sqlx::query_scalar("SELECT created_by FROM pnar_dictionary WHERE id = $1")
+            .bind(entry_id)
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+    assert_eq!(created_by, None);
+
+    let contributions: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM user_contributions WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+    assert_eq!(contributions, 1);
+
+    let notifications: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM notifications WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+    assert_eq!(notifications, 0);
+}
+
+#[test]
+fn roles_can_only_be_assigned_below_the_actor() {
+    assert!(user_service::can_assign_role("admin", "moderator"));
+    assert!(user_service::can_assign_role("moderator", "translator"));
+    assert!(!user_service::can_assign_role("moderator", "moderator"));
+    assert!(!user_service::can_assign_role("admin", "admin"));
+    assert!(!user_service::can_assign_role("admin", "wizard"));
+}
+
+#[tokio::test]
+async fn bulk_role_update_reports_each_disallowed_row() {
+    let db = spawn_db().await;
+    let admin_id = insert_user(&db.pool, "admin@example.com", "admin").await;
+    insert_user(&db.pool, "learner@example.com", "user").await;
+    insert_user(&db.pool, "climber@example.com", "user").await;
+    insert_user(&db.pool, "mod@example.com", "moderator").await;
+    insert_user(&db.pool, "peer@example.com", "admin").await;
+
+    let assignment = |email: &str, role: &str| RoleAssignment {
+        email: email.to_string(),
+        role: role.to_string(),
+    };
+    let result = user_service::bulk_update_roles(
+        &db.pool,
+        admin_id,
+        "admin",
+        vec![
+            assignment("learner@example.com", "translator"),
+            assignment("climber@example.com", "admin"),
+            assignment("mod@example.com", "contributor"),
+            assignment("peer@example.com", "user"),
+            assignment("admin@example.com", "user"),
+            assignment("ghost@example.com", "user"),
+            assignment("learner@example.com", "wizard"),
+        ],
+    )
+    .await
+    .unwrap();
+
+    let updated: Vec<(&str, &str)> = result
+        .created
+        .iter()
+        .map(|u| (u.email.as_str(), u.role.as_str()))
+        .collect();
+    assert_eq!(
+        updated,
+        [
+            ("learner@example.com", "translator"),
+            ("mod@example.com", "contributor"),
+        ]
+    );
+
+    let failed: Vec<(usize, &str)> = result
+        .failed
+        .iter()
+        .map(|e| (e.index, e.message.as_str()))
+        .collect();
+    assert_eq!(
+        failed,
+        [
+            (1, "You cannot assign the 'admin' role"),
+            (
+                3,
+                "You cannot change the role of a user at or above your own role"
+            ),
+            (4, "You cannot change your own role"),
+            (5, "User not found"),
+            (6, "Unknown role 'wizard'"),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn points_history_sums_to_translation_points() {
+    let db = spawn_db().await;
+    let admin_id = insert_user(&db.pool, "admin@example.com", "admin").await;
+    let user_id = insert_user(&db.pool, "earner@example.com", "contributor").await;
+
+    for (points, reason) in [(25, "Great entries"), (-5, "Duplicate entry")] {
+        user_service::award_points(
+            &db.pool,
+            user_id,
+            admin_id,
+            AwardPointsRequest {
+                points,
+                reason: reason.to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    }
+    // Recorded the way the impact job credits lookups of the user's words
+    sqlx::query(
+        r#"
+        INSERT INTO user_contributions
+            (user_id, contribution_type, entity_type, entity_id, action, points_awarded, status)
+        VALUES ($1, 'impact', 'user', $1, 'award', 7, 'approved')
+        "#,
+    )
+    .bind(user_id)
+    .execute(&db.pool)
+    .await
+    .unwrap();
+    sqlx::query("UPDATE users SET translation_points = translation_points + 7 WHERE id = $1")
+        .bind(user_id)
+        .execute(&db.pool)
+        .await
+        .unwrap();
+    // Contributions that didn't credit points stay out of the ledger
+    insert_contribution(&db.pool, user_id, None).await;
+
+    let history = user_service::points_history(&db.pool, user_id, 1, 50)
+        .await
+        .unwrap();
+    let user = user_service::get_user_by_id(&db.pool, user_id)
+        .await
+        .unwrap();
+
+    assert_eq!(history.data.len(), 3);
+    let total: i32 = history.data.iter().map(|entry| entry.points).sum();
+    assert_eq!(total, 27);
+    assert_eq!(total, user.translation_points);
+}